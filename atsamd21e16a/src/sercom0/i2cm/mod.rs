@@ -0,0 +1,66 @@
+#[doc = "I2CM Control A"]
+pub struct CTRLA {
+    register: ::vcell::VolatileCell<u32>,
+}
+#[doc = "I2CM Control A"]
+pub mod ctrla;
+#[doc = "I2CM Control B"]
+pub struct CTRLB {
+    register: ::vcell::VolatileCell<u32>,
+}
+#[doc = "I2CM Control B"]
+pub mod ctrlb;
+#[doc = "I2CM Baud Rate"]
+pub struct BAUD {
+    register: ::vcell::VolatileCell<u32>,
+}
+#[doc = "I2CM Baud Rate"]
+pub mod baud;
+#[doc = "I2CM Interrupt Enable Clear"]
+pub struct INTENCLR {
+    register: ::vcell::VolatileCell<u8>,
+}
+#[doc = "I2CM Interrupt Enable Clear"]
+pub mod intenclr;
+#[doc = "I2CM Interrupt Enable Set"]
+pub struct INTENSET {
+    register: ::vcell::VolatileCell<u8>,
+}
+#[doc = "I2CM Interrupt Enable Set"]
+pub mod intenset;
+#[doc = "I2CM Interrupt Flag Status and Clear"]
+pub struct INTFLAG {
+    register: ::vcell::VolatileCell<u8>,
+}
+#[doc = "I2CM Interrupt Flag Status and Clear"]
+pub mod intflag;
+#[doc = "I2CM Status"]
+pub struct STATUS {
+    register: ::vcell::VolatileCell<u16>,
+}
+#[doc = "I2CM Status"]
+pub mod status;
+#[doc = "I2CM Syncbusy"]
+pub struct SYNCBUSY {
+    register: ::vcell::VolatileCell<u32>,
+}
+#[doc = "I2CM Syncbusy"]
+pub mod syncbusy;
+#[doc = "I2CM Address"]
+pub struct ADDR {
+    register: ::vcell::VolatileCell<u32>,
+}
+#[doc = "I2CM Address"]
+pub mod addr;
+#[doc = "I2CM Data"]
+pub struct DATA {
+    register: ::vcell::VolatileCell<u8>,
+}
+#[doc = "I2CM Data"]
+pub mod data;
+#[doc = "I2CM Debug Control"]
+pub struct DBGCTRL {
+    register: ::vcell::VolatileCell<u8>,
+}
+#[doc = "I2CM Debug Control"]
+pub mod dbgctrl;
@@ -0,0 +1,479 @@
+#[doc = r" Value read from the register"]
+pub struct R {
+    bits: u8,
+}
+#[doc = r" Value to write to the register"]
+pub struct W {
+    bits: u8,
+}
+impl super::CTRLB {
+    #[doc = r" Modifies the contents of the register"]
+    #[inline]
+    pub fn modify<F>(&self, f: F)
+    where
+        for<'w> F: FnOnce(&R, &'w mut W) -> &'w mut W,
+    {
+        let bits = self.register.get();
+        let r = R { bits };
+        let mut w = W { bits };
+        f(&r, &mut w);
+        self.register.set(w.bits);
+    }
+    #[doc = r" Reads the contents of the register"]
+    #[inline]
+    pub fn read(&self) -> R {
+        R {
+            bits: self.register.get(),
+        }
+    }
+    #[doc = r" Writes to the register"]
+    #[inline]
+    pub fn write<F>(&self, f: F)
+    where
+        F: FnOnce(&mut W) -> &mut W,
+    {
+        let mut w = W::reset_value();
+        f(&mut w);
+        self.register.set(w.bits);
+    }
+    #[doc = r" Writes the reset value to the register"]
+    #[inline]
+    pub fn reset(&self) {
+        self.write(|w| w)
+    }
+}
+#[doc = r" Value of the field"]
+pub struct EOENR {
+    bits: bool,
+}
+impl EOENR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = r" Value of the field"]
+pub struct IOENR {
+    bits: bool,
+}
+impl IOENR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = r" Value of the field"]
+pub struct LEFTADJR {
+    bits: bool,
+}
+impl LEFTADJR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = r" Value of the field"]
+pub struct VPDR {
+    bits: bool,
+}
+impl VPDR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = r" Value of the field"]
+pub struct BDWPR {
+    bits: bool,
+}
+impl BDWPR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = "Possible values of the field `REFSEL`"]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum REFSELR {
+    #[doc = "Internal 1.0V reference"]
+    INT1V,
+    #[doc = "AVCC"]
+    AVCC,
+    #[doc = "External reference"]
+    VREFP,
+    #[doc = r" Reserved"]
+    _Reserved(u8),
+}
+impl REFSELR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bits(&self) -> u8 {
+        match *self {
+            REFSELR::INT1V => 0,
+            REFSELR::AVCC => 1,
+            REFSELR::VREFP => 2,
+            REFSELR::_Reserved(bits) => bits,
+        }
+    }
+    #[allow(missing_docs)]
+    #[doc(hidden)]
+    #[inline]
+    pub fn _from(value: u8) -> REFSELR {
+        match value {
+            0 => REFSELR::INT1V,
+            1 => REFSELR::AVCC,
+            2 => REFSELR::VREFP,
+            i => REFSELR::_Reserved(i),
+        }
+    }
+    #[doc = "Checks if the value of the field is `INT1V`"]
+    #[inline]
+    pub fn is_int1v(&self) -> bool {
+        *self == REFSELR::INT1V
+    }
+    #[doc = "Checks if the value of the field is `AVCC`"]
+    #[inline]
+    pub fn is_avcc(&self) -> bool {
+        *self == REFSELR::AVCC
+    }
+    #[doc = "Checks if the value of the field is `VREFP`"]
+    #[inline]
+    pub fn is_vrefp(&self) -> bool {
+        *self == REFSELR::VREFP
+    }
+}
+#[doc = r" Proxy"]
+pub struct _EOENW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _EOENW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 0;
+        self.w.bits &= !((MASK as u8) << OFFSET);
+        self.w.bits |= ((value & MASK) as u8) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _IOENW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _IOENW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 1;
+        self.w.bits &= !((MASK as u8) << OFFSET);
+        self.w.bits |= ((value & MASK) as u8) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _LEFTADJW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _LEFTADJW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 2;
+        self.w.bits &= !((MASK as u8) << OFFSET);
+        self.w.bits |= ((value & MASK) as u8) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _VPDW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _VPDW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 3;
+        self.w.bits &= !((MASK as u8) << OFFSET);
+        self.w.bits |= ((value & MASK) as u8) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _BDWPW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _BDWPW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 4;
+        self.w.bits &= !((MASK as u8) << OFFSET);
+        self.w.bits |= ((value & MASK) as u8) << OFFSET;
+        self.w
+    }
+}
+#[doc = "Values that can be written to the field `REFSEL`"]
+pub enum REFSELW {
+    #[doc = "Internal 1.0V reference"]
+    INT1V,
+    #[doc = "AVCC"]
+    AVCC,
+    #[doc = "External reference"]
+    VREFP,
+}
+impl REFSELW {
+    #[allow(missing_docs)]
+    #[doc(hidden)]
+    #[inline]
+    pub fn _bits(&self) -> u8 {
+        match *self {
+            REFSELW::INT1V => 0,
+            REFSELW::AVCC => 1,
+            REFSELW::VREFP => 2,
+        }
+    }
+}
+#[doc = r" Proxy"]
+pub struct _REFSELW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _REFSELW<'a> {
+    #[doc = r" Writes `variant` to the field"]
+    #[inline]
+    pub fn variant(self, variant: REFSELW) -> &'a mut W {
+        unsafe { self.bits(variant._bits()) }
+    }
+    #[doc = "Internal 1.0V reference"]
+    #[inline]
+    pub fn int1v(self) -> &'a mut W {
+        self.variant(REFSELW::INT1V)
+    }
+    #[doc = "AVCC"]
+    #[inline]
+    pub fn avcc(self) -> &'a mut W {
+        self.variant(REFSELW::AVCC)
+    }
+    #[doc = "External reference"]
+    #[inline]
+    pub fn vrefp(self) -> &'a mut W {
+        self.variant(REFSELW::VREFP)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub unsafe fn bits(self, value: u8) -> &'a mut W {
+        const MASK: u8 = 3;
+        const OFFSET: u8 = 6;
+        self.w.bits &= !((MASK as u8) << OFFSET);
+        self.w.bits |= ((value & MASK) as u8) << OFFSET;
+        self.w
+    }
+}
+impl R {
+    #[doc = r" Value of the register as raw bits"]
+    #[inline]
+    pub fn bits(&self) -> u8 {
+        self.bits
+    }
+    #[doc = "Bit 0 - External Output Enable"]
+    #[inline]
+    pub fn eoen(&self) -> EOENR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 0;
+            ((self.bits >> OFFSET) & MASK as u8) != 0
+        };
+        EOENR { bits }
+    }
+    #[doc = "Bit 1 - Internal Output Enable"]
+    #[inline]
+    pub fn ioen(&self) -> IOENR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 1;
+            ((self.bits >> OFFSET) & MASK as u8) != 0
+        };
+        IOENR { bits }
+    }
+    #[doc = "Bit 2 - Left Adjusted Data"]
+    #[inline]
+    pub fn leftadj(&self) -> LEFTADJR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 2;
+            ((self.bits >> OFFSET) & MASK as u8) != 0
+        };
+        LEFTADJR { bits }
+    }
+    #[doc = "Bit 3 - Voltage Pump Disable"]
+    #[inline]
+    pub fn vpd(&self) -> VPDR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 3;
+            ((self.bits >> OFFSET) & MASK as u8) != 0
+        };
+        VPDR { bits }
+    }
+    #[doc = "Bit 4 - Bypass DATABUF Write Protection"]
+    #[inline]
+    pub fn bdwp(&self) -> BDWPR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 4;
+            ((self.bits >> OFFSET) & MASK as u8) != 0
+        };
+        BDWPR { bits }
+    }
+    #[doc = "Bits 6:7 - Reference Selection"]
+    #[inline]
+    pub fn refsel(&self) -> REFSELR {
+        REFSELR::_from({
+            const MASK: u8 = 3;
+            const OFFSET: u8 = 6;
+            ((self.bits >> OFFSET) & MASK as u8) as u8
+        })
+    }
+}
+impl W {
+    #[doc = r" Reset value of the register"]
+    #[inline]
+    pub fn reset_value() -> W {
+        W { bits: 0 }
+    }
+    #[doc = r" Writes raw bits to the register"]
+    #[inline]
+    pub unsafe fn bits(&mut self, bits: u8) -> &mut Self {
+        self.bits = bits;
+        self
+    }
+    #[doc = "Bit 0 - External Output Enable"]
+    #[inline]
+    pub fn eoen(&mut self) -> _EOENW {
+        _EOENW { w: self }
+    }
+    #[doc = "Bit 1 - Internal Output Enable"]
+    #[inline]
+    pub fn ioen(&mut self) -> _IOENW {
+        _IOENW { w: self }
+    }
+    #[doc = "Bit 2 - Left Adjusted Data"]
+    #[inline]
+    pub fn leftadj(&mut self) -> _LEFTADJW {
+        _LEFTADJW { w: self }
+    }
+    #[doc = "Bit 3 - Voltage Pump Disable"]
+    #[inline]
+    pub fn vpd(&mut self) -> _VPDW {
+        _VPDW { w: self }
+    }
+    #[doc = "Bit 4 - Bypass DATABUF Write Protection"]
+    #[inline]
+    pub fn bdwp(&mut self) -> _BDWPW {
+        _BDWPW { w: self }
+    }
+    #[doc = "Bits 6:7 - Reference Selection"]
+    #[inline]
+    pub fn refsel(&mut self) -> _REFSELW {
+        _REFSELW { w: self }
+    }
+}
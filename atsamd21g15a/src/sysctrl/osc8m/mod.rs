@@ -0,0 +1,554 @@
+#[doc = r" Value read from the register"]
+pub struct R {
+    bits: u32,
+}
+#[doc = r" Value to write to the register"]
+pub struct W {
+    bits: u32,
+}
+impl super::OSC8M {
+    #[doc = r" Modifies the contents of the register"]
+    #[inline]
+    pub fn modify<F>(&self, f: F)
+    where
+        for<'w> F: FnOnce(&R, &'w mut W) -> &'w mut W,
+    {
+        let bits = self.register.get();
+        let r = R { bits };
+        let mut w = W { bits };
+        f(&r, &mut w);
+        self.register.set(w.bits);
+    }
+    #[doc = r" Reads the contents of the register"]
+    #[inline]
+    pub fn read(&self) -> R {
+        R {
+            bits: self.register.get(),
+        }
+    }
+    #[doc = r" Writes to the register"]
+    #[inline]
+    pub fn write<F>(&self, f: F)
+    where
+        F: FnOnce(&mut W) -> &mut W,
+    {
+        let mut w = W::reset_value();
+        f(&mut w);
+        self.register.set(w.bits);
+    }
+    #[doc = r" Writes the reset value to the register"]
+    #[inline]
+    pub fn reset(&self) {
+        self.write(|w| w)
+    }
+}
+#[doc = r" Value of the field"]
+pub struct ENABLER {
+    bits: bool,
+}
+impl ENABLER {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = r" Value of the field"]
+pub struct RUNSTDBYR {
+    bits: bool,
+}
+impl RUNSTDBYR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = r" Value of the field"]
+pub struct ONDEMANDR {
+    bits: bool,
+}
+impl ONDEMANDR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bit(&self) -> bool {
+        self.bits
+    }
+    #[doc = r" Returns `true` if the bit is clear (0)"]
+    #[inline]
+    pub fn bit_is_clear(&self) -> bool {
+        !self.bit()
+    }
+    #[doc = r" Returns `true` if the bit is set (1)"]
+    #[inline]
+    pub fn bit_is_set(&self) -> bool {
+        self.bit()
+    }
+}
+#[doc = "Possible values of the field `PRESC`"]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PRESCR {
+    #[doc = "1"]
+    _0,
+    #[doc = "2"]
+    _1,
+    #[doc = "4"]
+    _2,
+    #[doc = "8"]
+    _3,
+}
+impl PRESCR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bits(&self) -> u8 {
+        match *self {
+            PRESCR::_0 => 0,
+            PRESCR::_1 => 1,
+            PRESCR::_2 => 2,
+            PRESCR::_3 => 3,
+        }
+    }
+    #[allow(missing_docs)]
+    #[doc(hidden)]
+    #[inline]
+    pub fn _from(value: u8) -> PRESCR {
+        match value {
+            0 => PRESCR::_0,
+            1 => PRESCR::_1,
+            2 => PRESCR::_2,
+            3 => PRESCR::_3,
+            _ => unreachable!(),
+        }
+    }
+    #[doc = "Checks if the value of the field is `_0`"]
+    #[inline]
+    pub fn is_0(&self) -> bool {
+        *self == PRESCR::_0
+    }
+    #[doc = "Checks if the value of the field is `_1`"]
+    #[inline]
+    pub fn is_1(&self) -> bool {
+        *self == PRESCR::_1
+    }
+    #[doc = "Checks if the value of the field is `_2`"]
+    #[inline]
+    pub fn is_2(&self) -> bool {
+        *self == PRESCR::_2
+    }
+    #[doc = "Checks if the value of the field is `_3`"]
+    #[inline]
+    pub fn is_3(&self) -> bool {
+        *self == PRESCR::_3
+    }
+}
+#[doc = r" Value of the field"]
+pub struct CALIBR {
+    bits: u16,
+}
+impl CALIBR {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bits(&self) -> u16 {
+        self.bits
+    }
+}
+#[doc = "Possible values of the field `FRANGE`"]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FRANGER {
+    #[doc = "4 to 6MHz"]
+    _0,
+    #[doc = "6 to 8MHz"]
+    _1,
+    #[doc = "8 to 11MHz"]
+    _2,
+    #[doc = "11 to 15MHz"]
+    _3,
+}
+impl FRANGER {
+    #[doc = r" Value of the field as raw bits"]
+    #[inline]
+    pub fn bits(&self) -> u8 {
+        match *self {
+            FRANGER::_0 => 0,
+            FRANGER::_1 => 1,
+            FRANGER::_2 => 2,
+            FRANGER::_3 => 3,
+        }
+    }
+    #[allow(missing_docs)]
+    #[doc(hidden)]
+    #[inline]
+    pub fn _from(value: u8) -> FRANGER {
+        match value {
+            0 => FRANGER::_0,
+            1 => FRANGER::_1,
+            2 => FRANGER::_2,
+            3 => FRANGER::_3,
+            _ => unreachable!(),
+        }
+    }
+    #[doc = "Checks if the value of the field is `_0`"]
+    #[inline]
+    pub fn is_0(&self) -> bool {
+        *self == FRANGER::_0
+    }
+    #[doc = "Checks if the value of the field is `_1`"]
+    #[inline]
+    pub fn is_1(&self) -> bool {
+        *self == FRANGER::_1
+    }
+    #[doc = "Checks if the value of the field is `_2`"]
+    #[inline]
+    pub fn is_2(&self) -> bool {
+        *self == FRANGER::_2
+    }
+    #[doc = "Checks if the value of the field is `_3`"]
+    #[inline]
+    pub fn is_3(&self) -> bool {
+        *self == FRANGER::_3
+    }
+}
+#[doc = r" Proxy"]
+pub struct _ENABLEW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _ENABLEW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 1;
+        self.w.bits &= !((MASK as u32) << OFFSET);
+        self.w.bits |= ((value & MASK) as u32) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _RUNSTDBYW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _RUNSTDBYW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 6;
+        self.w.bits &= !((MASK as u32) << OFFSET);
+        self.w.bits |= ((value & MASK) as u32) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _ONDEMANDW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _ONDEMANDW<'a> {
+    #[doc = r" Sets the field bit"]
+    pub fn set_bit(self) -> &'a mut W {
+        self.bit(true)
+    }
+    #[doc = r" Clears the field bit"]
+    pub fn clear_bit(self) -> &'a mut W {
+        self.bit(false)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bit(self, value: bool) -> &'a mut W {
+        const MASK: bool = true;
+        const OFFSET: u8 = 7;
+        self.w.bits &= !((MASK as u32) << OFFSET);
+        self.w.bits |= ((value & MASK) as u32) << OFFSET;
+        self.w
+    }
+}
+#[doc = "Values that can be written to the field `PRESC`"]
+pub enum PRESCW {
+    #[doc = "1"]
+    _0,
+    #[doc = "2"]
+    _1,
+    #[doc = "4"]
+    _2,
+    #[doc = "8"]
+    _3,
+}
+impl PRESCW {
+    #[allow(missing_docs)]
+    #[doc(hidden)]
+    #[inline]
+    pub fn _bits(&self) -> u8 {
+        match *self {
+            PRESCW::_0 => 0,
+            PRESCW::_1 => 1,
+            PRESCW::_2 => 2,
+            PRESCW::_3 => 3,
+        }
+    }
+}
+#[doc = r" Proxy"]
+pub struct _PRESCW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _PRESCW<'a> {
+    #[doc = r" Writes `variant` to the field"]
+    #[inline]
+    pub fn variant(self, variant: PRESCW) -> &'a mut W {
+        {
+            self.bits(variant._bits())
+        }
+    }
+    #[doc = "1"]
+    #[inline]
+    pub fn _0(self) -> &'a mut W {
+        self.variant(PRESCW::_0)
+    }
+    #[doc = "2"]
+    #[inline]
+    pub fn _1(self) -> &'a mut W {
+        self.variant(PRESCW::_1)
+    }
+    #[doc = "4"]
+    #[inline]
+    pub fn _2(self) -> &'a mut W {
+        self.variant(PRESCW::_2)
+    }
+    #[doc = "8"]
+    #[inline]
+    pub fn _3(self) -> &'a mut W {
+        self.variant(PRESCW::_3)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bits(self, value: u8) -> &'a mut W {
+        const MASK: u8 = 3;
+        const OFFSET: u8 = 8;
+        self.w.bits &= !((MASK as u32) << OFFSET);
+        self.w.bits |= ((value & MASK) as u32) << OFFSET;
+        self.w
+    }
+}
+#[doc = r" Proxy"]
+pub struct _CALIBW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _CALIBW<'a> {
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub unsafe fn bits(self, value: u16) -> &'a mut W {
+        const MASK: u16 = 4095;
+        const OFFSET: u8 = 16;
+        self.w.bits &= !((MASK as u32) << OFFSET);
+        self.w.bits |= ((value & MASK) as u32) << OFFSET;
+        self.w
+    }
+}
+#[doc = "Values that can be written to the field `FRANGE`"]
+pub enum FRANGEW {
+    #[doc = "4 to 6MHz"]
+    _0,
+    #[doc = "6 to 8MHz"]
+    _1,
+    #[doc = "8 to 11MHz"]
+    _2,
+    #[doc = "11 to 15MHz"]
+    _3,
+}
+impl FRANGEW {
+    #[allow(missing_docs)]
+    #[doc(hidden)]
+    #[inline]
+    pub fn _bits(&self) -> u8 {
+        match *self {
+            FRANGEW::_0 => 0,
+            FRANGEW::_1 => 1,
+            FRANGEW::_2 => 2,
+            FRANGEW::_3 => 3,
+        }
+    }
+}
+#[doc = r" Proxy"]
+pub struct _FRANGEW<'a> {
+    w: &'a mut W,
+}
+impl<'a> _FRANGEW<'a> {
+    #[doc = r" Writes `variant` to the field"]
+    #[inline]
+    pub fn variant(self, variant: FRANGEW) -> &'a mut W {
+        {
+            self.bits(variant._bits())
+        }
+    }
+    #[doc = "4 to 6MHz"]
+    #[inline]
+    pub fn _0(self) -> &'a mut W {
+        self.variant(FRANGEW::_0)
+    }
+    #[doc = "6 to 8MHz"]
+    #[inline]
+    pub fn _1(self) -> &'a mut W {
+        self.variant(FRANGEW::_1)
+    }
+    #[doc = "8 to 11MHz"]
+    #[inline]
+    pub fn _2(self) -> &'a mut W {
+        self.variant(FRANGEW::_2)
+    }
+    #[doc = "11 to 15MHz"]
+    #[inline]
+    pub fn _3(self) -> &'a mut W {
+        self.variant(FRANGEW::_3)
+    }
+    #[doc = r" Writes raw bits to the field"]
+    #[inline]
+    pub fn bits(self, value: u8) -> &'a mut W {
+        const MASK: u8 = 3;
+        const OFFSET: u8 = 30;
+        self.w.bits &= !((MASK as u32) << OFFSET);
+        self.w.bits |= ((value & MASK) as u32) << OFFSET;
+        self.w
+    }
+}
+impl R {
+    #[doc = r" Value of the register as raw bits"]
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+    #[doc = "Bit 1 - Oscillator Enable"]
+    #[inline]
+    pub fn enable(&self) -> ENABLER {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 1;
+            ((self.bits >> OFFSET) & MASK as u32) != 0
+        };
+        ENABLER { bits }
+    }
+    #[doc = "Bit 6 - Run in Standby"]
+    #[inline]
+    pub fn runstdby(&self) -> RUNSTDBYR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 6;
+            ((self.bits >> OFFSET) & MASK as u32) != 0
+        };
+        RUNSTDBYR { bits }
+    }
+    #[doc = "Bit 7 - On Demand Control"]
+    #[inline]
+    pub fn ondemand(&self) -> ONDEMANDR {
+        let bits = {
+            const MASK: bool = true;
+            const OFFSET: u8 = 7;
+            ((self.bits >> OFFSET) & MASK as u32) != 0
+        };
+        ONDEMANDR { bits }
+    }
+    #[doc = "Bits 8:9 - Oscillator Prescaler"]
+    #[inline]
+    pub fn presc(&self) -> PRESCR {
+        PRESCR::_from({
+            const MASK: u8 = 3;
+            const OFFSET: u8 = 8;
+            ((self.bits >> OFFSET) & MASK as u32) as u8
+        })
+    }
+    #[doc = "Bits 16:27 - Oscillator Calibration"]
+    #[inline]
+    pub fn calib(&self) -> CALIBR {
+        let bits = {
+            const MASK: u16 = 4095;
+            const OFFSET: u8 = 16;
+            ((self.bits >> OFFSET) & MASK as u32) as u16
+        };
+        CALIBR { bits }
+    }
+    #[doc = "Bits 30:31 - Oscillator Frequency Range"]
+    #[inline]
+    pub fn frange(&self) -> FRANGER {
+        FRANGER::_from({
+            const MASK: u8 = 3;
+            const OFFSET: u8 = 30;
+            ((self.bits >> OFFSET) & MASK as u32) as u8
+        })
+    }
+}
+impl W {
+    #[doc = r" Reset value of the register"]
+    #[inline]
+    pub fn reset_value() -> W {
+        W { bits: 2265383810 }
+    }
+    #[doc = r" Writes raw bits to the register"]
+    #[inline]
+    pub unsafe fn bits(&mut self, bits: u32) -> &mut Self {
+        self.bits = bits;
+        self
+    }
+    #[doc = "Bit 1 - Oscillator Enable"]
+    #[inline]
+    pub fn enable(&mut self) -> _ENABLEW {
+        _ENABLEW { w: self }
+    }
+    #[doc = "Bit 6 - Run in Standby"]
+    #[inline]
+    pub fn runstdby(&mut self) -> _RUNSTDBYW {
+        _RUNSTDBYW { w: self }
+    }
+    #[doc = "Bit 7 - On Demand Control"]
+    #[inline]
+    pub fn ondemand(&mut self) -> _ONDEMANDW {
+        _ONDEMANDW { w: self }
+    }
+    #[doc = "Bits 8:9 - Oscillator Prescaler"]
+    #[inline]
+    pub fn presc(&mut self) -> _PRESCW {
+        _PRESCW { w: self }
+    }
+    #[doc = "Bits 16:27 - Oscillator Calibration"]
+    #[inline]
+    pub fn calib(&mut self) -> _CALIBW {
+        _CALIBW { w: self }
+    }
+    #[doc = "Bits 30:31 - Oscillator Frequency Range"]
+    #[inline]
+    pub fn frange(&mut self) -> _FRANGEW {
+        _FRANGEW { w: self }
+    }
+}
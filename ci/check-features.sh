@@ -0,0 +1,14 @@
+#!/bin/sh
+# Builds the HAL once for each of the chip features listed in
+# hal/Cargo.toml, so that code that is conditional on the part
+# is compiled for every supported part.
+set -e
+
+top=$(cd $(dirname $0)/.. && pwd)
+features=$(sed -n -e 's/^\(samd21[a-z0-9]*\) = .*/\1/p' $top/hal/Cargo.toml)
+
+for feature in $features ; do
+  echo "Checking $feature"
+  cargo build --manifest-path $top/hal/Cargo.toml --target thumbv6m-none-eabi \
+    --no-default-features --features "$feature unproven"
+done
@@ -0,0 +1,61 @@
+#![feature(used)]
+#![no_std]
+
+extern crate circuit_playground_express as hal;
+extern crate panic_abort;
+
+use hal::clock::GenericClockController;
+use hal::dma::Dma;
+use hal::i2s::Serializer;
+use hal::prelude::*;
+use hal::Peripherals;
+
+/// The number of 32 bit words of PDM data in each block, which is
+/// 16ms at 2Mhz
+const WORDS: usize = 1024;
+
+/// Lights the red LED while the microphone hears a loud sound.
+/// The density of ones in a PDM stream follows the audio signal, so
+/// the spread of the number of ones in each word gives a rough level.
+fn main() {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut clocks = GenericClockController::new(
+        peripherals.GCLK,
+        &mut peripherals.PM,
+        &mut peripherals.SYSCTRL,
+        &mut peripherals.NVMCTRL,
+    );
+    let mut pins = hal::pins(peripherals.PORT);
+    let mut red_led = pins.d13.into_open_drain_output(&mut pins.port);
+
+    let mut channels = Dma::new(peripherals.DMAC, &mut peripherals.PM).split();
+    let mut mic = hal::pdm_microphone(
+        &mut clocks,
+        2.mhz(),
+        peripherals.I2S,
+        &mut peripherals.PM,
+        pins.mic_clk,
+        pins.mic_data,
+        &mut pins.port,
+    )
+    .unwrap();
+    mic.enable();
+
+    let mut buffer = [0u32; WORDS];
+    loop {
+        unsafe {
+            mic.receive_dma(Serializer::Serializer1, &mut channels.ch0, &mut buffer, false);
+        }
+        channels.ch0.wait().unwrap();
+
+        let (min, max) = buffer
+            .iter()
+            .map(|word| word.count_ones())
+            .fold((32, 0), |(min, max), ones| (min.min(ones), max.max(ones)));
+        if max - min > 16 {
+            red_led.set_high();
+        } else {
+            red_led.set_low();
+        }
+    }
+}
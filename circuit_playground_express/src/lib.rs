@@ -0,0 +1,270 @@
+#![no_std]
+
+extern crate atsamd21_hal as hal;
+
+pub use hal::atsamd21g18a::*;
+use hal::prelude::*;
+pub use hal::*;
+
+use gpio::{Floating, Input, Output, Port, PushPull};
+use hal::clock::GenericClockController;
+use hal::i2s::{self, ClockConfig, ClockUnit, ClockUnit0Pins, I2s, I2sPin, SerializerConfig};
+use hal::sercom::{I2CMaster1, I2CMaster5, PadPin, SPIMaster3, UART4};
+use hal::time::Hertz;
+
+/// Maps the pins to the names printed on the board and
+/// the on-board devices they are wired to.
+pub struct Pins {
+    /// Opaque port reference
+    pub port: Port,
+
+    /// Pad A0.  Can act as a true analog output as it has a DAC,
+    /// and is also wired to the speaker amplifier.
+    pub a0: gpio::Pa2<Input<Floating>>,
+    /// Pad A1, a capacitive touch pad
+    pub a1: gpio::Pa5<Input<Floating>>,
+    /// Pad A2, a capacitive touch pad
+    pub a2: gpio::Pa6<Input<Floating>>,
+    /// Pad A3, a capacitive touch pad
+    pub a3: gpio::Pa7<Input<Floating>>,
+    /// Pad A4, a capacitive touch pad and the external I2C SCL
+    pub a4: gpio::Pb3<Input<Floating>>,
+    /// Pad A5, a capacitive touch pad and the external I2C SDA
+    pub a5: gpio::Pb2<Input<Floating>>,
+    /// Pad A6, a capacitive touch pad and the UART RX
+    pub a6: gpio::Pb9<Input<Floating>>,
+    /// Pad A7, a capacitive touch pad and the UART TX
+    pub a7: gpio::Pb8<Input<Floating>>,
+
+    /// Digital pin number 13, which is attached to
+    /// the red LED.  PWM capable.
+    pub d13: gpio::Pa17<Input<Floating>>,
+
+    /// The data line of the ring of ten neopixels
+    pub neopixel: gpio::Pb23<Input<Floating>>,
+
+    /// The left button, which reads high while pressed
+    pub button_a: gpio::Pa28<Input<Floating>>,
+    /// The right button, which reads high while pressed
+    pub button_b: gpio::Pa14<Input<Floating>>,
+    /// The slide switch, which needs a pull up and reads low
+    /// when switched to the left
+    pub slide_switch: gpio::Pa15<Input<Floating>>,
+
+    /// The analog output of the light sensor
+    pub light: gpio::Pa11<Input<Floating>>,
+    /// The analog output of the thermistor
+    pub temperature: gpio::Pa9<Input<Floating>>,
+
+    /// The clock of the PDM microphone
+    pub mic_clk: gpio::Pa10<Input<Floating>>,
+    /// The data line of the PDM microphone
+    pub mic_data: gpio::Pa8<Input<Floating>>,
+
+    /// The SDA line of the internal I2C bus to the accelerometer
+    pub accel_sda: gpio::Pa0<Input<Floating>>,
+    /// The SCL line of the internal I2C bus to the accelerometer
+    pub accel_scl: gpio::Pa1<Input<Floating>>,
+    /// The interrupt line of the accelerometer
+    pub accel_int: gpio::Pa13<Input<Floating>>,
+
+    /// Drives the infrared LED
+    pub ir_tx: gpio::Pa23<Input<Floating>>,
+    /// The output of the infrared receiver
+    pub ir_rx: gpio::Pa12<Input<Floating>>,
+    /// The analog input used for infrared proximity sensing
+    pub ir_proximity: gpio::Pa4<Input<Floating>>,
+
+    /// Enables the speaker amplifier when driven high
+    pub speaker_enable: gpio::Pa30<Input<Floating>>,
+
+    /// The SCK pin attached to the on-board SPI flash
+    pub flash_sck: gpio::Pa21<Input<Floating>>,
+    /// The MOSI pin attached to the on-board SPI flash
+    pub flash_mosi: gpio::Pa20<Input<Floating>>,
+    /// The MISO pin attached to the on-board SPI flash
+    pub flash_miso: gpio::Pa16<Input<Floating>>,
+    /// The CS pin attached to the on-board SPI flash
+    pub flash_cs: gpio::Pb22<Input<Floating>>,
+
+    /// The USB D- pad
+    pub usb_dm: gpio::Pa24<Input<Floating>>,
+    /// The USB D+ pad
+    pub usb_dp: gpio::Pa25<Input<Floating>>,
+}
+
+/// Returns the pins for the device
+pub fn pins(port: atsamd21g18a::PORT) -> Pins {
+    let pins = port.split();
+    Pins {
+        port: pins.port,
+        a0: pins.pa2,
+        a1: pins.pa5,
+        a2: pins.pa6,
+        a3: pins.pa7,
+        a4: pins.pb3,
+        a5: pins.pb2,
+        a6: pins.pb9,
+        a7: pins.pb8,
+        d13: pins.pa17,
+        neopixel: pins.pb23,
+        button_a: pins.pa28,
+        button_b: pins.pa14,
+        slide_switch: pins.pa15,
+        light: pins.pa11,
+        temperature: pins.pa9,
+        mic_clk: pins.pa10,
+        mic_data: pins.pa8,
+        accel_sda: pins.pa0,
+        accel_scl: pins.pa1,
+        accel_int: pins.pa13,
+        ir_tx: pins.pa23,
+        ir_rx: pins.pa12,
+        ir_proximity: pins.pa4,
+        speaker_enable: pins.pa30,
+
+        flash_sck: pins.pa21,
+        flash_mosi: pins.pa20,
+        flash_miso: pins.pa16,
+        flash_cs: pins.pb22,
+
+        usb_dm: pins.pa24,
+        usb_dp: pins.pa25,
+    }
+}
+
+/// Convenience for accessing the on-board SPI Flash device.
+/// This powers up SERCOM3 and configures it for use as an
+/// SPI Master.
+pub fn flash_spi_master(
+    clocks: &mut GenericClockController,
+    sercom3: SERCOM3,
+    pm: &mut PM,
+    sck: gpio::Pa21<Input<Floating>>,
+    mosi: gpio::Pa20<Input<Floating>>,
+    miso: gpio::Pa16<Input<Floating>>,
+    cs: gpio::Pb22<Input<Floating>>,
+    port: &mut Port,
+) -> (SPIMaster3, gpio::Pb22<Output<PushPull>>) {
+    let gclk0 = clocks.gclk0();
+    let flash = SPIMaster3::new(
+        &clocks.sercom3_core(&gclk0).unwrap(),
+        48.mhz(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom3,
+        pm,
+        hal::sercom::SPI3Pinout::Dipo0Dopo1 {
+            miso: miso.into_pad(port),
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    );
+
+    let mut cs = cs.into_push_pull_output(port);
+    cs.set_high();
+
+    (flash, cs)
+}
+
+/// Convenience for talking to the on-board LIS3DH accelerometer,
+/// which is at address 0x19.
+/// This powers up SERCOM1 and configures it as an I2C master
+/// running at the specified frequency.
+pub fn accel_i2c_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom1: SERCOM1,
+    pm: &mut PM,
+    sda: gpio::Pa0<Input<Floating>>,
+    scl: gpio::Pa1<Input<Floating>>,
+    port: &mut Port,
+) -> I2CMaster1 {
+    let gclk0 = clocks.gclk0();
+    I2CMaster1::new(
+        &clocks.sercom1_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        sercom1,
+        pm,
+        sda.into_pad(port),
+        scl.into_pad(port),
+    )
+}
+
+/// Convenience for setting up pads A5 and A4 to operate as an I2C
+/// master running at the specified frequency, with SDA on A5
+/// and SCL on A4.
+pub fn i2c_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom5: SERCOM5,
+    pm: &mut PM,
+    sda: gpio::Pb2<Input<Floating>>,
+    scl: gpio::Pb3<Input<Floating>>,
+    port: &mut Port,
+) -> I2CMaster5 {
+    let gclk0 = clocks.gclk0();
+    I2CMaster5::new(
+        &clocks.sercom5_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        sercom5,
+        pm,
+        sda.into_pad(port),
+        scl.into_pad(port),
+    )
+}
+
+/// Convenience for setting up pads A6 and A7 as a UART running at
+/// the specified baud rate, with RX on A6 and TX on A7.
+pub fn uart<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    baud: F,
+    sercom4: SERCOM4,
+    pm: &mut PM,
+    rx: gpio::Pb9<Input<Floating>>,
+    tx: gpio::Pb8<Input<Floating>>,
+    port: &mut Port,
+) -> UART4 {
+    let gclk0 = clocks.gclk0();
+    UART4::new(
+        &clocks.sercom4_core(&gclk0).unwrap(),
+        baud.into(),
+        sercom4,
+        pm,
+        hal::sercom::UART4Pinout::Rx1Tx0 {
+            rx: rx.into_pad(port),
+            tx: tx.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for recording from the on-board PDM microphone.
+/// This powers up the I2S controller and clocks the microphone at
+/// `clock_rate` from clock unit 0, receiving its raw PDM bits on
+/// serializer 1.  The controller is left disabled so that the caller
+/// can start DMA before calling `enable`.
+pub fn pdm_microphone<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    clock_rate: F,
+    i2s: I2S,
+    pm: &mut PM,
+    clk: gpio::Pa10<Input<Floating>>,
+    data: gpio::Pa8<Input<Floating>>,
+    port: &mut Port,
+) -> Result<I2s, i2s::Error> {
+    let gclk0 = clocks.gclk0();
+    let mut mic = I2s::new(i2s, pm);
+    mic.clock_unit0(
+        &clocks.i2s0(&gclk0).unwrap(),
+        ClockConfig::pdm(clock_rate),
+        ClockUnit0Pins {
+            sck: clk.into_i2s(port),
+            fs: None,
+            mck: None,
+        },
+    )?;
+    mic.serializer1(SerializerConfig::pdm(ClockUnit::Unit0, false), data.into_i2s(port));
+    Ok(mic)
+}
@@ -0,0 +1,30 @@
+#![feature(used)]
+#![no_std]
+
+extern crate feather_m0 as hal;
+extern crate panic_abort;
+
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::prelude::*;
+use hal::{CorePeripherals, Peripherals};
+
+fn main() {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::new(
+        peripherals.GCLK,
+        &mut peripherals.PM,
+        &mut peripherals.SYSCTRL,
+        &mut peripherals.NVMCTRL,
+    );
+    let mut pins = hal::pins(peripherals.PORT);
+    let mut red_led = pins.d13.into_open_drain_output(&mut pins.port);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+    loop {
+        delay.delay_ms(200u8);
+        red_led.set_high();
+        delay.delay_ms(200u8);
+        red_led.set_low();
+    }
+}
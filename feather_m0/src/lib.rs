@@ -0,0 +1,310 @@
+#![no_std]
+
+extern crate atsamd21_hal as hal;
+
+pub use hal::atsamd21g18a::*;
+use hal::prelude::*;
+pub use hal::*;
+
+#[cfg(feature = "rfm")]
+use gpio::Output;
+#[cfg(any(feature = "express", feature = "rfm"))]
+use gpio::PushPull;
+use gpio::{Floating, Input, Port};
+use hal::clock::GenericClockController;
+#[cfg(feature = "express")]
+use hal::sercom::SPIMaster2;
+use hal::sercom::{I2CMaster3, PadPin, SPIMaster4, UART0};
+use hal::time::Hertz;
+
+#[cfg(all(feature = "express", feature = "rfm"))]
+compile_error!("the express and rfm features select different boards");
+
+/// Maps the pins to their arduino names and
+/// the numbers printed on the board.
+pub struct Pins {
+    /// Opaque port reference
+    pub port: Port,
+
+    /// Analog pin 0.  Can act as a true analog output
+    /// as it has a DAC (which is not currently supported
+    /// by this hal) as well as input.
+    pub a0: gpio::Pa2<Input<Floating>>,
+    /// Analog Pin 1
+    pub a1: gpio::Pb8<Input<Floating>>,
+    /// Analog Pin 2
+    pub a2: gpio::Pb9<Input<Floating>>,
+    /// Analog Pin 3
+    pub a3: gpio::Pa4<Input<Floating>>,
+    /// Analog Pin 4
+    pub a4: gpio::Pa5<Input<Floating>>,
+    /// Analog Pin 5
+    pub a5: gpio::Pb2<Input<Floating>>,
+
+    /// Pin 0, rx
+    pub d0: gpio::Pa11<Input<Floating>>,
+    /// Pin 1, tx
+    pub d1: gpio::Pa10<Input<Floating>>,
+    /// Pin 5, PWM capable
+    pub d5: gpio::Pa15<Input<Floating>>,
+    /// Pin 6, PWM capable
+    pub d6: gpio::Pa20<Input<Floating>>,
+    /// Pin 9, PWM capable.  Also analog input (A7), which is
+    /// connected to a divider on the battery voltage
+    pub d9: gpio::Pa7<Input<Floating>>,
+    /// Pin 10, PWM capable
+    pub d10: gpio::Pa18<Input<Floating>>,
+    /// Pin 11, PWM capable
+    pub d11: gpio::Pa16<Input<Floating>>,
+    /// Pin 12, PWM capable
+    pub d12: gpio::Pa19<Input<Floating>>,
+    /// Digital pin number 13, which is also attached to
+    /// the red LED.  PWM capable.
+    pub d13: gpio::Pa17<Input<Floating>>,
+    pub sda: gpio::Pa22<Input<Floating>>,
+    pub scl: gpio::Pa23<Input<Floating>>,
+
+    /// The SPI SCK
+    pub sck: gpio::Pb11<Input<Floating>>,
+    /// The SPI MOSI
+    pub mosi: gpio::Pb10<Input<Floating>>,
+    /// The SPI MISO
+    pub miso: gpio::Pa12<Input<Floating>>,
+
+    /// The data line attached to the neopixel (pin 8)
+    #[cfg(feature = "express")]
+    pub neopixel: gpio::Pa6<Input<Floating>>,
+
+    /// The SCK pin attached to the on-board SPI flash
+    #[cfg(feature = "express")]
+    pub flash_sck: gpio::Pa9<Input<Floating>>,
+    /// The MOSI pin attached to the on-board SPI flash
+    #[cfg(feature = "express")]
+    pub flash_mosi: gpio::Pa8<Input<Floating>>,
+    /// The MISO pin attached to the on-board SPI flash
+    #[cfg(feature = "express")]
+    pub flash_miso: gpio::Pa14<Input<Floating>>,
+    /// The CS pin attached to the on-board SPI flash
+    #[cfg(feature = "express")]
+    pub flash_cs: gpio::Pa13<Input<Floating>>,
+
+    /// The CS pin of the radio module (pin 8).  The radio
+    /// shares the SPI bus with the header.
+    #[cfg(feature = "rfm")]
+    pub rfm_cs: gpio::Pa6<Input<Floating>>,
+    /// The reset pin of the radio module (pin 4)
+    #[cfg(feature = "rfm")]
+    pub rfm_reset: gpio::Pa8<Input<Floating>>,
+    /// The interrupt pin of the radio module (pin 3), which is
+    /// DIO0 on the RFM9x LoRa modules
+    #[cfg(feature = "rfm")]
+    pub rfm_irq: gpio::Pa9<Input<Floating>>,
+
+    /// The USB D- pad
+    pub usb_dm: gpio::Pa24<Input<Floating>>,
+    /// The USB D+ pad
+    pub usb_dp: gpio::Pa25<Input<Floating>>,
+}
+
+/// Returns the pins for the device
+pub fn pins(port: atsamd21g18a::PORT) -> Pins {
+    let pins = port.split();
+    Pins {
+        port: pins.port,
+        a0: pins.pa2,
+        a1: pins.pb8,
+        a2: pins.pb9,
+        a3: pins.pa4,
+        a4: pins.pa5,
+        a5: pins.pb2,
+        d0: pins.pa11,
+        d1: pins.pa10,
+        d5: pins.pa15,
+        d6: pins.pa20,
+        d9: pins.pa7,
+        d10: pins.pa18,
+        d11: pins.pa16,
+        d12: pins.pa19,
+        d13: pins.pa17,
+        sda: pins.pa22,
+        scl: pins.pa23,
+        sck: pins.pb11,
+        mosi: pins.pb10,
+        miso: pins.pa12,
+
+        #[cfg(feature = "express")]
+        neopixel: pins.pa6,
+        #[cfg(feature = "express")]
+        flash_sck: pins.pa9,
+        #[cfg(feature = "express")]
+        flash_mosi: pins.pa8,
+        #[cfg(feature = "express")]
+        flash_miso: pins.pa14,
+        #[cfg(feature = "express")]
+        flash_cs: pins.pa13,
+
+        #[cfg(feature = "rfm")]
+        rfm_cs: pins.pa6,
+        #[cfg(feature = "rfm")]
+        rfm_reset: pins.pa8,
+        #[cfg(feature = "rfm")]
+        rfm_irq: pins.pa9,
+
+        usb_dm: pins.pa24,
+        usb_dp: pins.pa25,
+    }
+}
+
+/// Convenience for setting up the labelled SPI pins.
+/// This powers up SERCOM4 and configures it for use as an
+/// SPI Master in SPI Mode 0.
+/// Configuring a pin for CS is the responsibility of the caller.
+pub fn spi_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom4: SERCOM4,
+    pm: &mut PM,
+    sck: gpio::Pb11<Input<Floating>>,
+    mosi: gpio::Pb10<Input<Floating>>,
+    miso: gpio::Pa12<Input<Floating>>,
+    port: &mut Port,
+) -> SPIMaster4 {
+    let gclk0 = clocks.gclk0();
+    SPIMaster4::new(
+        &clocks.sercom4_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom4,
+        pm,
+        hal::sercom::SPI4Pinout::Dipo0Dopo1 {
+            miso: miso.into_pad(port),
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for accessing the on-board SPI Flash device.
+/// This powers up SERCOM2 and configures it for use as an
+/// SPI Master.
+#[cfg(feature = "express")]
+pub fn flash_spi_master(
+    clocks: &mut GenericClockController,
+    sercom2: SERCOM2,
+    pm: &mut PM,
+    sck: gpio::Pa9<Input<Floating>>,
+    mosi: gpio::Pa8<Input<Floating>>,
+    miso: gpio::Pa14<Input<Floating>>,
+    cs: gpio::Pa13<Input<Floating>>,
+    port: &mut Port,
+) -> (SPIMaster2, gpio::Pa13<gpio::Output<PushPull>>) {
+    let gclk0 = clocks.gclk0();
+    let flash = SPIMaster2::new(
+        &clocks.sercom2_core(&gclk0).unwrap(),
+        48.mhz(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom2,
+        pm,
+        hal::sercom::SPI2Pinout::Dipo2Dopo0 {
+            miso: miso.into_pad(port),
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    );
+
+    let mut cs = cs.into_push_pull_output(port);
+    cs.set_high();
+
+    (flash, cs)
+}
+
+/// The pins of the RFM69 or RFM9x radio module, set up by `rfm_spi_master`
+#[cfg(feature = "rfm")]
+pub struct Radio {
+    /// Held low to select the module
+    pub cs: gpio::Pa6<Output<PushPull>>,
+    /// Pulsed to reset the module; the RFM69 resets while it is high
+    /// and the RFM9x while it is low
+    pub reset: gpio::Pa8<Output<PushPull>>,
+    /// Raised by the module when a packet has been sent or received
+    pub irq: gpio::Pa9<Input<Floating>>,
+}
+
+/// Convenience for talking to the radio module, which is connected to
+/// the SPI bus of the header.  This powers up SERCOM4 and configures it
+/// as an SPI Master in SPI Mode 0 at the 8Mhz that the modules support,
+/// with the radio deselected.
+#[cfg(feature = "rfm")]
+pub fn rfm_spi_master(
+    clocks: &mut GenericClockController,
+    sercom4: SERCOM4,
+    pm: &mut PM,
+    sck: gpio::Pb11<Input<Floating>>,
+    mosi: gpio::Pb10<Input<Floating>>,
+    miso: gpio::Pa12<Input<Floating>>,
+    cs: gpio::Pa6<Input<Floating>>,
+    reset: gpio::Pa8<Input<Floating>>,
+    irq: gpio::Pa9<Input<Floating>>,
+    port: &mut Port,
+) -> (SPIMaster4, Radio) {
+    let spi = spi_master(clocks, 8.mhz(), sercom4, pm, sck, mosi, miso, port);
+
+    let mut cs = cs.into_push_pull_output(port);
+    cs.set_high();
+    let reset = reset.into_push_pull_output(port);
+    let irq = irq.into_floating_input(port);
+
+    (spi, Radio { cs, reset, irq })
+}
+
+/// Convenience for setting up the labelled SDA, SCL pins to
+/// operate as an I2C master running at the specified frequency.
+pub fn i2c_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom3: SERCOM3,
+    pm: &mut PM,
+    sda: gpio::Pa22<Input<Floating>>,
+    scl: gpio::Pa23<Input<Floating>>,
+    port: &mut Port,
+) -> I2CMaster3 {
+    let gclk0 = clocks.gclk0();
+    I2CMaster3::new(
+        &clocks.sercom3_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        sercom3,
+        pm,
+        sda.into_pad(port),
+        scl.into_pad(port),
+    )
+}
+
+/// Convenience for setting up the labelled RX, TX pins to
+/// operate as a UART running at the specified baud rate.
+pub fn uart<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    baud: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    rx: gpio::Pa11<Input<Floating>>,
+    tx: gpio::Pa10<Input<Floating>>,
+    port: &mut Port,
+) -> UART0 {
+    let gclk0 = clocks.gclk0();
+    UART0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        baud.into(),
+        sercom0,
+        pm,
+        hal::sercom::UART0Pinout::Rx3Tx2 {
+            rx: rx.into_pad(port),
+            tx: tx.into_pad(port),
+        },
+    )
+}
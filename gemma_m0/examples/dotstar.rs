@@ -0,0 +1,59 @@
+#![feature(used)]
+#![no_std]
+
+extern crate gemma_m0 as hal;
+extern crate panic_abort;
+
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::leds::apa102::Apa102;
+use hal::leds::RGB8;
+use hal::prelude::*;
+use hal::{CorePeripherals, Peripherals};
+
+/// Fades the on-board DotStar around the color wheel
+fn main() {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::new(
+        peripherals.GCLK,
+        &mut peripherals.PM,
+        &mut peripherals.SYSCTRL,
+        &mut peripherals.NVMCTRL,
+    );
+    let mut pins = hal::pins(peripherals.PORT);
+    let spi = hal::dotstar_spi(
+        &mut clocks,
+        peripherals.SERCOM1,
+        &mut peripherals.PM,
+        pins.mosi,
+        pins.sck,
+        &mut pins.port,
+    );
+    let mut dotstar = Apa102::new(spi);
+    dotstar.set_brightness(4);
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let mut position = 0u8;
+    loop {
+        dotstar.write(Some(wheel(position)).into_iter()).unwrap();
+        position = position.wrapping_add(1);
+        delay.delay_ms(10u8);
+    }
+}
+
+/// Maps 0-255 to a color that goes from red through green and blue
+/// and back to red
+fn wheel(position: u8) -> RGB8 {
+    match position {
+        0...84 => RGB8::new(255 - position * 3, position * 3, 0),
+        85...169 => {
+            let position = position - 85;
+            RGB8::new(0, 255 - position * 3, position * 3)
+        }
+        _ => {
+            let position = position - 170;
+            RGB8::new(position * 3, 0, 255 - position * 3)
+        }
+    }
+}
@@ -7,24 +7,59 @@ use hal::prelude::*;
 pub use hal::*;
 
 use gpio::{Floating, Input, Port};
+use hal::clock::GenericClockController;
+use hal::sercom::{I2CMaster0, PadPin, SPIMaster1, UART0};
+use hal::time::Hertz;
 
 /// Maps the pins to their arduino names and
 /// the numbers printed on the board.
+/// The three pads are shared between several functions, so the
+/// analog input and capacitive touch names are listed on each pad;
+/// the `A0`, `A1` and `A2` types name the pads by those functions.
 pub struct Pins {
     /// Opaque port reference
     pub port: Port,
 
+    /// Pin 0.  Also analog input A2, a capacitive touch pad,
+    /// the UART TX and the I2C SDA
     pub d0: gpio::Pa4<Input<Floating>>,
+    /// Pin 1.  Also analog input A0, which can act as a true analog
+    /// output as it has a DAC, and a capacitive touch pad
     pub d1: gpio::Pa2<Input<Floating>>,
+    /// Pin 2.  Also analog input A1, a capacitive touch pad,
+    /// the UART RX and the I2C SCL
     pub d2: gpio::Pa5<Input<Floating>>,
 
     /// Digital pin number 13, which is also attached to
     /// the red LED.  PWM capable.
     pub d13: gpio::Pa23<Input<Floating>>,
+
+    /// The data line of the on-board DotStar LED
     pub mosi: gpio::Pa0<Input<Floating>>,
+    /// The clock line of the on-board DotStar LED
     pub sck: gpio::Pa1<Input<Floating>>,
+
+    /// The USB D- pad
+    pub usb_dm: gpio::Pa24<Input<Floating>>,
+    /// The USB D+ pad
+    pub usb_dp: gpio::Pa25<Input<Floating>>,
+
+    /// The SWD clock, on the pads on the back of the board
+    pub swclk: gpio::Pa30<Input<Floating>>,
+    /// The SWD data line, on the pads on the back of the board
+    pub swdio: gpio::Pa31<Input<Floating>>,
 }
 
+/// Analog input A0, which can also act as a true analog output as it
+/// has a DAC, and capacitive touch pad A0.  This is the pad of `d1`.
+pub type A0<MODE> = gpio::Pa2<MODE>;
+/// Analog input A1 and capacitive touch pad A1.  This is the pad
+/// of `d2`.
+pub type A1<MODE> = gpio::Pa5<MODE>;
+/// Analog input A2 and capacitive touch pad A2.  This is the pad
+/// of `d0`.
+pub type A2<MODE> = gpio::Pa4<MODE>;
+
 /// Returns the pins for the device
 pub fn pins(port: atsamd21e18a::PORT) -> Pins {
     let pins = port.split();
@@ -36,5 +71,84 @@ pub fn pins(port: atsamd21e18a::PORT) -> Pins {
         d13: pins.pa23,
         sck: pins.pa1,
         mosi: pins.pa0,
+        usb_dm: pins.pa24,
+        usb_dp: pins.pa25,
+        swclk: pins.pa30,
+        swdio: pins.pa31,
     }
 }
+
+/// Convenience for setting up pins 0 and 2 to operate as an I2C
+/// master running at the specified frequency, with SDA on pin 0
+/// and SCL on pin 2.
+pub fn i2c_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    sda: gpio::Pa4<Input<Floating>>,
+    scl: gpio::Pa5<Input<Floating>>,
+    port: &mut Port,
+) -> I2CMaster0 {
+    let gclk0 = clocks.gclk0();
+    I2CMaster0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        sercom0,
+        pm,
+        sda.into_pad(port),
+        scl.into_pad(port),
+    )
+}
+
+/// Convenience for setting up pins 0 and 2 as a UART running at
+/// the specified baud rate, with TX on pin 0 and RX on pin 2.
+pub fn uart<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    baud: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    tx: gpio::Pa4<Input<Floating>>,
+    rx: gpio::Pa5<Input<Floating>>,
+    port: &mut Port,
+) -> UART0 {
+    let gclk0 = clocks.gclk0();
+    UART0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        baud.into(),
+        sercom0,
+        pm,
+        hal::sercom::UART0Pinout::Rx1Tx0 {
+            rx: rx.into_pad(port),
+            tx: tx.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for driving the on-board DotStar LED.
+/// This powers up SERCOM1 and configures it as a write-only
+/// SPI Master in SPI Mode 0, which is what the APA102 expects.
+pub fn dotstar_spi(
+    clocks: &mut GenericClockController,
+    sercom1: SERCOM1,
+    pm: &mut PM,
+    mosi: gpio::Pa0<Input<Floating>>,
+    sck: gpio::Pa1<Input<Floating>>,
+    port: &mut Port,
+) -> SPIMaster1 {
+    let gclk0 = clocks.gclk0();
+    SPIMaster1::new(
+        &clocks.sercom1_core(&gclk0).unwrap(),
+        4.mhz(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom1,
+        pm,
+        hal::sercom::SPI1Pinout::Dopo0 {
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    )
+}
@@ -1,10 +1,20 @@
-//! NVM Software Calibration Area Mapping
+//! NVM Software Calibration Area Mapping and the device serial number.
+//!
+//! The calibration row is written during production testing; the
+//! functions in this module read the individual values, applying the
+//! defaults from the errata where a part was shipped with the field left
+//! erased.  `SoftwareCalibration::read` collects all of them at once.
 // See 10.3.2 NVM Software Calibration Area Mapping, page 46
 
 use core::ptr;
+use core::str;
 
 const ADDR: u32 = 0x806020u32;
 
+/// The addresses of the four words of the serial number;
+/// see 9.6 Serial Number
+const SERIAL_ADDR: [u32; 4] = [0x0080_a00c, 0x0080_a040, 0x0080_a044, 0x0080_a048];
+
 fn cal(addr_offset: u32, bit_shift: u32, bit_mask: u32) -> u32 {
     unsafe {
         let addr: *const u32 = (ADDR + addr_offset) as *const _;
@@ -41,6 +51,11 @@ pub fn dfll48m_coarse_cal() -> u8 {
     cal_with_errata(4, 26, 0x3f, 0x3f, 0x1f) as u8
 }
 
+/// Returns the dfll48m fine calibration value
+pub fn dfll48m_fine_cal() -> u16 {
+    cal(8, 0, 0x3ff) as u16
+}
+
 /// USB TRANSN calibration value. Should be written to USB PADCAL register.
 pub fn usb_transn_cal() -> u8 {
     cal_with_errata(4, 13, 0x1f, 0x1f, 5) as u8
@@ -65,3 +80,81 @@ pub fn adc_linearity() -> u8 {
 pub fn adc_biascal() -> u8 {
     cal(4, 3, 0x07) as u8
 }
+
+/// All of the values in the software calibration row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoftwareCalibration {
+    pub adc_linearity: u8,
+    pub adc_biascal: u8,
+    pub osc32k: u8,
+    pub usb_transn: u8,
+    pub usb_transp: u8,
+    pub usb_trim: u8,
+    pub dfll48m_coarse: u8,
+    pub dfll48m_fine: u16,
+}
+
+impl SoftwareCalibration {
+    /// Reads the calibration row
+    pub fn read() -> Self {
+        Self {
+            adc_linearity: adc_linearity(),
+            adc_biascal: adc_biascal(),
+            osc32k: osc32k_cal(),
+            usb_transn: usb_transn_cal(),
+            usb_transp: usb_transp_cal(),
+            usb_trim: usb_trim_cal(),
+            dfll48m_coarse: dfll48m_coarse_cal(),
+            dfll48m_fine: dfll48m_fine_cal(),
+        }
+    }
+
+    /// Returns false if any of the fields that the errata provides
+    /// defaults for is erased in the calibration row, in which case
+    /// those defaults are used in place of the factory values.
+    pub fn is_factory_programmed() -> bool {
+        cal(4, 26, 0x3f) != 0x3f
+            && cal(4, 13, 0x1f) != 0x1f
+            && cal(4, 18, 0x1f) != 0x1f
+            && cal(4, 23, 7) != 7
+    }
+}
+
+/// The 128 bit serial number that is unique to each device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerialNumber(pub [u32; 4]);
+
+impl SerialNumber {
+    /// Reads the serial number
+    pub fn read() -> Self {
+        let mut words = [0; 4];
+        for (word, &addr) in words.iter_mut().zip(SERIAL_ADDR.iter()) {
+            *word = unsafe { ptr::read(addr as *const u32) };
+        }
+        SerialNumber(words)
+    }
+
+    /// Returns the serial number as bytes, first word first and most
+    /// significant byte first within each word
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (chunk, word) in bytes.chunks_mut(4).zip(self.0.iter()) {
+            chunk[0] = (word >> 24) as u8;
+            chunk[1] = (word >> 16) as u8;
+            chunk[2] = (word >> 8) as u8;
+            chunk[3] = *word as u8;
+        }
+        bytes
+    }
+
+    /// Formats the serial number as 32 upper case hex digits in `buf`,
+    /// which is suitable for use as a USB serial number string
+    pub fn to_hex<'a>(&self, buf: &'a mut [u8; 32]) -> &'a str {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+        for (pair, byte) in buf.chunks_mut(2).zip(self.to_bytes().iter()) {
+            pair[0] = DIGITS[(byte >> 4) as usize];
+            pair[1] = DIGITS[(byte & 0xf) as usize];
+        }
+        str::from_utf8(buf).unwrap()
+    }
+}
@@ -3,6 +3,9 @@
 //! before you can set up most of the peripherals on the atsamd21 device.
 //! The other types in this module are used to enforce at compile time
 //! that the peripherals have been correctly configured.
+use core::fmt;
+use core::ptr;
+use gpio::{self, IntoFunction, Port};
 use target_device::gclk::clkctrl::GENR::*;
 use target_device::gclk::clkctrl::IDR::*;
 use target_device::gclk::genctrl::SRCR::*;
@@ -24,12 +27,63 @@ pub struct GClock {
     freq: Hertz,
 }
 
+impl GClock {
+    /// Returns the clock generator that this `GClock` refers to
+    pub fn id(&self) -> ClockGenId {
+        self.gclk
+    }
+}
+
 impl Into<Hertz> for GClock {
     fn into(self) -> Hertz {
         self.freq
     }
 }
 
+/// The division applied by a clock generator to its source.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GClockDivider {
+    /// Divide the source frequency by the value.  Both 0 and 1
+    /// leave the source undivided.
+    Linear(u16),
+    /// Divide the source frequency by `2^(value+1)`.
+    Exponential(u8),
+}
+
+impl GClockDivider {
+    fn bits(&self) -> u16 {
+        match *self {
+            GClockDivider::Linear(div) => div,
+            GClockDivider::Exponential(exp) => exp as u16,
+        }
+    }
+
+    fn divide(&self, freq: Hertz) -> Hertz {
+        match *self {
+            GClockDivider::Linear(div) => Hertz(freq.0 / (div as u32).max(1)),
+            GClockDivider::Exponential(exp) => {
+                Hertz(freq.0.checked_shr(exp as u32 + 1).unwrap_or(0))
+            }
+        }
+    }
+}
+
+/// A snapshot of the hardware configuration of a clock generator,
+/// as returned by `GenericClockController::gclk_config`.
+#[derive(Debug, Clone, Copy)]
+pub struct GClockConfig {
+    pub gclk: ClockGenId,
+    pub enabled: bool,
+    pub src: ClockSource,
+    pub divider: GClockDivider,
+    pub improve_duty_cycle: bool,
+    /// Whether the generator is driving its GCLK_IO pin
+    pub output_enabled: bool,
+    /// The frequency recorded by the `GenericClockController`; this
+    /// is zero for generators configured outside of it.
+    pub freq: Hertz,
+}
+
 struct State {
     gclk: GCLK,
 }
@@ -49,22 +103,29 @@ impl State {
     fn set_gclk_divider_and_source(
         &mut self,
         gclk: ClockGenId,
-        divider: u16,
+        divider: GClockDivider,
         src: ClockSource,
         improve_duty_cycle: bool,
     ) {
+        // Preserve the state of the GCLK_IO output across reconfiguration
+        self.select_gclk(gclk);
+        let output_enabled = self.gclk.genctrl.read().oe().bit_is_set();
+
         self.gclk.gendiv.write(|w| unsafe {
             w.id().bits(gclk.bits());
-            w.div().bits(divider)
+            w.div().bits(divider.bits())
         });
         self.wait_for_sync();
 
         self.gclk.genctrl.write(|w| unsafe {
             w.id().bits(gclk.bits());
             w.src().bits(src.bits());
-            // divide directly by divider, rather than exponential
-            w.divsel().clear_bit();
+            match divider {
+                GClockDivider::Linear(_) => w.divsel().clear_bit(),
+                GClockDivider::Exponential(_) => w.divsel().set_bit(),
+            };
             w.idc().bit(improve_duty_cycle);
+            w.oe().bit(output_enabled);
             w.genen().set_bit()
         });
         self.wait_for_sync();
@@ -78,6 +139,83 @@ impl State {
         });
         self.wait_for_sync();
     }
+
+    fn disable_clock(&mut self, clock: ClockId) {
+        self.gclk.clkctrl.write(|w| unsafe {
+            w.id().bits(clock.bits());
+            w.clken().clear_bit()
+        });
+        self.wait_for_sync();
+    }
+
+    /// The GENCTRL, GENDIV and CLKCTRL registers are indirect; the
+    /// hardware requires an 8-bit write to the ID field to select
+    /// which generator or clock is returned by a subsequent read.
+    /// The ID field occupies the low byte of each register.
+    fn select_gclk(&mut self, gclk: ClockGenId) {
+        unsafe {
+            ptr::write_volatile(&self.gclk.genctrl as *const _ as *mut u8, gclk.bits());
+            ptr::write_volatile(&self.gclk.gendiv as *const _ as *mut u8, gclk.bits());
+        }
+    }
+
+    fn select_clock(&mut self, clock: ClockId) {
+        unsafe {
+            ptr::write_volatile(&self.gclk.clkctrl as *const _ as *mut u8, clock.bits());
+        }
+    }
+
+    /// Returns the generator that drives `clock`, or `None` if the
+    /// clock is not enabled.
+    fn clock_generator(&mut self, clock: ClockId) -> Option<ClockGenId> {
+        self.select_clock(clock);
+        let clkctrl = self.gclk.clkctrl.read();
+        if clkctrl.clken().bit_is_set() {
+            Some(clkctrl.gen())
+        } else {
+            None
+        }
+    }
+
+    fn gclk_config(&mut self, gclk: ClockGenId) -> (bool, ClockSource, GClockDivider, bool, bool) {
+        self.select_gclk(gclk);
+        let genctrl = self.gclk.genctrl.read();
+        let div = self.gclk.gendiv.read().div().bits();
+        let divider = if genctrl.divsel().bit_is_set() {
+            GClockDivider::Exponential(div as u8)
+        } else {
+            GClockDivider::Linear(div)
+        };
+        (
+            genctrl.genen().bit_is_set(),
+            genctrl.src(),
+            divider,
+            genctrl.idc().bit_is_set(),
+            genctrl.oe().bit_is_set(),
+        )
+    }
+
+    fn set_gclk_run_in_standby(&mut self, gclk: ClockGenId, enable: bool) {
+        self.select_gclk(gclk);
+        let bits = self.gclk.genctrl.read().bits();
+        self.gclk.genctrl.write(|w| unsafe {
+            w.bits(bits);
+            w.id().bits(gclk.bits());
+            w.runstdby().bit(enable)
+        });
+        self.wait_for_sync();
+    }
+
+    fn set_gclk_output(&mut self, gclk: ClockGenId, enable: bool) {
+        self.select_gclk(gclk);
+        let bits = self.gclk.genctrl.read().bits();
+        self.gclk.genctrl.write(|w| unsafe {
+            w.bits(bits);
+            w.id().bits(gclk.bits());
+            w.oe().bit(enable)
+        });
+        self.wait_for_sync();
+    }
 }
 
 /// `GenericClockController` encapsulates the GCLK hardware.
@@ -88,7 +226,7 @@ impl State {
 /// gclk0 at 48Mhz.
 pub struct GenericClockController {
     state: State,
-    gclks: [Hertz; 8],
+    gclks: [Hertz; 9],
     used_clocks: u64,
 }
 
@@ -96,6 +234,35 @@ impl GenericClockController {
     /// Reset the clock controller, configure the system to run
     /// at 48Mhz and reset various clock dividers.
     pub fn new(gclk: GCLK, pm: &mut PM, sysctrl: &mut SYSCTRL, nvmctrl: &mut NVMCTRL) -> Self {
+        Self::init(gclk, pm, sysctrl, nvmctrl, false)
+    }
+
+    /// Reset the clock controller and configure the system to run
+    /// at 48Mhz using the crystal-less USB clock recovery mode of
+    /// the DFLL48M.
+    /// Rather than locking to gclk1, the DFLL48M is locked to the 1Khz
+    /// USB start-of-frame (SOF) signal once the `UsbDevice` is attached
+    /// to a host.  Until the first SOF is received the DFLL48M runs
+    /// open-loop from the factory calibration values, which is accurate
+    /// enough to enumerate but not for sustained USB traffic.
+    /// gclk1 is still configured as a 32khz source so that the other
+    /// peripherals see the same clock tree as with `new`.
+    pub fn with_usb_clock_recovery(
+        gclk: GCLK,
+        pm: &mut PM,
+        sysctrl: &mut SYSCTRL,
+        nvmctrl: &mut NVMCTRL,
+    ) -> Self {
+        Self::init(gclk, pm, sysctrl, nvmctrl, true)
+    }
+
+    fn init(
+        gclk: GCLK,
+        pm: &mut PM,
+        sysctrl: &mut SYSCTRL,
+        nvmctrl: &mut NVMCTRL,
+        usb_clock_recovery: bool,
+    ) -> Self {
         let mut state = State { gclk };
 
         set_flash_to_half_auto_wait_state(nvmctrl);
@@ -105,13 +272,18 @@ impl GenericClockController {
         state.reset_gclk();
 
         // Enable a 32khz source -> GCLK1
-        state.set_gclk_divider_and_source(GCLK1, 1, OSC32K, false);
-        // Feed 32khz into the DFLL48
-        state.enable_clock_generator(DFLL48, GCLK1);
-        // Enable the DFLL48
-        configure_and_enable_dfll48m(sysctrl);
+        state.set_gclk_divider_and_source(GCLK1, GClockDivider::Linear(1), OSC32K, false);
+        if usb_clock_recovery {
+            // The DFLL48 tracks USB SOF, so it needs no reference clock
+            configure_and_enable_dfll48m_usb_recovery(sysctrl);
+        } else {
+            // Feed 32khz into the DFLL48
+            state.enable_clock_generator(DFLL48, GCLK1);
+            // Enable the DFLL48
+            configure_and_enable_dfll48m(sysctrl);
+        }
         // Feed DFLL48 into the main clock
-        state.set_gclk_divider_and_source(GCLK0, 1, DFLL48M, true);
+        state.set_gclk_divider_and_source(GCLK0, GClockDivider::Linear(1), DFLL48M, true);
         // We are now running at 48Mhz
 
         // Reset various dividers back to 1
@@ -135,8 +307,14 @@ impl GenericClockController {
                 Hertz(0),
                 Hertz(0),
                 Hertz(0),
+                Hertz(0),
             ],
-            used_clocks: 1u64 << DFLL48M.bits(),
+            used_clocks: if usb_clock_recovery {
+                0
+            } else {
+                // gclk1 is routed to the DFLL48 reference clock
+                1u64 << DFLL48.bits()
+            },
         }
     }
 
@@ -174,36 +352,282 @@ impl GenericClockController {
     /// Configures a clock generator with the specified divider and
     /// source.
     /// `divider` is a linear divider to be applied to the clock
-    /// source.  While the hardware also supports an exponential divider,
-    /// this function doesn't expose that functionality at this time.
+    /// source.  Use `configure_gclk` if you need the exponential
+    /// divider supported by the hardware.
     /// `improve_duty_cycle` is a boolean that, when set to true, enables
     /// a 5o/50 duty cycle for odd divider values.
     /// Returns a `GClock` for the configured clock generator.
-    /// Returns `None` if the clock generator has already been configured.
+    /// Returns `None` if the clock generator has already been configured,
+    /// or if `src` is one whose frequency is not known to the controller.
     pub fn configure_gclk_divider_and_source(
         &mut self,
         gclk: ClockGenId,
         divider: u16,
         src: ClockSource,
         improve_duty_cycle: bool,
+    ) -> Option<GClock> {
+        self.configure_gclk(
+            gclk,
+            GClockDivider::Linear(divider),
+            src,
+            improve_duty_cycle,
+        )
+    }
+
+    /// Configures a clock generator with the specified divider and
+    /// source.  This is the same as `configure_gclk_divider_and_source`
+    /// except that it accepts either a linear or exponential divider.
+    /// Returns `None` if the clock generator has already been configured,
+    /// or if `src` is the XOSC crystal oscillator or the GCLK_IO input,
+    /// whose frequencies are not known to the controller.
+    pub fn configure_gclk(
+        &mut self,
+        gclk: ClockGenId,
+        divider: GClockDivider,
+        src: ClockSource,
+        improve_duty_cycle: bool,
     ) -> Option<GClock> {
         let idx = gclk.bits() as usize;
         if self.gclks[idx].0 != 0 {
             return None;
         }
-        self.state
-            .set_gclk_divider_and_source(gclk, divider, src, improve_duty_cycle);
+        self.apply_gclk_config(gclk, divider, src, improve_duty_cycle)
+    }
+
+    /// Changes the divider and source of a clock generator that has
+    /// previously been configured.
+    /// Any peripheral clock tokens obtained from the generator would
+    /// report a stale frequency after this change, so this returns
+    /// `None` while any peripheral clocks or other generators are fed
+    /// by it; release them with `disable_clock` first.  gclk0 drives
+    /// the CPU and cannot be reconfigured.  As with `configure_gclk`,
+    /// `None` is also returned for sources of unknown frequency, and
+    /// the generator is left unchanged.
+    pub fn reconfigure_gclk(
+        &mut self,
+        gclk: ClockGenId,
+        divider: GClockDivider,
+        src: ClockSource,
+        improve_duty_cycle: bool,
+    ) -> Option<GClock> {
+        if gclk == GCLK0 || self.gclk_in_use(gclk) {
+            return None;
+        }
+        self.apply_gclk_config(gclk, divider, src, improve_duty_cycle)
+    }
+
+    fn apply_gclk_config(
+        &mut self,
+        gclk: ClockGenId,
+        divider: GClockDivider,
+        src: ClockSource,
+        improve_duty_cycle: bool,
+    ) -> Option<GClock> {
+        let idx = gclk.bits() as usize;
         let freq: Hertz = match src {
             XOSC32K | OSC32K | OSCULP32K => OSC32K_FREQ,
             GCLKGEN1 => self.gclks[1],
             OSC8M => 8.mhz().into(),
             DFLL48M => OSC48M_FREQ,
             DPLL96M => 96.mhz().into(),
-            GCLKIN | XOSC | _ => unimplemented!(),
+            // The crystal and the input pad run at whatever frequency
+            // the board provides
+            GCLKIN | XOSC | ClockSource::_Reserved(_) => return None,
         };
-        self.gclks[idx] = Hertz(freq.0 / divider as u32);
+        self.state
+            .set_gclk_divider_and_source(gclk, divider, src, improve_duty_cycle);
+        let freq = divider.divide(freq);
+        self.gclks[idx] = freq;
         Some(GClock { gclk, freq })
     }
+
+    /// Returns true if any enabled peripheral clock is driven by
+    /// `gclk`, or if `gclk` is gclk1 and feeds another generator.
+    fn gclk_in_use(&mut self, gclk: ClockGenId) -> bool {
+        for id in 0..NUM_CLOCK_IDS {
+            let clock = ClockId::_from(id);
+            if let ClockId::_Reserved(_) = clock {
+                continue;
+            }
+            if self.state.clock_generator(clock) == Some(gclk) {
+                return true;
+            }
+        }
+        if gclk == GCLK1 {
+            for idx in 0..self.gclks.len() as u8 {
+                let (enabled, src, _, _, _) = self.state.gclk_config(ClockGenId::_from(idx));
+                if enabled && src == GCLKGEN1 {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Reads back the hardware configuration of a clock generator
+    pub fn gclk_config(&mut self, gclk: ClockGenId) -> GClockConfig {
+        let (enabled, src, divider, improve_duty_cycle, output_enabled) =
+            self.state.gclk_config(gclk);
+        GClockConfig {
+            gclk,
+            enabled,
+            src,
+            divider,
+            improve_duty_cycle,
+            output_enabled,
+            freq: self.gclks[gclk.bits() as usize],
+        }
+    }
+
+    /// Returns the clock generator that is currently driving the
+    /// specified peripheral clock, or `None` if that clock is disabled.
+    pub fn clock_generator(&mut self, clock: ClockId) -> Option<ClockGenId> {
+        self.state.clock_generator(clock)
+    }
+
+    /// Disables the peripheral clock associated with the token,
+    /// consuming it.  The corresponding peripheral stops receiving
+    /// its generic clock and the clock may be configured again later
+    /// by calling the matching method (eg: `sercom4_core`).
+    pub fn disable_clock<T: PeripheralClock>(&mut self, clock: T) {
+        let id = clock.clock_id();
+        self.state.disable_clock(id);
+        self.used_clocks &= !(1u64 << id.bits());
+    }
+
+    /// Routes the peripheral clock associated with the token to a
+    /// different clock generator, returning the token updated with the
+    /// new frequency.  Peripherals configured with the old token are
+    /// not reconfigured and will run at the new rate.
+    pub fn reassign_clock<T: PeripheralClock>(&mut self, mut clock: T, generator: &GClock) -> T {
+        self.state
+            .enable_clock_generator(clock.clock_id(), generator.gclk);
+        clock.set_freq(self.gclks[generator.gclk.bits() as usize]);
+        clock
+    }
+
+    /// Enables the output of a clock generator on its GCLK_IO pin.
+    /// The generator is determined by the pad type.  Returns the
+    /// `GClock` being output, or `None` if that generator has not yet
+    /// been configured.
+    pub fn enable_gclk_output<P: GclkOutPad>(&mut self, pad: &P) -> Option<GClock> {
+        let gclk = pad.gclk();
+        let clock = self.get_gclk(gclk)?;
+        self.state.set_gclk_output(gclk, true);
+        Some(clock)
+    }
+
+    /// Stops a clock generator from driving its GCLK_IO pin
+    pub fn disable_gclk_output<P: GclkOutPad>(&mut self, pad: &P) {
+        self.state.set_gclk_output(pad.gclk(), false);
+    }
+
+    /// Configures a clock generator, and the oscillator that it is
+    /// sourced from, to keep running while the device is in STANDBY
+    /// sleep.  Peripherals such as the RTC and EIC have no RUNSTDBY
+    /// setting of their own and run in standby only if their generic
+    /// clock does.
+    /// Note that gclk1 is the reference for the DFLL48M when using
+    /// `new`, so enabling this for gclk1 keeps the OSC32K running too.
+    pub fn run_in_standby(&mut self, generator: &GClock, sysctrl: &mut SYSCTRL, enable: bool) {
+        let (_, src, _, _, _) = self.state.gclk_config(generator.gclk);
+        match src {
+            OSC32K => sysctrl.osc32k.modify(|_, w| w.runstdby().bit(enable)),
+            XOSC32K => sysctrl.xosc32k.modify(|_, w| w.runstdby().bit(enable)),
+            OSC8M => sysctrl.osc8m.modify(|_, w| w.runstdby().bit(enable)),
+            XOSC => sysctrl.xosc.modify(|_, w| w.runstdby().bit(enable)),
+            DFLL48M => {
+                sysctrl.dfllctrl.modify(|_, w| w.runstdby().bit(enable));
+                wait_for_dfllrdy(sysctrl);
+            }
+            DPLL96M => sysctrl.dpllctrla.modify(|_, w| w.runstdby().bit(enable)),
+            // OSCULP32K always runs, and the remaining sources are
+            // either external or another generator
+            _ => {}
+        }
+        self.state.set_gclk_run_in_standby(generator.gclk, enable);
+    }
+
+    /// Configures the oscillator that a clock generator is sourced from
+    /// to run only while a generator or peripheral requests its clock.
+    /// `new` leaves every oscillator running continuously, so that
+    /// they are ready without any start-up delay; with on-demand
+    /// operation an oscillator that nothing is using stops, and is
+    /// started again, with its start-up time, when it is next needed.
+    /// In STANDBY sleep an on-demand oscillator runs only if it is
+    /// also configured with `run_in_standby`.
+    pub fn run_on_demand(&mut self, generator: &GClock, sysctrl: &mut SYSCTRL, enable: bool) {
+        let (_, src, _, _, _) = self.state.gclk_config(generator.gclk);
+        match src {
+            OSC32K => sysctrl.osc32k.modify(|_, w| w.ondemand().bit(enable)),
+            XOSC32K => sysctrl.xosc32k.modify(|_, w| w.ondemand().bit(enable)),
+            OSC8M => sysctrl.osc8m.modify(|_, w| w.ondemand().bit(enable)),
+            XOSC => sysctrl.xosc.modify(|_, w| w.ondemand().bit(enable)),
+            DFLL48M => {
+                sysctrl.dfllctrl.modify(|_, w| w.ondemand().bit(enable));
+                wait_for_dfllrdy(sysctrl);
+            }
+            DPLL96M => sysctrl.dpllctrla.modify(|_, w| w.ondemand().bit(enable)),
+            // OSCULP32K always runs, and the remaining sources are
+            // either external or another generator
+            _ => {}
+        }
+    }
+
+    /// Writes a description of the clock tree to `w`; each enabled
+    /// generator is listed with its source, divider and frequency,
+    /// followed by the peripheral clocks that it drives.
+    /// This is intended for diagnostic purposes, for example via
+    /// semihosting output.
+    pub fn dump<W: fmt::Write>(&mut self, w: &mut W) -> fmt::Result {
+        for idx in 0..self.gclks.len() as u8 {
+            let config = self.gclk_config(ClockGenId::_from(idx));
+            if !config.enabled {
+                continue;
+            }
+            write!(
+                w,
+                "{:?}: {:?} {:?} -> {}Hz",
+                config.gclk, config.src, config.divider, config.freq.0
+            )?;
+            if config.output_enabled {
+                write!(w, " (GCLK_IO)")?;
+            }
+            writeln!(w)?;
+
+            for id in 0..NUM_CLOCK_IDS {
+                let clock = ClockId::_from(id);
+                if let ClockId::_Reserved(_) = clock {
+                    continue;
+                }
+                if self.state.clock_generator(clock) == Some(config.gclk) {
+                    writeln!(w, "  {:?}", clock)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The number of generic clock multiplexer IDs, including the
+/// reserved slot between DAC and I2S_0.
+const NUM_CLOCK_IDS: u8 = 37;
+
+mod private {
+    use time::Hertz;
+
+    pub trait Sealed {
+        fn set_freq(&mut self, freq: Hertz);
+    }
+}
+
+/// Implemented by the peripheral clock tokens so that they can be
+/// released and reassigned through the `GenericClockController`.
+/// This trait is sealed; only the tokens defined in this module
+/// implement it.
+pub trait PeripheralClock: private::Sealed {
+    /// The generic clock multiplexer ID that this token represents
+    fn clock_id(&self) -> ClockId;
 }
 
 macro_rules! clock_generator {
@@ -233,6 +657,18 @@ impl Into<Hertz> for $Type {
         self.freq
     }
 }
+
+impl private::Sealed for $Type {
+    fn set_freq(&mut self, freq: Hertz) {
+        self.freq = freq;
+    }
+}
+
+impl PeripheralClock for $Type {
+    fn clock_id(&self) -> ClockId {
+        $clock
+    }
+}
 )+
 
 impl GenericClockController {
@@ -248,7 +684,8 @@ impl GenericClockController {
     /// clock token be passed in to ensure that the clock has been initialized
     /// appropriately.
     /// Returns `None` is the specified generic clock has already been
-    /// configured.
+    /// configured; the token must be released via `disable_clock` before
+    /// it can be configured again.
     pub fn $id(&mut self, generator: &GClock) -> Option<$Type> {
         let bits : u64 = 1<<$clock.bits() as u64;
         if (self.used_clocks & bits) != 0 {
@@ -266,6 +703,7 @@ impl GenericClockController {
 }
 
 clock_generator!(
+    (tcc0_tcc1, Tcc0Tcc1Clock, TCC0_TCC1),
     (tcc2_tc3, Tcc2Tc3Clock, TCC2_TC3),
     (tc4_tc5, Tc4Tc5Clock, TC4_TC5),
     (tc6_tc7, Tc6Tc7Clock, TC6_TC7),
@@ -277,6 +715,128 @@ clock_generator!(
     (sercom5_core, Sercom5CoreClock, SERCOM5_CORE),
     (adc, AdcClock, ADC),
     (usb, UsbClock, USB),
+    (i2s0, I2s0Clock, I2S_0),
+    (i2s1, I2s1Clock, I2S_1),
+    (wdt, WdtClock, WDT),
+);
+
+/// The gclk_out macro defines the pad types for the GCLK_IO pins, in
+/// the same style as the sercom pads.  All of the GCLK_IO pins use
+/// peripheral function H.
+macro_rules! gclk_out {
+    ($(pub enum $PadType:ident ($gclk:ident) {
+        $( $(#[$attr:meta])* $PinType:ident ($new:ident),)+
+    })+
+    ) => {
+$(
+/// Represents a pin configured to output the clock generator
+/// with the matching number.
+pub enum $PadType {
+    $(
+        $(#[$attr])*
+        $PinType(gpio::$PinType<gpio::PfH>),
+    )+
+}
+
+impl $PadType {
+    $(
+    /// Construct the pad from the appropriate pin in any mode.
+    $(#[$attr])*
+    pub fn $new<MODE>(pin: gpio::$PinType<MODE>, port: &mut Port) -> Self {
+        $PadType::$PinType(pin.into_function(port))
+    }
+    )+
+}
+
+impl GclkOutPad for $PadType {
+    fn gclk(&self) -> ClockGenId {
+        $gclk
+    }
+}
+
+$(
+$(#[$attr])*
+impl<MODE> GclkOutPin<$PadType> for gpio::$PinType<MODE> {
+    fn into_gclk_out(self, port: &mut Port) -> $PadType {
+        $PadType::$new(self, port)
+    }
+}
+)+
+)+
+    };
+}
+
+/// Implemented by the GCLK_IO pad types to identify the clock generator
+/// that they are able to output.
+pub trait GclkOutPad {
+    fn gclk(&self) -> ClockGenId;
+}
+
+/// The GclkOutPin trait makes it more ergonomic to convert a
+/// pin into a GCLK_IO pad.
+pub trait GclkOutPin<T> {
+    fn into_gclk_out(self, port: &mut Port) -> T;
+}
+
+gclk_out!(
+pub enum GclkOut0 (GCLK0) {
+    Pa14(pa14),
+    Pa27(pa27),
+    Pa28(pa28),
+    Pa30(pa30),
+    #[cfg(feature = "pins-64")]
+    Pb14(pb14),
+    #[cfg(feature = "pins-48")]
+    Pb22(pb22),
+}
+
+pub enum GclkOut1 (GCLK1) {
+    Pa15(pa15),
+    #[cfg(feature = "pins-64")]
+    Pb15(pb15),
+    #[cfg(feature = "pins-48")]
+    Pb23(pb23),
+}
+
+pub enum GclkOut2 (GCLK2) {
+    Pa16(pa16),
+    #[cfg(feature = "pins-64")]
+    Pb16(pb16),
+}
+
+pub enum GclkOut3 (GCLK3) {
+    Pa17(pa17),
+    #[cfg(feature = "pins-64")]
+    Pb17(pb17),
+}
+
+pub enum GclkOut4 (GCLK4) {
+    Pa10(pa10),
+    #[cfg(feature = "pins-48")]
+    Pa20(pa20),
+    #[cfg(feature = "pins-48")]
+    Pb10(pb10),
+}
+
+pub enum GclkOut5 (GCLK5) {
+    Pa11(pa11),
+    #[cfg(feature = "pins-48")]
+    Pa21(pa21),
+    #[cfg(feature = "pins-48")]
+    Pb11(pb11),
+}
+
+pub enum GclkOut6 (GCLK6) {
+    Pa22(pa22),
+    #[cfg(feature = "pins-64")]
+    Pb12(pb12),
+}
+
+pub enum GclkOut7 (GCLK7) {
+    Pa23(pa23),
+    #[cfg(feature = "pins-64")]
+    Pb13(pb13),
+}
 );
 
 /// Helper type for computing effective frequency given a source
@@ -389,8 +949,8 @@ fn configure_and_enable_dfll48m(sysctrl: &mut SYSCTRL) {
         // chill cycle disable
         w.ccdis().set_bit();
 
-        // usb correction
-        w.usbcrm().set_bit();
+        // lock to the gclk1 reference rather than USB SOF
+        w.usbcrm().clear_bit();
 
         // bypass coarse lock (have calibration data)
         w.bplckc().set_bit()
@@ -403,3 +963,54 @@ fn configure_and_enable_dfll48m(sysctrl: &mut SYSCTRL) {
 
     wait_for_dfllrdy(sysctrl);
 }
+
+/// Configure the dfll48m to operate at 48Mhz in USB clock recovery mode.
+/// The multiplier is relative to the 1Khz USB SOF; until SOF packets
+/// arrive the loop has nothing to track and the output stays at the
+/// calibrated open-loop values loaded into DFLLVAL.
+fn configure_and_enable_dfll48m_usb_recovery(sysctrl: &mut SYSCTRL) {
+    sysctrl.dfllctrl.write(|w| w.ondemand().clear_bit());
+    wait_for_dfllrdy(sysctrl);
+
+    // Start from the factory coarse calibration and the middle of
+    // the fine range
+    let coarse = super::calibration::dfll48m_coarse_cal();
+    let fine = 0x200;
+
+    sysctrl.dfllval.write(|w| unsafe {
+        w.coarse().bits(coarse);
+        w.fine().bits(fine)
+    });
+
+    sysctrl.dfllmul.write(|w| unsafe {
+        // Use small steps; SOF arrives once per millisecond and
+        // large corrections would show up as jitter on the bus
+        w.cstep().bits(1);
+        w.fstep().bits(1);
+        // 48Mhz / 1Khz SOF
+        w.mul().bits(48_000)
+    });
+
+    sysctrl.dfllctrl.write(|w| {
+        // always on
+        w.ondemand().clear_bit();
+
+        // closed loop mode
+        w.mode().set_bit();
+
+        // chill cycle disable
+        w.ccdis().set_bit();
+
+        // use USB SOF as the reference
+        w.usbcrm().set_bit();
+
+        // bypass coarse lock (have calibration data)
+        w.bplckc().set_bit()
+    });
+
+    wait_for_dfllrdy(sysctrl);
+
+    sysctrl.dfllctrl.modify(|_, w| w.enable().set_bit());
+
+    wait_for_dfllrdy(sysctrl);
+}
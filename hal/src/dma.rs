@@ -0,0 +1,372 @@
+//! Moving data between memory and peripherals with the DMA controller.
+//!
+//! `Dma::new` takes ownership of the DMAC and `split` hands out its
+//! twelve channels.  A channel moves a block of beats each time its
+//! trigger fires, such as a SERCOM becoming ready for the next byte or
+//! a timer overflowing, without involving the CPU.  The descriptors
+//! that the controller reads are kept in a table owned by this module.
+use core::ptr;
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use cortex_m::interrupt;
+use target_device::{DMAC, PM};
+
+/// The number of channels
+pub const NUM_CHANNELS: usize = 12;
+
+/// The transfer descriptor layout defined by the hardware
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Descriptor {
+    btctrl: u16,
+    btcnt: u16,
+    srcaddr: u32,
+    dstaddr: u32,
+    descaddr: u32,
+}
+
+const EMPTY: Descriptor = Descriptor {
+    btctrl: 0,
+    btcnt: 0,
+    srcaddr: 0,
+    dstaddr: 0,
+    descaddr: 0,
+};
+
+/// The descriptor and write-back sections must be 128-bit aligned
+#[repr(C, align(16))]
+struct DescriptorTable {
+    descriptors: [Descriptor; NUM_CHANNELS],
+    writeback: [Descriptor; NUM_CHANNELS],
+}
+
+/// Only accessed by the owner of the DMAC
+static mut TABLE: DescriptorTable = DescriptorTable {
+    descriptors: [EMPTY; NUM_CHANNELS],
+    writeback: [EMPTY; NUM_CHANNELS],
+};
+
+// BTCTRL fields
+const BTCTRL_VALID: u16 = 1;
+const BTCTRL_BLOCKACT_INT: u16 = 1 << 3;
+const BTCTRL_BEATSIZE_SHIFT: u16 = 8;
+const BTCTRL_SRCINC: u16 = 1 << 10;
+const BTCTRL_DSTINC: u16 = 1 << 11;
+
+/// The size of each data transfer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BeatSize {
+    Byte,
+    HalfWord,
+    Word,
+}
+
+impl BeatSize {
+    fn bytes(self) -> u32 {
+        match self {
+            BeatSize::Byte => 1,
+            BeatSize::HalfWord => 2,
+            BeatSize::Word => 4,
+        }
+    }
+
+    fn bits(self) -> u16 {
+        match self {
+            BeatSize::Byte => 0,
+            BeatSize::HalfWord => 1,
+            BeatSize::Word => 2,
+        }
+    }
+}
+
+/// The peripheral signal that triggers a channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TriggerSource {
+    /// The transfer is started by software and runs to completion
+    Software = 0x00,
+    Sercom0Rx = 0x01,
+    Sercom0Tx = 0x02,
+    Sercom1Rx = 0x03,
+    Sercom1Tx = 0x04,
+    Sercom2Rx = 0x05,
+    Sercom2Tx = 0x06,
+    Sercom3Rx = 0x07,
+    Sercom3Tx = 0x08,
+    Sercom4Rx = 0x09,
+    Sercom4Tx = 0x0a,
+    Sercom5Rx = 0x0b,
+    Sercom5Tx = 0x0c,
+    Tcc0Ovf = 0x0d,
+    Tcc0Mc0 = 0x0e,
+    Tcc0Mc1 = 0x0f,
+    Tcc0Mc2 = 0x10,
+    Tcc0Mc3 = 0x11,
+    Tcc1Ovf = 0x12,
+    Tcc1Mc0 = 0x13,
+    Tcc1Mc1 = 0x14,
+    Tcc2Ovf = 0x15,
+    Tcc2Mc0 = 0x16,
+    Tcc2Mc1 = 0x17,
+    Tc3Ovf = 0x18,
+    Tc4Ovf = 0x1b,
+    Tc5Ovf = 0x1e,
+    AdcResrdy = 0x27,
+    DacEmpty = 0x28,
+    I2sRx0 = 0x29,
+    I2sRx1 = 0x2a,
+    I2sTx0 = 0x2b,
+    I2sTx1 = 0x2c,
+}
+
+/// How much data is moved each time the trigger fires
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TriggerAction {
+    /// The whole block
+    Block = 0,
+    /// A single beat
+    Beat = 2,
+}
+
+/// A block transfer between two addresses
+#[derive(Debug, Clone, Copy)]
+pub struct Transfer {
+    /// The address of the first beat to read
+    pub source: u32,
+    /// Whether the source address advances after each beat; false
+    /// for a peripheral data register
+    pub source_increment: bool,
+    /// The address of the first beat to write
+    pub destination: u32,
+    /// Whether the destination address advances after each beat
+    pub destination_increment: bool,
+    pub beat_size: BeatSize,
+    /// The number of beats to move
+    pub count: u16,
+}
+
+/// Errors reported by a channel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// The controller could not fetch the descriptor or access one
+    /// of the addresses
+    Bus,
+}
+
+/// The DMA controller
+pub struct Dma {
+    _dmac: DMAC,
+}
+
+impl Dma {
+    /// Powers up the controller and enables all priority levels
+    pub fn new(dmac: DMAC, pm: &mut PM) -> Self {
+        pm.ahbmask.modify(|_, w| w.dmac_().set_bit());
+        pm.apbbmask.modify(|_, w| w.dmac_().set_bit());
+
+        dmac.ctrl.modify(|_, w| w.dmaenable().clear_bit());
+        dmac.ctrl.write(|w| w.swrst().set_bit());
+        while dmac.ctrl.read().swrst().bit_is_set() {}
+
+        unsafe {
+            dmac.baseaddr
+                .write(|w| w.baseaddr().bits(&TABLE.descriptors as *const _ as u32));
+            dmac.wrbaddr
+                .write(|w| w.wrbaddr().bits(&TABLE.writeback as *const _ as u32));
+        }
+        dmac.ctrl.write(|w| {
+            w.dmaenable().set_bit();
+            w.lvlen0().set_bit();
+            w.lvlen1().set_bit();
+            w.lvlen2().set_bit();
+            w.lvlen3().set_bit()
+        });
+
+        Self { _dmac: dmac }
+    }
+
+    /// Hands out the channels.  The controller cannot be reclaimed,
+    /// because the channels refer to it after this point.
+    pub fn split(self) -> Channels {
+        Channels {
+            ch0: Channel { id: 0 },
+            ch1: Channel { id: 1 },
+            ch2: Channel { id: 2 },
+            ch3: Channel { id: 3 },
+            ch4: Channel { id: 4 },
+            ch5: Channel { id: 5 },
+            ch6: Channel { id: 6 },
+            ch7: Channel { id: 7 },
+            ch8: Channel { id: 8 },
+            ch9: Channel { id: 9 },
+            ch10: Channel { id: 10 },
+            ch11: Channel { id: 11 },
+        }
+    }
+}
+
+/// The channels of the controller; lower numbered channels win
+/// arbitration when several are pending at the same level
+pub struct Channels {
+    pub ch0: Channel,
+    pub ch1: Channel,
+    pub ch2: Channel,
+    pub ch3: Channel,
+    pub ch4: Channel,
+    pub ch5: Channel,
+    pub ch6: Channel,
+    pub ch7: Channel,
+    pub ch8: Channel,
+    pub ch9: Channel,
+    pub ch10: Channel,
+    pub ch11: Channel,
+}
+
+/// A DMA channel
+pub struct Channel {
+    id: u8,
+}
+
+impl Channel {
+    /// The channel number
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Runs `f` with the channel selected in CHID.  The channel
+    /// registers are shared, so interrupts are masked.
+    fn with<R, F: FnOnce(&::target_device::dmac::RegisterBlock) -> R>(&self, f: F) -> R {
+        interrupt::free(|_| {
+            let dmac = unsafe { &*DMAC::ptr() };
+            dmac.chid.write(|w| unsafe { w.id().bits(self.id) });
+            f(dmac)
+        })
+    }
+
+    /// Starts moving `transfer.count` beats, which are moved as
+    /// `trigger` fires.  Any transfer in progress is abandoned.
+    ///
+    /// This is unsafe because the hardware accesses the addresses
+    /// after this returns: they must stay valid until the transfer
+    /// is complete or `stop` is called.
+    pub unsafe fn start(
+        &mut self,
+        transfer: Transfer,
+        trigger: TriggerSource,
+        action: TriggerAction,
+    ) {
+        self.start_descriptor(transfer, trigger, action, false);
+    }
+
+    /// Starts moving `transfer.count` beats like `start`, but starts
+    /// again from the beginning of the block whenever it completes,
+    /// until `stop` is called.  Used with a peripheral, this streams
+    /// through a ring buffer; `remaining` tells how far the transfer
+    /// has got, and `wait` returns each time it wraps around.
+    ///
+    /// This is unsafe for the same reasons as `start`.
+    pub unsafe fn start_circular(
+        &mut self,
+        transfer: Transfer,
+        trigger: TriggerSource,
+        action: TriggerAction,
+    ) {
+        self.start_descriptor(transfer, trigger, action, true);
+    }
+
+    unsafe fn start_descriptor(
+        &mut self,
+        transfer: Transfer,
+        trigger: TriggerSource,
+        action: TriggerAction,
+        circular: bool,
+    ) {
+        self.stop();
+
+        // An incrementing address is given as the end of the block
+        let span = transfer.beat_size.bytes() * u32::from(transfer.count);
+        let mut btctrl = BTCTRL_VALID | transfer.beat_size.bits() << BTCTRL_BEATSIZE_SHIFT;
+        let mut source = transfer.source;
+        if transfer.source_increment {
+            btctrl |= BTCTRL_SRCINC;
+            source += span;
+        }
+        let mut destination = transfer.destination;
+        if transfer.destination_increment {
+            btctrl |= BTCTRL_DSTINC;
+            destination += span;
+        }
+        let descriptor = &mut TABLE.descriptors[self.id as usize];
+        // A circular transfer links the descriptor to itself, and flags
+        // the end of each block rather than the end of the transfer
+        let descaddr = if circular {
+            btctrl |= BTCTRL_BLOCKACT_INT;
+            descriptor as *const Descriptor as u32
+        } else {
+            0
+        };
+        *descriptor = Descriptor {
+            btctrl,
+            btcnt: transfer.count,
+            srcaddr: source,
+            dstaddr: destination,
+            descaddr,
+        };
+        // The descriptor must be in memory before the channel is enabled
+        compiler_fence(Ordering::SeqCst);
+
+        let id = self.id;
+        self.with(|dmac| {
+            dmac.chctrlb.write(|w| {
+                w.lvl().lvl0();
+                w.trigsrc().bits(trigger as u8);
+                w.trigact().bits(action as u8)
+            });
+            dmac.chintflag.write(|w| {
+                w.tcmpl().set_bit();
+                w.terr().set_bit();
+                w.susp().set_bit()
+            });
+            dmac.chctrla.write(|w| w.enable().set_bit());
+            if trigger == TriggerSource::Software {
+                dmac.swtrigctrl.modify(|r, w| w.bits(r.bits() | 1 << id));
+            }
+        });
+    }
+
+    /// Returns the number of beats left in the current block.  The
+    /// controller saves its progress after each burst, so this may lag
+    /// the transfer by a beat.
+    pub fn remaining(&self) -> u16 {
+        unsafe { ptr::read_volatile(&TABLE.writeback[self.id as usize].btcnt) }
+    }
+
+    /// Returns true once the transfer has moved all of its beats
+    pub fn is_complete(&self) -> bool {
+        self.with(|dmac| dmac.chintflag.read().tcmpl().bit_is_set())
+    }
+
+    /// Blocks until the transfer is complete, or until a circular
+    /// transfer next wraps around
+    pub fn wait(&mut self) -> Result<(), Error> {
+        loop {
+            let flags = self.with(|dmac| dmac.chintflag.read());
+            if flags.terr().bit_is_set() {
+                return Err(Error::Bus);
+            }
+            if flags.tcmpl().bit_is_set() {
+                self.with(|dmac| dmac.chintflag.write(|w| w.tcmpl().set_bit()));
+                return Ok(());
+            }
+        }
+    }
+
+    /// Stops the channel, abandoning any transfer in progress
+    pub fn stop(&mut self) {
+        self.with(|dmac| {
+            dmac.chctrla.write(|w| w.enable().clear_bit());
+            while dmac.chctrla.read().enable().bit_is_set() {}
+            dmac.chctrla.write(|w| w.swrst().set_bit());
+            while dmac.chctrla.read().swrst().bit_is_set() {}
+        });
+    }
+}
@@ -0,0 +1,278 @@
+//! Driving several pins of a port group at once.
+//!
+//! `PortGroup` owns a set of pins from the same group and updates all of
+//! them with a single register write, so that they change on the same
+//! clock edge.  The pins are configured together through the WRCONFIG
+//! register.  `ParallelBus` builds on it for pins with consecutive
+//! numbers, reading and writing words over the single-cycle IOBUS.
+use core::marker::PhantomData;
+
+use super::{
+    group_registers, iobus_group_registers, Floating, GroupRegisters, Input, OpenDrain, Output,
+    Pin, Port, PullDown, PullUp, PushPull,
+};
+
+/// A set of pins from the same port group, addressed with a mask
+/// in which bit n corresponds to pin n of the group
+pub struct PortGroup<MODE> {
+    group: u8,
+    mask: u32,
+    _mode: PhantomData<MODE>,
+}
+
+impl<MODE> PortGroup<MODE> {
+    /// Creates a group containing `pin`
+    pub fn new(pin: Pin<MODE>) -> Self {
+        Self {
+            group: pin.group(),
+            mask: 1 << pin.pin(),
+            _mode: PhantomData,
+        }
+    }
+
+    /// Adds `pin` to the group.  The group and the pin are returned if
+    /// the pin is from a different group.
+    pub fn with(mut self, pin: Pin<MODE>) -> Result<Self, (Self, Pin<MODE>)> {
+        if pin.group() != self.group {
+            return Err((self, pin));
+        }
+        self.mask |= 1 << pin.pin();
+        Ok(self)
+    }
+
+    /// The port group; 0 for PAn and 1 for PBn
+    pub fn group(&self) -> u8 {
+        self.group
+    }
+
+    /// The pins in the group
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// Releases the pins
+    pub fn free(self) -> PortGroupPins<MODE> {
+        PortGroupPins {
+            group: self.group,
+            mask: self.mask,
+            _mode: PhantomData,
+        }
+    }
+
+    fn registers(&self) -> &'static GroupRegisters {
+        group_registers(self.group)
+    }
+
+    fn into_mode<NEW>(self) -> PortGroup<NEW> {
+        PortGroup {
+            group: self.group,
+            mask: self.mask,
+            _mode: PhantomData,
+        }
+    }
+
+    /// Configures all of the pins as inputs or outputs with the given
+    /// settings.  WRCONFIG addresses one half of the group at a time.
+    fn configure(&self, output: bool, inen: bool, pullen: bool) {
+        let regs = self.registers();
+        if output {
+            regs.dirset.write(|bits| unsafe { bits.bits(self.mask) });
+        } else {
+            regs.dirclr.write(|bits| unsafe { bits.bits(self.mask) });
+        }
+        for &(half, hwsel) in &[(self.mask as u16, false), ((self.mask >> 16) as u16, true)] {
+            if half == 0 {
+                continue;
+            }
+            regs.wrconfig.write(|w| {
+                unsafe {
+                    w.pinmask().bits(half);
+                }
+                w.hwsel().bit(hwsel);
+                w.wrpincfg().set_bit();
+                w.pmuxen().clear_bit();
+                w.inen().bit(inen);
+                w.pullen().bit(pullen);
+                w.drvstr().clear_bit()
+            });
+        }
+    }
+
+    /// Configures the pins to operate as floating inputs
+    pub fn into_floating_input(self, _port: &mut Port) -> PortGroup<Input<Floating>> {
+        self.configure(false, true, false);
+        self.into_mode()
+    }
+
+    /// Configures the pins to operate as pulled down inputs
+    pub fn into_pull_down_input(self, _port: &mut Port) -> PortGroup<Input<PullDown>> {
+        self.configure(false, true, true);
+        self.registers()
+            .outclr
+            .write(|bits| unsafe { bits.bits(self.mask) });
+        self.into_mode()
+    }
+
+    /// Configures the pins to operate as pulled up inputs
+    pub fn into_pull_up_input(self, _port: &mut Port) -> PortGroup<Input<PullUp>> {
+        self.configure(false, true, true);
+        self.registers()
+            .outset
+            .write(|bits| unsafe { bits.bits(self.mask) });
+        self.into_mode()
+    }
+
+    /// Configures the pins to operate as open drain outputs
+    pub fn into_open_drain_output(self, _port: &mut Port) -> PortGroup<Output<OpenDrain>> {
+        self.configure(true, true, false);
+        self.into_mode()
+    }
+
+    /// Configures the pins to operate as push-pull outputs
+    pub fn into_push_pull_output(self, _port: &mut Port) -> PortGroup<Output<PushPull>> {
+        self.configure(true, true, false);
+        self.into_mode()
+    }
+
+    /// Reads the input levels of the pins; bits outside of the
+    /// group are zero
+    pub fn read(&self) -> u32 {
+        self.registers().in_.read().bits() & self.mask
+    }
+}
+
+impl<MODE> PortGroup<Output<MODE>> {
+    /// Sets the pins in `mask` to the corresponding bits of `value`.
+    /// Pins that are not in the group are left alone.
+    pub fn write(&mut self, mask: u32, value: u32) {
+        let regs = self.registers();
+        // Toggling only the bits that differ updates all of the pins
+        // with one write, without disturbing the other pins of the group
+        let changed = (regs.out.read().bits() ^ value) & mask & self.mask;
+        regs.outtgl.write(|bits| unsafe { bits.bits(changed) });
+    }
+
+    /// Drives the pins in `mask` high
+    pub fn set_high(&mut self, mask: u32) {
+        let mask = mask & self.mask;
+        self.registers()
+            .outset
+            .write(|bits| unsafe { bits.bits(mask) });
+    }
+
+    /// Drives the pins in `mask` low
+    pub fn set_low(&mut self, mask: u32) {
+        let mask = mask & self.mask;
+        self.registers()
+            .outclr
+            .write(|bits| unsafe { bits.bits(mask) });
+    }
+
+    /// Toggles the pins in `mask`
+    pub fn toggle(&mut self, mask: u32) {
+        let mask = mask & self.mask;
+        self.registers()
+            .outtgl
+            .write(|bits| unsafe { bits.bits(mask) });
+    }
+
+    /// Returns the levels the pins are being driven to
+    pub fn output(&self) -> u32 {
+        self.registers().out.read().bits() & self.mask
+    }
+}
+
+/// The pins released by `PortGroup::free`
+pub struct PortGroupPins<MODE> {
+    group: u8,
+    mask: u32,
+    _mode: PhantomData<MODE>,
+}
+
+impl<MODE> Iterator for PortGroupPins<MODE> {
+    type Item = Pin<MODE>;
+
+    fn next(&mut self) -> Option<Pin<MODE>> {
+        if self.mask == 0 {
+            return None;
+        }
+        let pin = self.mask.trailing_zeros() as u8;
+        self.mask &= !(1 << pin);
+        Some(Pin {
+            group: self.group,
+            pin,
+            _mode: PhantomData,
+        })
+    }
+}
+
+/// Up to 16 pins with consecutive numbers in the same group, used as a
+/// parallel data bus.  Words are written and read in a single cycle
+/// through the IOBUS, so all of the data lines change together.
+pub struct ParallelBus {
+    pins: PortGroup<Output<PushPull>>,
+    shift: u8,
+}
+
+impl ParallelBus {
+    /// Creates a bus from push-pull output pins.  The least significant
+    /// bit of each word is the lowest numbered pin.  The group is
+    /// returned if its pins are not consecutive or there are more
+    /// than 16 of them.
+    pub fn new(pins: PortGroup<Output<PushPull>>) -> Result<Self, PortGroup<Output<PushPull>>> {
+        let shift = pins.mask.trailing_zeros();
+        let bits = pins.mask >> shift;
+        if bits > 0xffff || bits & (bits + 1) != 0 {
+            return Err(pins);
+        }
+        Ok(Self {
+            pins,
+            shift: shift as u8,
+        })
+    }
+
+    /// The number of data lines
+    pub fn width(&self) -> u8 {
+        self.pins.mask.count_ones() as u8
+    }
+
+    /// Drives `value` onto the bus
+    pub fn write(&mut self, value: u16) {
+        let regs = self.registers();
+        let value = u32::from(value) << self.shift;
+        let changed = (regs.out.read().bits() ^ value) & self.pins.mask;
+        regs.outtgl.write(|bits| unsafe { bits.bits(changed) });
+    }
+
+    /// Reads the levels of the data lines.  When reading from another
+    /// device the bus should first be released with `set_input`.
+    pub fn read(&self) -> u16 {
+        ((self.registers().in_.read().bits() & self.pins.mask) >> self.shift) as u16
+    }
+
+    /// Stops driving the bus, so that another device can drive it
+    pub fn set_input(&mut self) {
+        let mask = self.pins.mask;
+        self.registers()
+            .dirclr
+            .write(|bits| unsafe { bits.bits(mask) });
+    }
+
+    /// Drives the bus again after `set_input`
+    pub fn set_output(&mut self) {
+        let mask = self.pins.mask;
+        self.registers()
+            .dirset
+            .write(|bits| unsafe { bits.bits(mask) });
+    }
+
+    /// Releases the pins, which are left driving the bus
+    pub fn free(mut self) -> PortGroup<Output<PushPull>> {
+        self.set_output();
+        self.pins
+    }
+
+    fn registers(&self) -> &'static GroupRegisters {
+        iobus_group_registers(self.pins.group)
+    }
+}
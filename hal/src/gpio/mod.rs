@@ -0,0 +1,879 @@
+//! Working with GPIO pins.
+//! The pins are associated with the PORT hardware.  This module
+//! defines a `split` method on the `PORT` type that is used to safely
+//! reference the individual pin configuration.
+//! The IO pins can be switched into alternate function modes, which
+//! routes the pins to different peripherals depending on the mode
+//! for the pin.   The pin configuration is reflected through the
+//! use of type states to make the interface (ideally, or at least practically)
+//! impossible to misuse.  Each pin only has the `into_function_x`
+//! methods for the functions it supports, along with aliases named for
+//! the kind of peripheral, such as `into_sercom_pad` or `into_eic`, so
+//! routing a pin to a peripheral it is not connected to fails to compile.
+//! Electrical options that do not change how the pin is used, such as
+//! the drive strength, continuous input sampling or a pull resistor on a
+//! pin that is controlled by a peripheral, are set with methods on the
+//! configured pin.
+//! Each pin is its own type; `downgrade` converts a pin into a `Pin`,
+//! which identifies the pin at runtime so that pins of different
+//! numbers can be stored together, for example in an array.  Pins of
+//! the same group can be combined into a `PortGroup` to drive them with
+//! a single register write, or into a `ParallelBus`.
+use target_device::port::{
+    PINCFG0_, PMUX0_, CTRL, DIR, DIRCLR, DIRSET, DIRTGL, IN, OUT, OUTCLR, OUTSET, OUTTGL,
+    WRCONFIG,
+};
+
+#[cfg(feature = "pins-48")]
+use target_device::port::{PINCFG1_, PMUX1_};
+
+use core::marker::PhantomData;
+use hal::digital::OutputPin;
+use target_device::{PORT, PORT_IOBUS};
+
+mod group;
+pub use self::group::{ParallelBus, PortGroup, PortGroupPins};
+
+#[cfg(feature = "unproven")]
+use hal::digital::{InputPin, StatefulOutputPin, ToggleableOutputPin};
+
+/// The GpioExt trait allows splitting the PORT hardware into
+/// its constituent pin parts.
+pub trait GpioExt {
+    type Parts;
+
+    /// Consume and split the device into its constitent parts
+    fn split(self) -> Self::Parts;
+}
+
+/// Represents a pin configured for input.
+/// The MODE type is typically one of `Floating`, `PullDown` or
+/// `PullUp`.
+pub struct Input<MODE> {
+    _mode: PhantomData<MODE>,
+}
+
+/// Represents a pin configured for output.
+/// The MODE type is typically one of `PushPull`, or
+/// `OpenDrain`.
+pub struct Output<MODE> {
+    _mode: PhantomData<MODE>,
+}
+
+// The following collection of types is used to encode the
+// state of the pin at compile time and helps to avoid misuse.
+
+/// Floating Input
+pub struct Floating;
+/// Pulled down Input
+pub struct PullDown;
+/// Pulled up Input
+pub struct PullUp;
+
+/// Totem Pole aka Push-Pull
+pub struct PushPull;
+/// Open drain output
+pub struct OpenDrain;
+
+/// The output drive strength of a pin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DriveStrength {
+    Normal,
+    /// Roughly three times the current of the normal strength
+    Strong,
+}
+
+/// The internal pull resistor of a pin
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pull {
+    None,
+    Up,
+    Down,
+}
+
+/// Peripheral Function A
+pub struct PfA;
+/// Peripheral Function B
+pub struct PfB;
+/// Peripheral Function C
+pub struct PfC;
+/// Peripheral Function D
+pub struct PfD;
+/// Peripheral Function E
+pub struct PfE;
+/// Peripheral Function F
+pub struct PfF;
+/// Peripheral Function G
+pub struct PfG;
+/// Peripheral Function H
+pub struct PfH;
+
+/// A trait that makes it easier to generically manage
+/// converting a pin from its current state into some
+/// other functional mode.  The configuration change
+/// requires exclusive access to the Port hardware,
+/// which is why this isn't simply the standard `Into`
+/// trait.
+pub trait IntoFunction<T> {
+    /// Consume the pin and configure it to operate in
+    /// the mode T.
+    fn into_function(self, port: &mut Port) -> T;
+}
+
+// rustfmt wants to keep indenting the nested macro on each run,
+// so disable it for this whole block :-/
+#[cfg_attr(rustfmt, rustfmt_skip)]
+macro_rules! pin {
+    (
+        $PinType:ident,
+        $pin_ident:ident,
+        $pin_no:expr,
+        $pin_mode:ty,
+        $dirset:ident,
+        $dirclr:ident,
+        $pincfg:ident,
+        $outset:ident,
+        $outclr:ident,
+        $pinmux:ident,
+        $out:ident,
+        $outtgl:ident,
+        $in:ident,
+        $ctrl:ident,
+        $group:expr,
+        [$($function:ident),*]
+    ) => {
+        // Helper for pmux peripheral function configuration.  Each
+        // function has a name for the kind of peripheral that the
+        // datasheet places on that column of the multiplexing table.
+        macro_rules! function {
+            (a) => { function!(PfA, into_function_a, a, into_eic,
+                "Routes the pin to the EIC as an external interrupt"); };
+            (b) => { function!(PfB, into_function_b, b, into_ain,
+                "Routes the pin to the analog peripherals (ADC, AC, DAC, PTC and references)"); };
+            (c) => { function!(PfC, into_function_c, c, into_sercom_pad,
+                "Routes the pin to a SERCOM pad"); };
+            (d) => { function!(PfD, into_function_d, d, into_sercom_alt_pad,
+                "Routes the pin to an alternate SERCOM pad"); };
+            (e) => { function!(PfE, into_function_e, e, into_tcc_wo,
+                "Routes the pin to a TC or TCC waveform output"); };
+            (f) => { function!(PfF, into_function_f, f, into_tcc_alt_wo,
+                "Routes the pin to an alternate TCC waveform output"); };
+            (g) => { function!(PfG, into_function_g, g, into_com,
+                "Routes the pin to a communication peripheral (USB, I2S or SWD)"); };
+            (h) => { function!(PfH, into_function_h, h, into_gclk_io,
+                "Routes the pin to an AC comparator output or a GCLK_IO"); };
+            ($FuncType:ty, $func_ident:ident, $variant:ident, $alias:ident, $doc:expr) => {
+
+        impl<MODE> $PinType<MODE> {
+            #[doc = $doc]
+            pub fn $alias(self, port: &mut Port) -> $PinType<$FuncType> {
+                self.$func_ident(port)
+            }
+
+            /// Configures the pin to operate with a peripheral
+            pub fn $func_ident(
+                self,
+                port: &mut Port
+            ) -> $PinType<$FuncType> {
+                port.$pinmux()[$pin_no >> 1].modify(|_, w| {
+                    if $pin_no & 1 == 1 {
+                        // Odd-numbered pin
+                        w.pmuxo().$variant()
+                    } else {
+                        // Even-numbered pin
+                        w.pmuxe().$variant()
+                    }
+                });
+                port.$pincfg()[$pin_no].write(|bits| {
+                    bits.pmuxen().set_bit()
+                });
+
+                $PinType { _mode: PhantomData }
+            }
+        }
+        impl<MODE> IntoFunction<$PinType<$FuncType>> for $PinType<MODE> {
+            fn into_function(self, port: &mut Port) -> $PinType<$FuncType> {
+                self.$func_ident(port)
+            }
+        }
+
+        impl $PinType<$FuncType> {
+            /// Configures the internal pull resistor while the pin is
+            /// controlled by the peripheral.  This is useful for I2C
+            /// without external pull ups, or to keep a UART RX line
+            /// idle when nothing is connected.
+            pub fn set_pull(&mut self, port: &mut Port, pull: Pull) {
+                self.set_pull_impl(port, pull);
+            }
+        }
+
+            };
+        }
+
+        /// Represents the IO pin with the matching name.
+        pub struct $PinType<MODE> {
+            _mode: PhantomData<MODE>,
+        }
+
+        $(
+            function!($function);
+        )*
+
+        impl<MODE> $PinType<MODE> {
+            /// Erases the pin number from the type, so that the pin can be
+            /// stored alongside other pins in the same mode
+            pub fn downgrade(self) -> Pin<MODE> {
+                Pin {
+                    group: $group,
+                    pin: $pin_no,
+                    _mode: PhantomData,
+                }
+            }
+
+            /// Enables continuous sampling of the input.  By default the
+            /// input is only sampled when it is read, which adds a
+            /// couple of cycles of latency to each read; continuous
+            /// sampling avoids this at the cost of extra power.
+            pub fn set_continuous_sampling(&mut self, port: &mut Port, on: bool) {
+                if on {
+                    port.sampling[$group] |= 1 << $pin_no;
+                } else {
+                    port.sampling[$group] &= !(1 << $pin_no);
+                }
+                let sampling = port.sampling[$group];
+                port.$ctrl().write(|w| unsafe { w.sampling().bits(sampling) });
+            }
+
+            // Only for pins that aren't GPIO outputs, where OUT selects the
+            // pull direction rather than driving the line.
+            fn set_pull_impl(&mut self, port: &mut Port, pull: Pull) {
+                port.$pincfg()[$pin_no].modify(|_, bits| {
+                    bits.pullen().bit(pull != Pull::None)
+                });
+                // OUT selects the direction of the pull
+                match pull {
+                    Pull::Up => port.$outset().write(|bits| unsafe {
+                        bits.bits(1 << $pin_no)
+                    }),
+                    Pull::Down => port.$outclr().write(|bits| unsafe {
+                        bits.bits(1 << $pin_no)
+                    }),
+                    Pull::None => {}
+                }
+            }
+
+            /// Configures the pin to operate as a floating input
+            pub fn into_floating_input(self, port: &mut Port) -> $PinType<Input<Floating>> {
+                port.$dirclr().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                port.$pincfg()[$pin_no].write(|bits| {
+                    bits.pmuxen().clear_bit();
+                    bits.inen().set_bit();
+                    bits.pullen().clear_bit();
+                    bits.drvstr().clear_bit();
+                    bits
+                });
+
+                $PinType { _mode: PhantomData }
+            }
+
+            /// Configures the pin to operate as a pulled down input pin
+            pub fn into_pull_down_input(self, port: &mut Port) -> $PinType<Input<PullDown>> {
+                port.$dirclr().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                port.$pincfg()[$pin_no].write(|bits| {
+                    bits.pmuxen().clear_bit();
+                    bits.inen().set_bit();
+                    bits.pullen().set_bit();
+                    bits.drvstr().clear_bit();
+                    bits
+                });
+
+                // Pull down
+                port.$outclr().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                $PinType { _mode: PhantomData }
+            }
+
+            /// Configures the pin to operate as a pulled up input pin
+            pub fn into_pull_up_input(self, port: &mut Port) -> $PinType<Input<PullUp>> {
+                port.$dirclr().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                port.$pincfg()[$pin_no].write(|bits| {
+                    bits.pmuxen().clear_bit();
+                    bits.inen().set_bit();
+                    bits.pullen().set_bit();
+                    bits.drvstr().clear_bit();
+                    bits
+                });
+
+                // Pull up
+                port.$outset().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                $PinType { _mode: PhantomData }
+            }
+
+            /// Configures the pin to operate as an open drain output.
+            /// The input buffer is enabled so that the level of the
+            /// line can be read back.
+            pub fn into_open_drain_output(self, port: &mut Port) -> $PinType<Output<OpenDrain>> {
+                port.$dirset().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                port.$pincfg()[$pin_no].write(|bits| {
+                    bits.pmuxen().clear_bit();
+                    bits.inen().set_bit();
+                    bits.pullen().clear_bit();
+                    bits.drvstr().clear_bit();
+                    bits
+                });
+
+                $PinType { _mode: PhantomData }
+            }
+
+            /// Configures the pin to operate as a push-pull output
+            pub fn into_push_pull_output(self, port: &mut Port) -> $PinType<Output<PushPull>> {
+                port.$dirset().write(|bits| unsafe {
+                    bits.bits(1 << $pin_no);
+                    bits
+                });
+
+                port.$pincfg()[$pin_no].write(|bits| {
+                    bits.pmuxen().clear_bit();
+                    bits.inen().set_bit();
+                    bits.pullen().clear_bit();
+                    bits.drvstr().clear_bit();
+                    bits
+                });
+
+                $PinType { _mode: PhantomData }
+            }
+        }
+
+        impl $PinType<Output<OpenDrain>> {
+            /// Control state of the internal pull up.  Only PULLEN is
+            /// changed; OUT is left alone because writing it would drive
+            /// the line rather than select the pull direction.
+            pub fn internal_pull_up(&mut self, port: &mut Port, on: bool) {
+                port.$pincfg()[$pin_no].modify(|_, bits| bits.pullen().bit(on));
+            }
+        }
+
+        #[cfg(feature = "unproven")]
+        impl InputPin for $PinType<Output<OpenDrain>> {
+            fn is_high(&self) -> bool {
+                unsafe { (((*PORT::ptr()).$in.read().bits()) & (1 << $pin_no)) != 0 }
+            }
+
+            fn is_low(&self) -> bool {
+                unsafe { (((*PORT::ptr()).$in.read().bits()) & (1 << $pin_no)) == 0 }
+            }
+        }
+
+        impl<MODE> $PinType<Output<MODE>> {
+            /// Sets the output drive strength
+            pub fn set_drive_strength(&mut self, port: &mut Port, strength: DriveStrength) {
+                port.$pincfg()[$pin_no].modify(|_, bits| {
+                    bits.drvstr().bit(strength == DriveStrength::Strong)
+                });
+            }
+
+            /// Enables or disables the input buffer, which is needed to
+            /// read back the level of the pin
+            pub fn set_input_enabled(&mut self, port: &mut Port, on: bool) {
+                port.$pincfg()[$pin_no].modify(|_, bits| bits.inen().bit(on));
+            }
+
+            /// Toggle the logic level of the pin; if it is currently
+            /// high, set it low and vice versa.
+            pub fn toggle(&mut self) {
+                self.toggle_impl();
+            }
+
+            fn toggle_impl(&mut self) {
+                unsafe {
+                    (*PORT::ptr()).$outtgl.write(|bits| {
+                        bits.bits(1 << $pin_no);
+                        bits
+                    });
+                }
+            }
+        }
+
+        #[cfg(feature = "unproven")]
+        impl<MODE> ToggleableOutputPin for $PinType<Output<MODE>> {
+            fn toggle(&mut self) {
+                self.toggle_impl();
+            }
+        }
+
+        #[cfg(feature = "unproven")]
+        impl<MODE> InputPin for $PinType<Input<MODE>> {
+            fn is_high(&self) -> bool {
+                unsafe { (((*PORT::ptr()).$in.read().bits()) & (1 << $pin_no)) != 0 }
+            }
+
+            fn is_low(&self) -> bool {
+                unsafe { (((*PORT::ptr()).$in.read().bits()) & (1 << $pin_no)) == 0 }
+            }
+        }
+
+        #[cfg(feature = "unproven")]
+        impl<MODE> StatefulOutputPin for $PinType<Output<MODE>> {
+            fn is_set_high(&self) -> bool {
+                unsafe { (((*PORT::ptr()).$out.read().bits()) & (1 << $pin_no)) != 0 }
+            }
+
+            fn is_set_low(&self) -> bool {
+                unsafe { (((*PORT::ptr()).$out.read().bits()) & (1 << $pin_no)) == 0 }
+            }
+        }
+
+
+        impl<MODE> OutputPin for $PinType<Output<MODE>> {
+            fn set_high(&mut self) {
+                unsafe {
+                    (*PORT::ptr()).$outset.write(|bits| {
+                        bits.bits(1 << $pin_no);
+                        bits
+                    });
+                }
+            }
+
+            fn set_low(&mut self) {
+                unsafe {
+                    (*PORT::ptr()).$outclr.write(|bits| {
+                        bits.bits(1 << $pin_no);
+                        bits
+                    });
+                }
+            }
+        }
+    };
+}
+
+/// Opaque port reference
+pub struct Port {
+    /// CTRL is write only, so the sampling bits of each
+    /// group are tracked here
+    sampling: [u32; 2],
+}
+
+impl Port {
+    fn dirset0(&mut self) -> &DIRSET {
+        unsafe { &(*PORT::ptr()).dirset0 }
+    }
+    fn dirclr0(&mut self) -> &DIRCLR {
+        unsafe { &(*PORT::ptr()).dirclr0 }
+    }
+    fn pincfg0(&mut self) -> &[PINCFG0_; 32] {
+        unsafe { &(*PORT::ptr()).pincfg0_ }
+    }
+    fn outset0(&mut self) -> &OUTSET {
+        unsafe { &(*PORT::ptr()).outset0 }
+    }
+    fn outclr0(&mut self) -> &OUTCLR {
+        unsafe { &(*PORT::ptr()).outclr0 }
+    }
+    fn pmux0(&mut self) -> &[PMUX0_; 16] {
+        unsafe { &(*PORT::ptr()).pmux0_ }
+    }
+    fn ctrl0(&mut self) -> &CTRL {
+        unsafe { &(*PORT::ptr()).ctrl0 }
+    }
+
+    #[cfg(feature = "pins-48")]
+    fn dirset1(&mut self) -> &DIRSET {
+        unsafe { &(*PORT::ptr()).dirset1 }
+    }
+    #[cfg(feature = "pins-48")]
+    fn dirclr1(&mut self) -> &DIRCLR {
+        unsafe { &(*PORT::ptr()).dirclr1 }
+    }
+    #[cfg(feature = "pins-48")]
+    fn pincfg1(&mut self) -> &[PINCFG1_; 32] {
+        unsafe { &(*PORT::ptr()).pincfg1_ }
+    }
+    #[cfg(feature = "pins-48")]
+    fn outset1(&mut self) -> &OUTSET {
+        unsafe { &(*PORT::ptr()).outset1 }
+    }
+    #[cfg(feature = "pins-48")]
+    fn outclr1(&mut self) -> &OUTCLR {
+        unsafe { &(*PORT::ptr()).outclr1 }
+    }
+    #[cfg(feature = "pins-48")]
+    fn pmux1(&mut self) -> &[PMUX1_; 16] {
+        unsafe { &(*PORT::ptr()).pmux1_ }
+    }
+    #[cfg(feature = "pins-48")]
+    fn ctrl1(&mut self) -> &CTRL {
+        unsafe { &(*PORT::ptr()).ctrl1 }
+    }
+}
+
+/// The registers of one port group.  The PAC has separate fields for
+/// each group, but the groups have identical layouts at 0x80 byte
+/// intervals, so this view allows a group to be selected at runtime.
+/// Every register is listed to preserve the layout, whether or not
+/// it is used.
+#[repr(C)]
+#[allow(dead_code)]
+struct GroupRegisters {
+    dir: DIR,
+    dirclr: DIRCLR,
+    dirset: DIRSET,
+    dirtgl: DIRTGL,
+    out: OUT,
+    outclr: OUTCLR,
+    outset: OUTSET,
+    outtgl: OUTTGL,
+    in_: IN,
+    ctrl: CTRL,
+    wrconfig: WRCONFIG,
+    _reserved: [u8; 4],
+    pmux: [PMUX0_; 16],
+    pincfg: [PINCFG0_; 32],
+}
+
+fn group_registers(group: u8) -> &'static GroupRegisters {
+    group_at(PORT::ptr() as *const u8, group)
+}
+
+/// The same registers through the single-cycle IOBUS
+fn iobus_group_registers(group: u8) -> &'static GroupRegisters {
+    group_at(PORT_IOBUS::ptr() as *const u8, group)
+}
+
+fn group_at(base: *const u8, group: u8) -> &'static GroupRegisters {
+    unsafe { &*(base.offset(isize::from(group) * 0x80) as *const GroupRegisters) }
+}
+
+/// A pin whose group and number are only known at runtime,
+/// created by `downgrade`
+pub struct Pin<MODE> {
+    group: u8,
+    pin: u8,
+    _mode: PhantomData<MODE>,
+}
+
+impl<MODE> Pin<MODE> {
+    /// The port group; 0 for PAn and 1 for PBn
+    pub fn group(&self) -> u8 {
+        self.group
+    }
+
+    /// The pin number within the group
+    pub fn pin(&self) -> u8 {
+        self.pin
+    }
+
+    fn mask(&self) -> u32 {
+        1 << self.pin
+    }
+
+    fn registers(&self) -> &'static GroupRegisters {
+        group_registers(self.group)
+    }
+
+    fn into_mode<NEW>(self) -> Pin<NEW> {
+        Pin {
+            group: self.group,
+            pin: self.pin,
+            _mode: PhantomData,
+        }
+    }
+
+    /// Configures the pin as an input or output with the given settings
+    fn configure(&self, output: bool, inen: bool, pullen: bool) {
+        let regs = self.registers();
+        if output {
+            regs.dirset.write(|bits| unsafe { bits.bits(self.mask()) });
+        } else {
+            regs.dirclr.write(|bits| unsafe { bits.bits(self.mask()) });
+        }
+        regs.pincfg[self.pin as usize].write(|bits| {
+            bits.pmuxen().clear_bit();
+            bits.inen().bit(inen);
+            bits.pullen().bit(pullen);
+            bits.drvstr().clear_bit();
+            bits
+        });
+    }
+
+    /// Configures the pin to operate as a floating input
+    pub fn into_floating_input(self, _port: &mut Port) -> Pin<Input<Floating>> {
+        self.configure(false, true, false);
+        self.into_mode()
+    }
+
+    /// Configures the pin to operate as a pulled down input pin
+    pub fn into_pull_down_input(self, _port: &mut Port) -> Pin<Input<PullDown>> {
+        self.configure(false, true, true);
+        let mask = self.mask();
+        self.registers().outclr.write(|bits| unsafe { bits.bits(mask) });
+        self.into_mode()
+    }
+
+    /// Configures the pin to operate as a pulled up input pin
+    pub fn into_pull_up_input(self, _port: &mut Port) -> Pin<Input<PullUp>> {
+        self.configure(false, true, true);
+        let mask = self.mask();
+        self.registers().outset.write(|bits| unsafe { bits.bits(mask) });
+        self.into_mode()
+    }
+
+    /// Configures the pin to operate as an open drain output.
+    /// The input buffer is enabled so that the level of the
+    /// line can be read back.
+    pub fn into_open_drain_output(self, _port: &mut Port) -> Pin<Output<OpenDrain>> {
+        self.configure(true, true, false);
+        self.into_mode()
+    }
+
+    /// Configures the pin to operate as a push-pull output
+    pub fn into_push_pull_output(self, _port: &mut Port) -> Pin<Output<PushPull>> {
+        self.configure(true, true, false);
+        self.into_mode()
+    }
+
+    #[cfg(feature = "unproven")]
+    fn is_high_impl(&self) -> bool {
+        self.registers().in_.read().bits() & self.mask() != 0
+    }
+}
+
+impl<MODE> Pin<Output<MODE>> {
+    /// Toggle the logic level of the pin; if it is currently
+    /// high, set it low and vice versa.
+    pub fn toggle(&mut self) {
+        self.toggle_impl();
+    }
+
+    fn toggle_impl(&mut self) {
+        let mask = self.mask();
+        self.registers().outtgl.write(|bits| unsafe { bits.bits(mask) });
+    }
+}
+
+impl<MODE> OutputPin for Pin<Output<MODE>> {
+    fn set_high(&mut self) {
+        let mask = self.mask();
+        self.registers().outset.write(|bits| unsafe { bits.bits(mask) });
+    }
+
+    fn set_low(&mut self) {
+        let mask = self.mask();
+        self.registers().outclr.write(|bits| unsafe { bits.bits(mask) });
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl<MODE> ToggleableOutputPin for Pin<Output<MODE>> {
+    fn toggle(&mut self) {
+        self.toggle_impl();
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl<MODE> StatefulOutputPin for Pin<Output<MODE>> {
+    fn is_set_high(&self) -> bool {
+        self.registers().out.read().bits() & self.mask() != 0
+    }
+
+    fn is_set_low(&self) -> bool {
+        !self.is_set_high()
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl<MODE> InputPin for Pin<Input<MODE>> {
+    fn is_high(&self) -> bool {
+        self.is_high_impl()
+    }
+
+    fn is_low(&self) -> bool {
+        !self.is_high_impl()
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl InputPin for Pin<Output<OpenDrain>> {
+    fn is_high(&self) -> bool {
+        self.is_high_impl()
+    }
+
+    fn is_low(&self) -> bool {
+        !self.is_high_impl()
+    }
+}
+
+macro_rules! port {
+    ([
+       $($(#[$attrA:meta])* $PinTypeA:ident: ($pin_identA:ident, $pin_noA:expr,
+            $pin_modeA:ty, [$($funcA:ident),*]),)+
+    ],[
+       $($(#[$attrB:meta])* $PinTypeB:ident: ($pin_identB:ident, $pin_noB:expr,
+            $pin_modeB:ty, [$($funcB:ident),*]),)+
+    ]) => {
+
+/// Holds the GPIO Port peripheral and broken out pin instances
+pub struct Parts {
+    /// Opaque port reference
+    pub port: Port,
+
+    $(
+        /// Pin $pin_identA
+        $(#[$attrA])*
+        pub $pin_identA: $PinTypeA<$pin_modeA>,
+    )+
+    $(
+        /// Pin $pin_identB
+        #[cfg(feature = "pins-48")]
+        $(#[$attrB])*
+        pub $pin_identB: $PinTypeB<$pin_modeB>,
+    )+
+}
+
+impl GpioExt for PORT {
+    type Parts = Parts;
+
+    /// Split the PORT peripheral into discrete pins
+    fn split(self) -> Parts {
+        Parts {
+            port: Port { sampling: [0; 2] },
+            $(
+                $(#[$attrA])*
+                $pin_identA: $PinTypeA { _mode: PhantomData },
+            )+
+            $(
+                #[cfg(feature = "pins-48")]
+                $(#[$attrB])*
+                $pin_identB: $PinTypeB { _mode: PhantomData },
+            )+
+        }
+    }
+}
+
+$(
+    $(#[$attrA])*
+    pin!($PinTypeA, $pin_identA, $pin_noA, $pin_modeA, dirset0, dirclr0,
+        pincfg0, outset0, outclr0, pmux0, out0, outtgl0, in0, ctrl0, 0, [$($funcA),*]);
+)+
+$(
+    #[cfg(feature = "pins-48")]
+    $(#[$attrB])*
+    pin!($PinTypeB, $pin_identB, $pin_noB, $pin_modeB, dirset1, dirclr1,
+        pincfg1, outset1, outclr1, pmux1, out1, outtgl1, in1, ctrl1, 1, [$($funcB),*]);
+)+
+
+    };
+}
+
+// The peripheral functions that each pin supports, from the
+// PORT Function Multiplexing table in the datasheet.  The pins without
+// any functions are not bonded out in any package, and the cfgs drop the
+// pins that the part's package doesn't bond out; see the "pins-48" and
+// "pins-64" features.
+port!([
+    Pa0: (pa0, 0, Input<Floating>, [a, d, e]),
+    Pa1: (pa1, 1, Input<Floating>, [a, d, e]),
+    Pa2: (pa2, 2, Input<Floating>, [a, b]),
+    Pa3: (pa3, 3, Input<Floating>, [a, b]),
+    Pa4: (pa4, 4, Input<Floating>, [a, b, d, e]),
+    Pa5: (pa5, 5, Input<Floating>, [a, b, d, e]),
+    Pa6: (pa6, 6, Input<Floating>, [a, b, d, e]),
+    Pa7: (pa7, 7, Input<Floating>, [a, b, d, e, g]),
+    Pa8: (pa8, 8, Input<Floating>, [a, b, c, d, e, f, g]),
+    Pa9: (pa9, 9, Input<Floating>, [a, b, c, d, e, f, g]),
+    Pa10: (pa10, 10, Input<Floating>, [a, b, c, d, e, f, g, h]),
+    Pa11: (pa11, 11, Input<Floating>, [a, b, c, d, e, f, g, h]),
+    #[cfg(feature = "pins-48")]
+    Pa12: (pa12, 12, Input<Floating>, [a, c, d, e, f, h]),
+    #[cfg(feature = "pins-48")]
+    Pa13: (pa13, 13, Input<Floating>, [a, c, d, e, f, h]),
+    Pa14: (pa14, 14, Input<Floating>, [a, c, d, e, f, h]),
+    Pa15: (pa15, 15, Input<Floating>, [a, c, d, e, f, h]),
+    Pa16: (pa16, 16, Input<Floating>, [a, b, c, d, e, f, h]),
+    Pa17: (pa17, 17, Input<Floating>, [a, b, c, d, e, f, h]),
+    Pa18: (pa18, 18, Input<Floating>, [a, b, c, d, e, f, h]),
+    Pa19: (pa19, 19, Input<Floating>, [a, b, c, d, e, f, g, h]),
+    #[cfg(feature = "pins-48")]
+    Pa20: (pa20, 20, Input<Floating>, [a, b, c, d, e, f, g, h]),
+    #[cfg(feature = "pins-48")]
+    Pa21: (pa21, 21, Input<Floating>, [a, b, c, d, e, f, g, h]),
+    Pa22: (pa22, 22, Input<Floating>, [a, b, c, d, e, f, h]),
+    Pa23: (pa23, 23, Input<Floating>, [a, b, c, d, e, f, g, h]),
+    Pa24: (pa24, 24, Input<Floating>, [a, c, d, e, f, g]),
+    Pa25: (pa25, 25, Input<Floating>, [a, c, d, e, f, g]),
+    Pa26: (pa26, 26, Input<Floating>, []),
+    Pa27: (pa27, 27, Input<Floating>, [a, h]),
+    Pa28: (pa28, 28, Input<Floating>, [a, h]),
+    Pa29: (pa29, 29, Input<Floating>, []),
+    Pa30: (pa30, 30, Input<Floating>, [a, d, e, g, h]),
+    Pa31: (pa31, 31, Input<Floating>, [a, d, e, g]),
+],[
+    #[cfg(feature = "pins-64")]
+    Pb0: (pb0, 0, Input<Floating>, [a, b, d, e]),
+    #[cfg(feature = "pins-64")]
+    Pb1: (pb1, 1, Input<Floating>, [a, b, d, e]),
+    Pb2: (pb2, 2, Input<Floating>, [a, b, d, e]),
+    Pb3: (pb3, 3, Input<Floating>, [a, b, d, e]),
+    #[cfg(feature = "pins-64")]
+    Pb4: (pb4, 4, Input<Floating>, [a, b]),
+    #[cfg(feature = "pins-64")]
+    Pb5: (pb5, 5, Input<Floating>, [a, b]),
+    #[cfg(feature = "pins-64")]
+    Pb6: (pb6, 6, Input<Floating>, [a, b]),
+    #[cfg(feature = "pins-64")]
+    Pb7: (pb7, 7, Input<Floating>, [a, b]),
+    Pb8: (pb8, 8, Input<Floating>, [a, b, d, e]),
+    Pb9: (pb9, 9, Input<Floating>, [a, b, d, e]),
+    Pb10: (pb10, 10, Input<Floating>, [a, d, e, f, g, h]),
+    Pb11: (pb11, 11, Input<Floating>, [a, d, e, f, g, h]),
+    #[cfg(feature = "pins-64")]
+    Pb12: (pb12, 12, Input<Floating>, [a, b, c, e, f, g, h]),
+    #[cfg(feature = "pins-64")]
+    Pb13: (pb13, 13, Input<Floating>, [a, b, c, e, f, h]),
+    #[cfg(feature = "pins-64")]
+    Pb14: (pb14, 14, Input<Floating>, [a, b, c, e, h]),
+    #[cfg(feature = "pins-64")]
+    Pb15: (pb15, 15, Input<Floating>, [a, b, c, e, h]),
+    #[cfg(feature = "pins-64")]
+    Pb16: (pb16, 16, Input<Floating>, [a, c, e, f, g, h]),
+    #[cfg(feature = "pins-64")]
+    Pb17: (pb17, 17, Input<Floating>, [a, c, e, f, g, h]),
+    Pb18: (pb18, 18, Input<Floating>, []),
+    Pb19: (pb19, 19, Input<Floating>, []),
+    Pb20: (pb20, 20, Input<Floating>, []),
+    Pb21: (pb21, 21, Input<Floating>, []),
+    Pb22: (pb22, 22, Input<Floating>, [a, d, e, h]),
+    Pb23: (pb23, 23, Input<Floating>, [a, d, e, h]),
+    Pb24: (pb24, 24, Input<Floating>, []),
+    Pb25: (pb25, 25, Input<Floating>, []),
+    Pb26: (pb26, 26, Input<Floating>, []),
+    Pb27: (pb27, 27, Input<Floating>, []),
+    Pb28: (pb28, 28, Input<Floating>, []),
+    Pb29: (pb29, 29, Input<Floating>, []),
+    #[cfg(feature = "pins-64")]
+    Pb30: (pb30, 30, Input<Floating>, [a, d, e, f]),
+    #[cfg(feature = "pins-64")]
+    Pb31: (pb31, 31, Input<Floating>, [a, d, e, f]),
+]);
@@ -0,0 +1,709 @@
+//! Inter-IC Sound (I2S) controller, for audio codecs, amplifiers and
+//! microphones.
+//!
+//! The controller has two clock units and two serializers.  A clock unit
+//! either generates the serial clock (SCK) and frame sync (FS) from its
+//! GCLK, optionally with a master clock (MCK) for the codec, or takes
+//! them from the pins when another device is the master.  Each frame has
+//! up to eight slots of 8 to 32 bits: two for stereo I2S, more for TDM.
+//! A serializer moves the data of one SD pin, using either clock unit,
+//! to transmit, to receive, or to receive from PDM microphones.
+//!
+//! Everything is configured before `enable` is called.  Samples can be
+//! moved one word at a time with `write` and `read`, or streamed with a
+//! DMA channel using `transmit_dma` and `receive_dma`.
+use clock;
+use dma::{self, BeatSize, Transfer, TriggerAction, TriggerSource};
+use gpio::{self, IntoFunction, Port};
+use nb;
+use target_device::{I2S, PM};
+use time::Hertz;
+
+/// Errors reported by the I2S controller
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// The serial or master clock cannot be derived from the GCLK,
+    /// which can only be divided by 1 to 32
+    ClockDivider,
+    /// A transmitting serializer had no data when a slot started
+    Underrun,
+    /// A received word was lost because the previous one had not
+    /// been read
+    Overrun,
+}
+
+macro_rules! i2s_pins {
+    ($(pub enum $PadType:ident {
+        $( $(#[$attr:meta])* $PinType:ident ($new:ident),)+
+    })+
+    ) => {
+$(
+/// Represents a pin configured for the I2S signal with the
+/// matching name.  All of the I2S pins use peripheral function G.
+pub enum $PadType {
+    $(
+        $(#[$attr])*
+        $PinType(gpio::$PinType<gpio::PfG>),
+    )+
+}
+
+impl $PadType {
+    $(
+    /// Construct the pad from the appropriate pin in any mode.
+    $(#[$attr])*
+    pub fn $new<MODE>(pin: gpio::$PinType<MODE>, port: &mut Port) -> Self {
+        $PadType::$PinType(pin.into_function(port))
+    }
+    )+
+}
+
+$(
+$(#[$attr])*
+impl<MODE> I2sPin<$PadType> for gpio::$PinType<MODE> {
+    fn into_i2s(self, port: &mut Port) -> $PadType {
+        $PadType::$new(self, port)
+    }
+}
+)+
+)+
+    };
+}
+
+/// The I2sPin trait makes it more ergonomic to convert a
+/// pin into an I2S pad.
+pub trait I2sPin<T> {
+    fn into_i2s(self, port: &mut Port) -> T;
+}
+
+i2s_pins!(
+pub enum Mck0 {
+    Pa9(pa9),
+    #[cfg(feature = "pins-64")]
+    Pb17(pb17),
+}
+
+pub enum Sck0 {
+    Pa10(pa10),
+    #[cfg(feature = "pins-48")]
+    Pa20(pa20),
+}
+
+pub enum Fs0 {
+    Pa11(pa11),
+    #[cfg(feature = "pins-48")]
+    Pa21(pa21),
+}
+
+pub enum Sd0 {
+    Pa7(pa7),
+    Pa19(pa19),
+}
+
+pub enum Sd1 {
+    Pa8(pa8),
+    #[cfg(feature = "pins-64")]
+    Pb16(pb16),
+}
+);
+
+#[cfg(feature = "pins-48")]
+i2s_pins!(
+pub enum Mck1 {
+    Pb10(pb10),
+}
+
+pub enum Sck1 {
+    Pb11(pb11),
+}
+
+pub enum Fs1 {
+    #[cfg(feature = "pins-64")]
+    Pb12(pb12),
+}
+);
+
+/// The pins of clock unit 0.  FS is not used by PDM microphones, and
+/// MCK is only needed by codecs that require a master clock.
+pub struct ClockUnit0Pins {
+    pub sck: Sck0,
+    pub fs: Option<Fs0>,
+    pub mck: Option<Mck0>,
+}
+
+/// The pins of clock unit 1, which are only bonded out on the
+/// larger packages.  FS1 is only bonded out on the 64 pin parts.
+#[cfg(feature = "pins-48")]
+pub struct ClockUnit1Pins {
+    pub sck: Sck1,
+    pub fs: Option<Fs1>,
+    pub mck: Option<Mck1>,
+}
+
+/// Selects one of the two clock units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockUnit {
+    Unit0,
+    Unit1,
+}
+
+/// Selects one of the two serializers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Serializer {
+    Serializer0,
+    Serializer1,
+}
+
+impl Serializer {
+    fn index(self) -> usize {
+        match self {
+            Serializer::Serializer0 => 0,
+            Serializer::Serializer1 => 1,
+        }
+    }
+}
+
+/// The number of bits in each slot of a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlotSize {
+    Bits8,
+    Bits16,
+    Bits24,
+    Bits32,
+}
+
+impl SlotSize {
+    fn bits(self) -> u32 {
+        match self {
+            SlotSize::Bits8 => 8,
+            SlotSize::Bits16 => 16,
+            SlotSize::Bits24 => 24,
+            SlotSize::Bits32 => 32,
+        }
+    }
+}
+
+/// The shape of the frame sync and the position of the data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    /// Philips I2S: FS is low for the first half of the frame, and the
+    /// data starts one bit after FS changes
+    I2s,
+    /// FS is high for the first half of the frame, and the data starts
+    /// as FS changes
+    LeftJustified,
+    /// FS is a one bit pulse one bit before the start of the frame, as
+    /// used by TDM and DSP mode codecs
+    Tdm,
+}
+
+/// Where the clock unit gets its clocks from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockMode {
+    /// SCK and FS are generated from the GCLK for `sample_rate` frames
+    /// per second, or the nearest rate that it divides down to.  If the
+    /// MCK pin is given, it outputs a master clock at 256 times the
+    /// sample rate.
+    Master { sample_rate: Hertz },
+    /// SCK and FS are inputs from another master
+    Slave,
+}
+
+/// The frame generated or expected by a clock unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockConfig {
+    pub mode: ClockMode,
+    pub format: Format,
+    pub slot_size: SlotSize,
+    /// The number of slots in each frame, from 1 to 8
+    pub slots: u8,
+}
+
+impl ClockConfig {
+    /// Stereo I2S frames of two slots, generated at `sample_rate`
+    pub fn i2s<F: Into<Hertz>>(sample_rate: F, slot_size: SlotSize) -> Self {
+        Self {
+            mode: ClockMode::Master {
+                sample_rate: sample_rate.into(),
+            },
+            format: Format::I2s,
+            slot_size,
+            slots: 2,
+        }
+    }
+
+    /// TDM frames of `slots` slots, generated at `sample_rate`
+    pub fn tdm<F: Into<Hertz>>(sample_rate: F, slot_size: SlotSize, slots: u8) -> Self {
+        Self {
+            mode: ClockMode::Master {
+                sample_rate: sample_rate.into(),
+            },
+            format: Format::Tdm,
+            slot_size,
+            slots,
+        }
+    }
+
+    /// A PDM microphone clock of `clock_rate`, which is typically 1 to
+    /// 3Mhz.  Each frame holds 64 bits of PDM data, so one word from
+    /// each microphone on the data line.
+    pub fn pdm<F: Into<Hertz>>(clock_rate: F) -> Self {
+        Self {
+            mode: ClockMode::Master {
+                sample_rate: Hertz(clock_rate.into().0 / 64),
+            },
+            format: Format::LeftJustified,
+            slot_size: SlotSize::Bits32,
+            slots: 2,
+        }
+    }
+}
+
+/// The number of bits of each sample, and how they are packed into
+/// the words read and written
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataSize {
+    Bits8,
+    Bits16,
+    Bits18,
+    Bits20,
+    Bits24,
+    Bits32,
+    /// Two 16 bit samples in each word, the first in the low half
+    Compact16,
+    /// Four 8 bit samples in each word, the first in the low byte
+    Compact8,
+}
+
+/// Which slots of the frame a serializer uses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Channels {
+    /// Only the first slot.  When transmitting, the sample is sent
+    /// in the second slot as well.
+    Mono,
+    /// Every slot, with one word per slot
+    Stereo,
+    /// The slots whose bits are set in the mask, with bit 0 for the
+    /// first slot
+    Tdm(u8),
+}
+
+/// What a serializer does
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SerializerMode {
+    Transmit,
+    Receive,
+    /// Receive from two PDM microphones sharing the data line, one
+    /// sampled on each edge of SCK.  A single microphone uses
+    /// `Receive`.
+    Pdm2,
+}
+
+/// The configuration of a serializer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SerializerConfig {
+    pub mode: SerializerMode,
+    pub clock_unit: ClockUnit,
+    pub data_size: DataSize,
+    pub channels: Channels,
+}
+
+impl SerializerConfig {
+    pub fn transmit(clock_unit: ClockUnit, data_size: DataSize, channels: Channels) -> Self {
+        Self {
+            mode: SerializerMode::Transmit,
+            clock_unit,
+            data_size,
+            channels,
+        }
+    }
+
+    pub fn receive(clock_unit: ClockUnit, data_size: DataSize, channels: Channels) -> Self {
+        Self {
+            mode: SerializerMode::Receive,
+            clock_unit,
+            data_size,
+            channels,
+        }
+    }
+
+    /// Raw PDM bits from one microphone, or two if `stereo` is set,
+    /// 32 at a time.  The bits must be filtered and decimated in
+    /// software to recover the audio.
+    pub fn pdm(clock_unit: ClockUnit, stereo: bool) -> Self {
+        Self {
+            mode: if stereo {
+                SerializerMode::Pdm2
+            } else {
+                SerializerMode::Receive
+            },
+            clock_unit,
+            data_size: DataSize::Bits32,
+            channels: if stereo {
+                Channels::Stereo
+            } else {
+                Channels::Mono
+            },
+        }
+    }
+}
+
+/// The pins released by `I2s::free`
+pub struct I2sPins {
+    pub unit0: Option<ClockUnit0Pins>,
+    #[cfg(feature = "pins-48")]
+    pub unit1: Option<ClockUnit1Pins>,
+    pub sd0: Option<Sd0>,
+    pub sd1: Option<Sd1>,
+}
+
+/// The I2S controller
+pub struct I2s {
+    i2s: I2S,
+    pins: I2sPins,
+    modes: [Option<SerializerMode>; 2],
+}
+
+impl I2s {
+    /// Powers up and resets the controller
+    pub fn new(i2s: I2S, pm: &mut PM) -> Self {
+        pm.apbcmask.modify(|_, w| w.i2s_().set_bit());
+
+        i2s.ctrla.write(|w| w.swrst().set_bit());
+        while i2s.syncbusy.read().swrst().bit_is_set() {}
+
+        Self {
+            i2s,
+            pins: I2sPins {
+                unit0: None,
+                #[cfg(feature = "pins-48")]
+                unit1: None,
+                sd0: None,
+                sd1: None,
+            },
+            modes: [None; 2],
+        }
+    }
+
+    /// Configures clock unit 0
+    pub fn clock_unit0(
+        &mut self,
+        clock: &clock::I2s0Clock,
+        config: ClockConfig,
+        pins: ClockUnit0Pins,
+    ) -> Result<(), Error> {
+        self.configure_clock_unit(0, clock.freq(), config, pins.mck.is_some())?;
+        self.pins.unit0 = Some(pins);
+        Ok(())
+    }
+
+    /// Configures clock unit 1
+    #[cfg(feature = "pins-48")]
+    pub fn clock_unit1(
+        &mut self,
+        clock: &clock::I2s1Clock,
+        config: ClockConfig,
+        pins: ClockUnit1Pins,
+    ) -> Result<(), Error> {
+        self.configure_clock_unit(1, clock.freq(), config, pins.mck.is_some())?;
+        self.pins.unit1 = Some(pins);
+        Ok(())
+    }
+
+    fn configure_clock_unit(
+        &mut self,
+        unit: usize,
+        gclk: Hertz,
+        config: ClockConfig,
+        mck: bool,
+    ) -> Result<(), Error> {
+        assert!(config.slots >= 1 && config.slots <= 8);
+
+        let (mckdiv, mckoutdiv) = match config.mode {
+            ClockMode::Master { sample_rate } => {
+                let sck = sample_rate.0 * u32::from(config.slots) * config.slot_size.bits();
+                let mckdiv = divider(gclk.0, sck)?;
+                let mckoutdiv = if mck {
+                    divider(gclk.0, sample_rate.0 * 256)?
+                } else {
+                    0
+                };
+                (mckdiv, mckoutdiv)
+            }
+            ClockMode::Slave => (0, 0),
+        };
+        let master = config.mode != ClockMode::Slave;
+
+        self.i2s.clkctrl[unit].write(|w| {
+            match config.slot_size {
+                SlotSize::Bits8 => w.slotsize()._8(),
+                SlotSize::Bits16 => w.slotsize()._16(),
+                SlotSize::Bits24 => w.slotsize()._24(),
+                SlotSize::Bits32 => w.slotsize()._32(),
+            };
+            unsafe {
+                w.nbslots().bits(config.slots - 1);
+                w.mckdiv().bits(mckdiv);
+                w.mckoutdiv().bits(mckoutdiv);
+            }
+            match config.format {
+                Format::I2s => {
+                    w.fswidth().half();
+                    w.bitdelay().i2s();
+                    w.fsinv().set_bit()
+                }
+                Format::LeftJustified => {
+                    w.fswidth().half();
+                    w.bitdelay().lj();
+                    w.fsinv().clear_bit()
+                }
+                Format::Tdm => {
+                    w.fswidth().bit_();
+                    w.bitdelay().i2s();
+                    w.fsinv().clear_bit()
+                }
+            };
+            if master {
+                w.fssel().sckdiv();
+                w.scksel().mckdiv();
+            } else {
+                w.fssel().fspin();
+                w.scksel().sckpin();
+            }
+            w.mcksel().gclk();
+            w.mcken().bit(master && mck)
+        });
+        Ok(())
+    }
+
+    /// Configures serializer 0, which uses the SD0 pin
+    pub fn serializer0(&mut self, config: SerializerConfig, sd: Sd0) {
+        self.configure_serializer(0, config);
+        self.pins.sd0 = Some(sd);
+    }
+
+    /// Configures serializer 1, which uses the SD1 pin
+    pub fn serializer1(&mut self, config: SerializerConfig, sd: Sd1) {
+        self.configure_serializer(1, config);
+        self.pins.sd1 = Some(sd);
+    }
+
+    fn configure_serializer(&mut self, serializer: usize, config: SerializerConfig) {
+        let enabled = match config.channels {
+            Channels::Tdm(mask) => mask,
+            _ => 0xff,
+        };
+        self.i2s.serctrl[serializer].write(|w| {
+            match config.mode {
+                SerializerMode::Transmit => w.sermode().tx(),
+                SerializerMode::Receive => w.sermode().rx(),
+                SerializerMode::Pdm2 => w.sermode().pdm2(),
+            };
+            match config.clock_unit {
+                ClockUnit::Unit0 => w.clksel().clk0(),
+                ClockUnit::Unit1 => w.clksel().clk1(),
+            };
+            match config.data_size {
+                DataSize::Bits8 => w.datasize()._8(),
+                DataSize::Bits16 => w.datasize()._16(),
+                DataSize::Bits18 => w.datasize()._18(),
+                DataSize::Bits20 => w.datasize()._20(),
+                DataSize::Bits24 => w.datasize()._24(),
+                DataSize::Bits32 => w.datasize()._32(),
+                DataSize::Compact16 => w.datasize()._16c(),
+                DataSize::Compact8 => w.datasize()._8c(),
+            };
+            match config.channels {
+                Channels::Mono => w.mono().mono(),
+                _ => w.mono().stereo(),
+            };
+            // Samples are MSB first at the start of the slot, and are
+            // right aligned in the data words
+            w.slotadj().left();
+            w.wordadj().right();
+            w.extend().zero();
+            w.bitrev().msbit();
+            w.txdefault().zero();
+            w.txsame().zero();
+            w.slotdis0().bit(enabled & 1 << 0 == 0);
+            w.slotdis1().bit(enabled & 1 << 1 == 0);
+            w.slotdis2().bit(enabled & 1 << 2 == 0);
+            w.slotdis3().bit(enabled & 1 << 3 == 0);
+            w.slotdis4().bit(enabled & 1 << 4 == 0);
+            w.slotdis5().bit(enabled & 1 << 5 == 0);
+            w.slotdis6().bit(enabled & 1 << 6 == 0);
+            w.slotdis7().bit(enabled & 1 << 7 == 0);
+            // One DMA channel serves all of the slots
+            w.dma().single()
+        });
+        self.modes[serializer] = Some(config.mode);
+    }
+
+    /// Starts the configured clock units and serializers
+    pub fn enable(&mut self) {
+        let cken0 = self.pins.unit0.is_some();
+        #[cfg(feature = "pins-48")]
+        let cken1 = self.pins.unit1.is_some();
+        #[cfg(not(feature = "pins-48"))]
+        let cken1 = false;
+        let seren0 = self.modes[0].is_some();
+        let seren1 = self.modes[1].is_some();
+
+        self.i2s.ctrla.write(|w| {
+            w.enable().set_bit();
+            w.cken0().bit(cken0);
+            w.cken1().bit(cken1);
+            w.seren0().bit(seren0);
+            w.seren1().bit(seren1)
+        });
+        while self.i2s.syncbusy.read().enable().bit_is_set() {}
+    }
+
+    /// Stops the controller, so that it can be reconfigured
+    pub fn disable(&mut self) {
+        self.i2s.ctrla.write(|w| w.enable().clear_bit());
+        while self.i2s.syncbusy.read().enable().bit_is_set() {}
+    }
+
+    /// Queues the next word for a transmitting serializer
+    pub fn write(&mut self, serializer: Serializer, word: u32) -> nb::Result<(), Error> {
+        let flags = self.i2s.intflag.read();
+        let (ready, underrun) = match serializer {
+            Serializer::Serializer0 => (flags.txrdy0().bit_is_set(), flags.txur0().bit_is_set()),
+            Serializer::Serializer1 => (flags.txrdy1().bit_is_set(), flags.txur1().bit_is_set()),
+        };
+        if underrun {
+            // The flag is cleared by writing a one
+            self.i2s.intflag.write(|w| match serializer {
+                Serializer::Serializer0 => w.txur0().set_bit(),
+                Serializer::Serializer1 => w.txur1().set_bit(),
+            });
+            return Err(nb::Error::Other(Error::Underrun));
+        }
+        if !ready {
+            return Err(nb::Error::WouldBlock);
+        }
+        self.i2s.data[serializer.index()].write(|w| unsafe { w.data().bits(word) });
+        Ok(())
+    }
+
+    /// Reads the next word from a receiving serializer
+    pub fn read(&mut self, serializer: Serializer) -> nb::Result<u32, Error> {
+        let flags = self.i2s.intflag.read();
+        let (ready, overrun) = match serializer {
+            Serializer::Serializer0 => (flags.rxrdy0().bit_is_set(), flags.rxor0().bit_is_set()),
+            Serializer::Serializer1 => (flags.rxrdy1().bit_is_set(), flags.rxor1().bit_is_set()),
+        };
+        if overrun {
+            self.i2s.intflag.write(|w| match serializer {
+                Serializer::Serializer0 => w.rxor0().set_bit(),
+                Serializer::Serializer1 => w.rxor1().set_bit(),
+            });
+            return Err(nb::Error::Other(Error::Overrun));
+        }
+        if !ready {
+            return Err(nb::Error::WouldBlock);
+        }
+        Ok(self.i2s.data[serializer.index()].read().data().bits())
+    }
+
+    /// Streams `buffer` to a transmitting serializer with `channel`,
+    /// one word each time the serializer is ready for more.  With
+    /// `circular` set the buffer is sent over and over until the
+    /// channel is stopped, which allows it to be refilled as a ring
+    /// buffer while it plays.
+    ///
+    /// This is unsafe because the DMA controller reads the buffer after
+    /// this returns: it must stay valid until the transfer is complete
+    /// or the channel is stopped.
+    ///
+    /// Panics if `buffer` is longer than 65535 words, the most that a
+    /// single DMA transfer can move.
+    pub unsafe fn transmit_dma(
+        &mut self,
+        serializer: Serializer,
+        channel: &mut dma::Channel,
+        buffer: &[u32],
+        circular: bool,
+    ) {
+        assert!(buffer.len() <= usize::from(u16::max_value()));
+        let transfer = Transfer {
+            source: buffer.as_ptr() as u32,
+            source_increment: true,
+            destination: self.data_address(serializer),
+            destination_increment: false,
+            beat_size: BeatSize::Word,
+            count: buffer.len() as u16,
+        };
+        let trigger = match serializer {
+            Serializer::Serializer0 => TriggerSource::I2sTx0,
+            Serializer::Serializer1 => TriggerSource::I2sTx1,
+        };
+        if circular {
+            channel.start_circular(transfer, trigger, TriggerAction::Beat);
+        } else {
+            channel.start(transfer, trigger, TriggerAction::Beat);
+        }
+    }
+
+    /// Streams words from a receiving serializer into `buffer` with
+    /// `channel`.  With `circular` set the buffer is filled over and
+    /// over until the channel is stopped; `dma::Channel::remaining`
+    /// tells which part of it has been filled.
+    ///
+    /// This is unsafe because the DMA controller writes the buffer after
+    /// this returns: it must stay valid, and must not be accessed
+    /// except to read the part that has been filled, until the transfer
+    /// is complete or the channel is stopped.
+    ///
+    /// Panics if `buffer` is longer than 65535 words, the most that a
+    /// single DMA transfer can move.
+    pub unsafe fn receive_dma(
+        &mut self,
+        serializer: Serializer,
+        channel: &mut dma::Channel,
+        buffer: &mut [u32],
+        circular: bool,
+    ) {
+        assert!(buffer.len() <= usize::from(u16::max_value()));
+        let transfer = Transfer {
+            source: self.data_address(serializer),
+            source_increment: false,
+            destination: buffer.as_mut_ptr() as u32,
+            destination_increment: true,
+            beat_size: BeatSize::Word,
+            count: buffer.len() as u16,
+        };
+        let trigger = match serializer {
+            Serializer::Serializer0 => TriggerSource::I2sRx0,
+            Serializer::Serializer1 => TriggerSource::I2sRx1,
+        };
+        if circular {
+            channel.start_circular(transfer, trigger, TriggerAction::Beat);
+        } else {
+            channel.start(transfer, trigger, TriggerAction::Beat);
+        }
+    }
+
+    fn data_address(&self, serializer: Serializer) -> u32 {
+        &self.i2s.data[serializer.index()] as *const _ as u32
+    }
+
+    /// Stops the controller and releases the peripheral and the pins
+    pub fn free(mut self) -> (I2sPins, I2S) {
+        self.disable();
+        (self.pins, self.i2s)
+    }
+}
+
+/// Returns the value of a clock divider field, which divides by one
+/// more than its value, for the nearest frequency to `output`
+fn divider(input: u32, output: u32) -> Result<u8, Error> {
+    if output == 0 {
+        return Err(Error::ClockDivider);
+    }
+    let div = (input + output / 2) / output;
+    if div == 0 || div > 32 {
+        return Err(Error::ClockDivider);
+    }
+    Ok((div - 1) as u8)
+}
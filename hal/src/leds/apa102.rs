@@ -0,0 +1,66 @@
+//! APA102 (DotStar) LEDs driven from an SPI master.
+//!
+//! The APA102 has separate clock and data lines, so unlike the WS2812
+//! it has no timing requirements and can be driven at any SPI speed
+//! up to a few Mhz.  The `dotstar_spi` helpers of the boards that have
+//! one on-board set up a suitable SPI master.
+use hal::blocking::spi::Write;
+
+use super::{SmartLedsWrite, RGB8};
+
+/// The largest value of the 5 bit global brightness
+pub const MAX_BRIGHTNESS: u8 = 31;
+
+/// A chain of APA102 LEDs connected to an SPI master that is
+/// configured for SPI Mode 0
+pub struct Apa102<SPI> {
+    spi: SPI,
+    brightness: u8,
+}
+
+impl<SPI: Write<u8>> Apa102<SPI> {
+    /// Creates the driver with full brightness
+    pub fn new(spi: SPI) -> Self {
+        Self {
+            spi,
+            brightness: MAX_BRIGHTNESS,
+        }
+    }
+
+    /// Sets the brightness applied to every LED by the next write, from
+    /// 0 to `MAX_BRIGHTNESS`.  The LEDs apply it by pulsing their
+    /// output, so it dims without losing color resolution.
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.brightness = brightness.min(MAX_BRIGHTNESS);
+    }
+
+    /// Releases the SPI master
+    pub fn free(self) -> SPI {
+        self.spi
+    }
+}
+
+impl<SPI: Write<u8>> SmartLedsWrite for Apa102<SPI> {
+    type Error = SPI::Error;
+    type Color = RGB8;
+
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), SPI::Error>
+    where
+        T: Iterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        self.spi.write(&[0; 4])?;
+        let mut count = 0;
+        for color in iterator {
+            let color = color.into();
+            self.spi.write(&[0xe0 | self.brightness, color.b, color.g, color.r])?;
+            count += 1;
+        }
+        // Each LED delays the data by half a clock, so the end frame
+        // needs a clock edge for every two LEDs in the chain
+        for _ in 0..(count + 15) / 16 + 1 {
+            self.spi.write(&[0xff])?;
+        }
+        Ok(())
+    }
+}
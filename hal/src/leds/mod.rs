@@ -0,0 +1,40 @@
+//! Drivers for addressable RGB LEDs, such as the NeoPixels and DotStars
+//! fitted to many samd21 boards.
+//!
+//! The drivers implement `SmartLedsWrite`, which follows the trait of
+//! the `smart-leds` crate: the colors for the whole chain are passed as
+//! an iterator, starting with the LED nearest the microcontroller.
+pub mod apa102;
+pub mod ws2812;
+
+/// The color of one LED, with 8 bits per channel
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RGB8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl RGB8 {
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl From<(u8, u8, u8)> for RGB8 {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self { r, g, b }
+    }
+}
+
+/// A chain of LEDs that is updated all at once
+pub trait SmartLedsWrite {
+    type Error;
+    type Color;
+
+    /// Sends the colors to the chain and latches them
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), Self::Error>
+    where
+        T: Iterator<Item = I>,
+        I: Into<Self::Color>;
+}
@@ -0,0 +1,262 @@
+//! WS2812 (NeoPixel) LEDs.
+//!
+//! The WS2812 has a single data line on which each bit is a high pulse
+//! whose length selects the value, so it cannot be bit-banged reliably
+//! with interrupts enabled.  Two drivers generate the waveform in
+//! hardware instead.
+//!
+//! `Ws2812` encodes each data bit as four SPI bits at 3Mhz: `1000` for
+//! a 0, a 333ns pulse, and `1100` for a 1, a 667ns pulse.  Every SPI
+//! byte ends with a low bit, so any gap between bytes only stretches
+//! the low time, which the LEDs tolerate.  Only the MOSI pin is needed;
+//! see the `DopoNMosi` SPI pinouts.
+//!
+//! `Ws2812Tcc` runs a TCC in normal PWM mode with a 1.25us period, and a
+//! DMA channel loads the compare value for the next bit on every
+//! overflow.  It works on any pin with a TCC output, at the cost of a
+//! DMA channel and a buffer of one halfword per bit.
+use core::ops::Deref;
+
+use hal::blocking::spi::Write;
+
+use super::{SmartLedsWrite, RGB8};
+use clock;
+use dma::{self, BeatSize, Channel, Transfer, TriggerAction, TriggerSource};
+use target_device::tcc0::RegisterBlock as TccRegisters;
+use target_device::{PM, TCC0, TCC1, TCC2};
+use time::Hertz;
+
+/// The SPI clock that the encoding is designed for
+pub const SPI_FREQUENCY: Hertz = Hertz(3_000_000);
+
+/// The LEDs latch the data after the line has been low for 50us, or
+/// 280us for recent WS2812B parts; 112 zero bytes at 3Mhz is 300us
+const RESET_BYTES: usize = 112;
+
+/// The same 300us in 1.25us bit periods, plus the two periods that the
+/// last compare value takes to pass through the buffer register
+const RESET_PERIODS: u32 = 242;
+
+/// The number of bits sent to each LED
+pub const BITS_PER_LED: usize = 24;
+
+/// Returns the green, red and blue channels in the order that the
+/// LEDs expect
+fn grb(color: RGB8) -> [u8; 3] {
+    [color.g, color.r, color.b]
+}
+
+/// A chain of WS2812 LEDs connected to the MOSI pin of an SPI master,
+/// which must be configured for `SPI_FREQUENCY` in SPI Mode 0
+pub struct Ws2812<SPI> {
+    spi: SPI,
+}
+
+impl<SPI: Write<u8>> Ws2812<SPI> {
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+
+    /// Releases the SPI master
+    pub fn free(self) -> SPI {
+        self.spi
+    }
+}
+
+impl<SPI: Write<u8>> SmartLedsWrite for Ws2812<SPI> {
+    type Error = SPI::Error;
+    type Color = RGB8;
+
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), SPI::Error>
+    where
+        T: Iterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        for color in iterator {
+            let mut buf = [0u8; 12];
+            for (byte, out) in grb(color.into()).iter().zip(buf.chunks_mut(4)) {
+                encode(*byte, out);
+            }
+            self.spi.write(&buf)?;
+        }
+        self.spi.write(&[0; RESET_BYTES])
+    }
+}
+
+/// Encodes the bits of `byte`, MSB first, two to each output byte
+fn encode(byte: u8, out: &mut [u8]) {
+    for (i, out) in out.iter_mut().enumerate() {
+        let bits = byte >> (6 - 2 * i);
+        *out = symbol(bits & 2 != 0) << 4 | symbol(bits & 1 != 0);
+    }
+}
+
+fn symbol(bit: bool) -> u8 {
+    if bit {
+        0b1100
+    } else {
+        0b1000
+    }
+}
+
+/// Errors reported by `Ws2812Tcc`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TccError {
+    /// The buffer has room for fewer LEDs than were written
+    BufferTooSmall,
+    Dma(dma::Error),
+}
+
+/// A chain of WS2812 LEDs connected to a TCC output.
+/// The pin must be configured for the TCC function, with
+/// `into_tcc_wo` or `into_tcc_alt_wo`, and `cc` is the compare channel
+/// that drives it: WO[n] is driven by channel n modulo the number of
+/// channels of the TCC.  The buffer needs `BITS_PER_LED` halfwords for
+/// each LED, plus one.
+pub struct Ws2812Tcc<'a, TCC, PIN> {
+    tcc: TCC,
+    pin: PIN,
+    channel: Channel,
+    trigger: TriggerSource,
+    cc: usize,
+    buffer: &'a mut [u16],
+    t0: u16,
+    t1: u16,
+}
+
+macro_rules! ws2812_tcc {
+    ($($TCC:ident: ($pm:ident, $clock:ident, $trigger:ident, $channels:expr),)+) => {
+$(
+impl<'a, PIN> Ws2812Tcc<'a, $TCC, PIN> {
+    /// Powers up the TCC and starts it generating the bit period, with
+    /// the output held low.  The clock should run at 48Mhz; the
+    /// timing is derived from its frequency and is only accurate to
+    /// one clock cycle.
+    pub fn new(
+        clock: &clock::$clock,
+        tcc: $TCC,
+        pm: &mut PM,
+        pin: PIN,
+        cc: u8,
+        channel: Channel,
+        buffer: &'a mut [u16],
+    ) -> Self {
+        assert!((cc as usize) < $channels);
+        pm.apbcmask.modify(|_, w| w.$pm().set_bit());
+
+        let freq = clock.freq().0;
+        let period = freq / 800_000;
+        start(&tcc, cc as usize, period);
+
+        Self {
+            tcc,
+            pin,
+            channel,
+            trigger: TriggerSource::$trigger,
+            cc: cc as usize,
+            buffer,
+            t0: (freq / 2_500_000) as u16,
+            t1: (freq / 1_250_000) as u16,
+        }
+    }
+}
+)+
+    };
+}
+
+ws2812_tcc! {
+    TCC0: (tcc0_, Tcc0Tcc1Clock, Tcc0Ovf, 4),
+    TCC1: (tcc1_, Tcc0Tcc1Clock, Tcc1Ovf, 2),
+    TCC2: (tcc2_, Tcc2Tc3Clock, Tcc2Ovf, 2),
+}
+
+/// Resets the TCC and runs it in normal PWM mode with `period` clocks
+/// per cycle and a duty cycle of zero on `cc`
+fn start(tcc: &TccRegisters, cc: usize, period: u32) {
+    tcc.ctrla.write(|w| w.swrst().set_bit());
+    while tcc.syncbusy.read().swrst().bit_is_set() {}
+
+    tcc.wave.write(|w| w.wavegen().npwm());
+    unsafe {
+        tcc.per.per.write(|w| w.per().bits(period - 1));
+        tcc.cc.cc[cc].write(|w| w.cc().bits(0));
+    }
+    tcc.ctrla.write(|w| {
+        w.prescaler().div1();
+        w.enable().set_bit()
+    });
+    while tcc.syncbusy.read().enable().bit_is_set() {}
+}
+
+impl<'a, TCC, PIN> Ws2812Tcc<'a, TCC, PIN>
+where
+    TCC: Deref<Target = TccRegisters>,
+{
+    /// Stops the TCC and releases the resources
+    pub fn free(self) -> (TCC, PIN, Channel) {
+        self.tcc.ctrla.write(|w| w.enable().clear_bit());
+        while self.tcc.syncbusy.read().enable().bit_is_set() {}
+        (self.tcc, self.pin, self.channel)
+    }
+}
+
+impl<'a, TCC, PIN> SmartLedsWrite for Ws2812Tcc<'a, TCC, PIN>
+where
+    TCC: Deref<Target = TccRegisters>,
+{
+    type Error = TccError;
+    type Color = RGB8;
+
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), TccError>
+    where
+        T: Iterator<Item = I>,
+        I: Into<RGB8>,
+    {
+        let mut len = 0;
+        for color in iterator {
+            if len + BITS_PER_LED >= self.buffer.len() {
+                return Err(TccError::BufferTooSmall);
+            }
+            for &byte in grb(color.into()).iter() {
+                for bit in (0..8).rev() {
+                    self.buffer[len] = if byte & (1 << bit) != 0 {
+                        self.t1
+                    } else {
+                        self.t0
+                    };
+                    len += 1;
+                }
+            }
+        }
+        // Hold the line low once the last bit has been sent
+        if len == self.buffer.len() {
+            return Err(TccError::BufferTooSmall);
+        }
+        self.buffer[len] = 0;
+        len += 1;
+
+        // Each overflow loads the next value into the buffer register,
+        // which is copied to the compare register at the next overflow
+        let ccb = unsafe { &self.tcc.ccb.ccb[self.cc] as *const _ as u32 };
+        let transfer = Transfer {
+            source: self.buffer.as_ptr() as u32,
+            source_increment: true,
+            destination: ccb,
+            destination_increment: false,
+            beat_size: BeatSize::HalfWord,
+            count: len as u16,
+        };
+        unsafe {
+            self.channel.start(transfer, self.trigger, TriggerAction::Beat);
+        }
+        let result = self.channel.wait();
+        self.channel.stop();
+        result.map_err(TccError::Dma)?;
+
+        for _ in 0..RESET_PERIODS {
+            self.tcc.intflag.write(|w| w.ovf().set_bit());
+            while self.tcc.intflag.read().ovf().bit_is_clear() {}
+        }
+        Ok(())
+    }
+}
@@ -1,27 +1,85 @@
 #![no_std]
 
-#[cfg(feature = "samd21g18a")]
-pub extern crate atsamd21g18a;
-#[cfg(feature = "samd21g18a")]
-pub use atsamd21g18a as target_device;
+#[cfg(feature = "samd21e15a")]
+pub extern crate atsamd21e15a;
+#[cfg(feature = "samd21e15a")]
+pub use atsamd21e15a as target_device;
+
+#[cfg(feature = "samd21e16a")]
+pub extern crate atsamd21e16a;
+#[cfg(feature = "samd21e16a")]
+pub use atsamd21e16a as target_device;
+
+#[cfg(feature = "samd21e17a")]
+pub extern crate atsamd21e17a;
+#[cfg(feature = "samd21e17a")]
+pub use atsamd21e17a as target_device;
 
 #[cfg(feature = "samd21e18a")]
 pub extern crate atsamd21e18a;
 #[cfg(feature = "samd21e18a")]
 pub use atsamd21e18a as target_device;
 
+#[cfg(feature = "samd21g15a")]
+pub extern crate atsamd21g15a;
+#[cfg(feature = "samd21g15a")]
+pub use atsamd21g15a as target_device;
+
+#[cfg(feature = "samd21g16a")]
+pub extern crate atsamd21g16a;
+#[cfg(feature = "samd21g16a")]
+pub use atsamd21g16a as target_device;
+
+#[cfg(feature = "samd21g17a")]
+pub extern crate atsamd21g17a;
+#[cfg(feature = "samd21g17a")]
+pub use atsamd21g17a as target_device;
+
+#[cfg(feature = "samd21g18a")]
+pub extern crate atsamd21g18a;
+#[cfg(feature = "samd21g18a")]
+pub use atsamd21g18a as target_device;
+
+#[cfg(feature = "samd21j15a")]
+pub extern crate atsamd21j15a;
+#[cfg(feature = "samd21j15a")]
+pub use atsamd21j15a as target_device;
+
+#[cfg(feature = "samd21j16a")]
+pub extern crate atsamd21j16a;
+#[cfg(feature = "samd21j16a")]
+pub use atsamd21j16a as target_device;
+
+#[cfg(feature = "samd21j17a")]
+pub extern crate atsamd21j17a;
+#[cfg(feature = "samd21j17a")]
+pub use atsamd21j17a as target_device;
+
+#[cfg(feature = "samd21j18a")]
+pub extern crate atsamd21j18a;
+#[cfg(feature = "samd21j18a")]
+pub use atsamd21j18a as target_device;
+
 extern crate cortex_m;
 pub extern crate embedded_hal as hal;
 extern crate nb;
 extern crate void;
 
 pub mod adc;
-mod calibration;
+pub mod calibration;
 pub mod clock;
 pub mod delay;
+pub mod dma;
 pub mod gpio;
+pub mod i2s;
+pub mod leds;
+pub mod nvm;
+pub mod power;
 pub mod prelude;
 pub mod sercom;
+pub mod spi_flash;
+pub mod supply;
 pub mod time;
 pub mod timer;
 pub mod usb;
+pub mod watchdog;
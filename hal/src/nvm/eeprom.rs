@@ -0,0 +1,758 @@
+//! EEPROM emulation in flash.
+//!
+//! `Eeprom` presents a small byte addressable memory that is stored in
+//! a flash area, normally the area reserved by the EEPROM fuse.  The
+//! memory is divided into logical pages, each of which occupies one
+//! physical flash page together with a header holding the logical page
+//! number, a sequence number and a checksum.  Updating a logical page
+//! writes a new copy of it to the next free physical page, so the rows
+//! of the area are used in turn and wear evenly; the copy with the
+//! highest sequence number is the current one.
+//!
+//! One row ahead of the row being written is always kept erased.  When
+//! the current row fills up, writing moves on to that spare row and the
+//! row after it is reclaimed: the pages in it that are still current are
+//! copied forward and then it is erased to become the new spare.  If
+//! all four pages of the reclaimed row were current, the new row is full
+//! as soon as it has been reclaimed, and writing moves on again; there
+//! are always fewer current pages than data rows can hold, so some row
+//! has room.  A copy is never made current until it has been completely written, and
+//! the checksum identifies pages whose programming was interrupted, so
+//! the contents survive a power failure at any point; `Eeprom::new`
+//! finishes an interrupted reclaim.
+//!
+//! Two rows of the area hold no data, so the capacity is the size of the
+//! area less two rows, less the page headers.  The emulation logic only
+//! uses the `Flash` trait, which `NvmArea` implements for the on-chip
+//! flash.
+use core::cmp;
+
+use super::{Error as NvmError, Nvm};
+
+/// The largest supported flash page size
+const MAX_PAGE_SIZE: usize = 64;
+
+/// The largest supported number of pages in the area, which is the
+/// 16K maximum that the EEPROM fuse can reserve
+const MAX_PAGES: usize = 256;
+
+const PAGES_PER_ROW: usize = 4;
+
+/// The page header holds the logical page number, a checksum over the
+/// rest of the page and the sequence number
+const HEADER_SIZE: usize = 8;
+
+/// The map entry for a logical page that has never been written
+const UNMAPPED: u16 = 0xffff;
+
+/// The flash operations needed by `Eeprom`.  Offsets are relative to
+/// the start of the area; erased flash reads as 0xff.
+pub trait Flash {
+    type Error;
+
+    /// The size of the area in bytes, which is a whole number of rows
+    fn size(&self) -> usize;
+
+    /// The size of a page in bytes; a row is four pages
+    fn page_size(&self) -> usize;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Erases the row at `offset`, which is row aligned
+    fn erase_row(&mut self, offset: usize) -> Result<(), Self::Error>;
+
+    /// Programs a whole page at `offset`, which is page aligned
+    fn write_page(&mut self, offset: usize, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error<E> {
+    /// The flash reported an error
+    Flash(E),
+    /// The access extends beyond the capacity of the EEPROM
+    OutOfRange,
+    /// The area is smaller than three rows, or larger than 16K, or its
+    /// page size is not supported
+    InvalidArea,
+}
+
+/// The contents of a physical page
+enum PageState {
+    Blank,
+    /// Programmed, but the checksum does not match
+    Invalid,
+    Valid { logical: u16, seq: u32 },
+}
+
+/// An emulated EEPROM
+pub struct Eeprom<F> {
+    flash: F,
+    page_size: usize,
+    rows: usize,
+    logical_pages: usize,
+    /// The physical page holding the current copy of each logical page
+    map: [u16; MAX_PAGES],
+    /// The row being written and the next free page in it
+    head_row: usize,
+    head_pos: usize,
+    /// The sequence number of the most recently written page
+    seq: u32,
+}
+
+impl<F: Flash> Eeprom<F> {
+    /// Scans the area and recovers from any interrupted operation.
+    /// An area that holds no valid pages is erased.
+    pub fn new(flash: F) -> Result<Self, Error<F::Error>> {
+        let page_size = flash.page_size();
+        let pages = flash.size() / page_size;
+        let rows = pages / PAGES_PER_ROW;
+        if page_size > MAX_PAGE_SIZE || page_size <= HEADER_SIZE || pages > MAX_PAGES || rows < 3 {
+            return Err(Error::InvalidArea);
+        }
+
+        let mut eeprom = Self {
+            flash,
+            page_size,
+            rows,
+            logical_pages: (rows - 2) * PAGES_PER_ROW,
+            map: [UNMAPPED; MAX_PAGES],
+            head_row: 0,
+            head_pos: 0,
+            seq: 0,
+        };
+        eeprom.mount()?;
+        Ok(eeprom)
+    }
+
+    /// Releases the flash area
+    pub fn free(self) -> F {
+        self.flash
+    }
+
+    /// The number of bytes that can be stored
+    pub fn capacity(&self) -> usize {
+        self.logical_pages * self.data_size()
+    }
+
+    /// Reads from the EEPROM.  Bytes that have never been written
+    /// read as 0xff.
+    pub fn read(&mut self, addr: usize, buf: &mut [u8]) -> Result<(), Error<F::Error>> {
+        self.check_range(addr, buf.len())?;
+        let data_size = self.data_size();
+        let mut addr = addr;
+        let mut buf = buf;
+        while !buf.is_empty() {
+            let offset = addr % data_size;
+            let len = cmp::min(data_size - offset, buf.len());
+            let (head, tail) = { buf }.split_at_mut(len);
+            self.read_logical(addr / data_size, offset, head)?;
+            addr += len;
+            buf = tail;
+        }
+        Ok(())
+    }
+
+    /// Writes to the EEPROM.  Only the logical pages whose contents
+    /// change are rewritten.
+    pub fn write(&mut self, addr: usize, data: &[u8]) -> Result<(), Error<F::Error>> {
+        self.check_range(addr, data.len())?;
+        let data_size = self.data_size();
+        let mut addr = addr;
+        let mut data = data;
+        while !data.is_empty() {
+            let logical = addr / data_size;
+            let offset = addr % data_size;
+            let len = cmp::min(data_size - offset, data.len());
+            let (head, tail) = data.split_at(len);
+
+            let mut page = [0xff; MAX_PAGE_SIZE];
+            self.read_logical(logical, 0, &mut page[..data_size])?;
+            if &page[offset..offset + len] != head {
+                page[offset..offset + len].copy_from_slice(head);
+                while self.head_pos == PAGES_PER_ROW {
+                    self.advance()?;
+                }
+                self.program(logical as u16, &page[..data_size])?;
+            }
+            addr += len;
+            data = tail;
+        }
+        Ok(())
+    }
+
+    fn data_size(&self) -> usize {
+        self.page_size - HEADER_SIZE
+    }
+
+    fn pages(&self) -> usize {
+        self.rows * PAGES_PER_ROW
+    }
+
+    fn next_row(&self, row: usize) -> usize {
+        (row + 1) % self.rows
+    }
+
+    fn check_range(&self, addr: usize, len: usize) -> Result<(), Error<F::Error>> {
+        match addr.checked_add(len) {
+            Some(end) if end <= self.capacity() => Ok(()),
+            _ => Err(Error::OutOfRange),
+        }
+    }
+
+    fn read_logical(
+        &mut self,
+        logical: usize,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> Result<(), Error<F::Error>> {
+        match self.map[logical] {
+            UNMAPPED => {
+                for byte in buf.iter_mut() {
+                    *byte = 0xff;
+                }
+                Ok(())
+            }
+            page => {
+                let addr = usize::from(page) * self.page_size + HEADER_SIZE + offset;
+                self.flash.read(addr, buf).map_err(Error::Flash)
+            }
+        }
+    }
+
+    fn page_state(
+        &mut self,
+        page: usize,
+        buf: &mut [u8; MAX_PAGE_SIZE],
+    ) -> Result<PageState, Error<F::Error>> {
+        let buf = &mut buf[..self.page_size];
+        self.flash
+            .read(page * self.page_size, buf)
+            .map_err(Error::Flash)?;
+        if buf.iter().all(|b| *b == 0xff) {
+            return Ok(PageState::Blank);
+        }
+        let logical = u16::from(buf[0]) | (u16::from(buf[1]) << 8);
+        let crc = u16::from(buf[2]) | (u16::from(buf[3]) << 8);
+        let seq = buf[4..8]
+            .iter()
+            .rev()
+            .fold(0, |seq, b| (seq << 8) | u32::from(*b));
+        if usize::from(logical) < self.logical_pages && crc == page_crc(buf) {
+            Ok(PageState::Valid { logical, seq })
+        } else {
+            Ok(PageState::Invalid)
+        }
+    }
+
+    fn row_is_blank(&mut self, row: usize) -> Result<bool, Error<F::Error>> {
+        let mut buf = [0; MAX_PAGE_SIZE];
+        for page in row * PAGES_PER_ROW..(row + 1) * PAGES_PER_ROW {
+            match self.page_state(page, &mut buf)? {
+                PageState::Blank => {}
+                _ => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns the logical page number if `page` holds a current copy
+    fn current_logical(&mut self, page: usize) -> Result<Option<u16>, Error<F::Error>> {
+        let mut buf = [0; MAX_PAGE_SIZE];
+        match self.page_state(page, &mut buf)? {
+            PageState::Valid { logical, .. } => {
+                let current = usize::from(self.map[usize::from(logical)]) == page;
+                Ok(if current { Some(logical) } else { None })
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Rebuilds the map and locates the head from the flash contents
+    fn mount(&mut self) -> Result<(), Error<F::Error>> {
+        let mut buf = [0; MAX_PAGE_SIZE];
+        loop {
+            self.map = [UNMAPPED; MAX_PAGES];
+            let mut seqs = [0; MAX_PAGES];
+            let mut last = None;
+            for page in 0..self.pages() {
+                if let PageState::Valid { logical, seq } = self.page_state(page, &mut buf)? {
+                    let logical = usize::from(logical);
+                    if self.map[logical] == UNMAPPED || newer(seq, seqs[logical]) {
+                        self.map[logical] = page as u16;
+                        seqs[logical] = seq;
+                    }
+                    if last.map(|(_, last_seq)| newer(seq, last_seq)).unwrap_or(true) {
+                        last = Some((page, seq));
+                    }
+                }
+            }
+
+            let (page, seq) = match last {
+                Some(last) => last,
+                None => return self.format(),
+            };
+            self.seq = seq;
+            self.head_row = page / PAGES_PER_ROW;
+            self.head_pos = page % PAGES_PER_ROW + 1;
+            // skip over any pages whose programming was interrupted
+            while self.head_pos < PAGES_PER_ROW {
+                let next = self.head_row * PAGES_PER_ROW + self.head_pos;
+                match self.page_state(next, &mut buf)? {
+                    PageState::Blank => break,
+                    _ => self.head_pos += 1,
+                }
+            }
+
+            let spare = self.next_row(self.head_row);
+            if self.row_is_blank(spare)? {
+                return Ok(());
+            }
+            // A reclaim of the spare row was interrupted
+            let mut live = 0;
+            for page in spare * PAGES_PER_ROW..(spare + 1) * PAGES_PER_ROW {
+                if self.current_logical(page)?.is_some() {
+                    live += 1;
+                }
+            }
+            if live <= PAGES_PER_ROW - self.head_pos {
+                return self.reclaim(spare);
+            }
+            // The head row was only receiving copies of the pages in the
+            // spare row when the reclaim was interrupted, so it can be
+            // erased and the reclaim started over
+            let offset = self.head_row * PAGES_PER_ROW * self.page_size;
+            self.flash.erase_row(offset).map_err(Error::Flash)?;
+        }
+    }
+
+    /// Erases the whole area
+    fn format(&mut self) -> Result<(), Error<F::Error>> {
+        for row in 0..self.rows {
+            if !self.row_is_blank(row)? {
+                let offset = row * PAGES_PER_ROW * self.page_size;
+                self.flash.erase_row(offset).map_err(Error::Flash)?;
+            }
+        }
+        self.map = [UNMAPPED; MAX_PAGES];
+        self.head_row = 0;
+        self.head_pos = 0;
+        self.seq = 0;
+        Ok(())
+    }
+
+    /// Moves on to the spare row and reclaims the row after it
+    fn advance(&mut self) -> Result<(), Error<F::Error>> {
+        self.head_row = self.next_row(self.head_row);
+        self.head_pos = 0;
+        let spare = self.next_row(self.head_row);
+        if self.row_is_blank(spare)? {
+            Ok(())
+        } else {
+            self.reclaim(spare)
+        }
+    }
+
+    /// Copies the current pages of `row` to the head, then erases it
+    fn reclaim(&mut self, row: usize) -> Result<(), Error<F::Error>> {
+        let data_size = self.data_size();
+        for page in row * PAGES_PER_ROW..(row + 1) * PAGES_PER_ROW {
+            if let Some(logical) = self.current_logical(page)? {
+                let mut data = [0; MAX_PAGE_SIZE];
+                self.read_logical(usize::from(logical), 0, &mut data[..data_size])?;
+                self.program(logical, &data[..data_size])?;
+            }
+        }
+        let offset = row * PAGES_PER_ROW * self.page_size;
+        self.flash.erase_row(offset).map_err(Error::Flash)
+    }
+
+    /// Writes a new copy of a logical page at the head.  The caller
+    /// ensures that the head row has a free page.
+    fn program(&mut self, logical: u16, data: &[u8]) -> Result<(), Error<F::Error>> {
+        assert!(self.head_pos < PAGES_PER_ROW);
+        let mut buf = [0xff; MAX_PAGE_SIZE];
+        let page = &mut buf[..self.page_size];
+        let seq = self.seq.wrapping_add(1);
+        page[0] = logical as u8;
+        page[1] = (logical >> 8) as u8;
+        for (i, byte) in page[4..8].iter_mut().enumerate() {
+            *byte = (seq >> (8 * i)) as u8;
+        }
+        page[HEADER_SIZE..].copy_from_slice(data);
+        let crc = page_crc(page);
+        page[2] = crc as u8;
+        page[3] = (crc >> 8) as u8;
+
+        // The page is consumed even if programming fails part way
+        let physical = self.head_row * PAGES_PER_ROW + self.head_pos;
+        self.head_pos += 1;
+        self.seq = seq;
+        self.flash
+            .write_page(physical * self.page_size, page)
+            .map_err(Error::Flash)?;
+        self.map[usize::from(logical)] = physical as u16;
+        Ok(())
+    }
+}
+
+/// Returns true if sequence number `a` was written after `b`.  The
+/// numbers wrap around, but the current pages are copied forward at
+/// least once per pass over the area, so the pages in it never span
+/// more than half of the range.
+fn newer(a: u32, b: u32) -> bool {
+    (a.wrapping_sub(b) as i32) > 0
+}
+
+/// The CRC-16/CCITT of a page, excluding the checksum field itself
+fn page_crc(page: &[u8]) -> u16 {
+    page[..2]
+        .iter()
+        .chain(page[4..].iter())
+        .fold(0xffff, |crc, byte| {
+            let mut crc = crc ^ (u16::from(*byte) << 8);
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+            crc
+        })
+}
+
+/// The flash area reserved by the EEPROM fuse, at the end of the
+/// main flash array
+pub struct NvmArea {
+    nvm: Nvm,
+    start: u32,
+    size: u32,
+}
+
+impl NvmArea {
+    pub fn new(nvm: Nvm) -> Self {
+        let size = nvm.eeprom_size();
+        let start = nvm.geometry().flash_size() - size;
+        Self { nvm, start, size }
+    }
+
+    /// Releases the NVM controller
+    pub fn free(self) -> Nvm {
+        self.nvm
+    }
+}
+
+impl Flash for NvmArea {
+    type Error = NvmError;
+
+    fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    fn page_size(&self) -> usize {
+        self.nvm.geometry().page_size as usize
+    }
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), NvmError> {
+        self.nvm.read(self.start + offset as u32, buf)
+    }
+
+    fn erase_row(&mut self, offset: usize) -> Result<(), NvmError> {
+        self.nvm.erase_row(self.start + offset as u32)
+    }
+
+    fn write_page(&mut self, offset: usize, data: &[u8]) -> Result<(), NvmError> {
+        let mut words = [0; MAX_PAGE_SIZE / 4];
+        for (word, bytes) in words.iter_mut().zip(data.chunks(4)) {
+            *word = bytes
+                .iter()
+                .rev()
+                .fold(0, |word, b| (word << 8) | u32::from(*b));
+        }
+        let len = (data.len() + 3) / 4;
+        self.nvm.write(self.start + offset as u32, &words[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_SIZE: usize = 64;
+    const ROW_SIZE: usize = PAGES_PER_ROW * PAGE_SIZE;
+    const ROWS: usize = 4;
+    const SIZE: usize = ROWS * ROW_SIZE;
+    const DATA_SIZE: usize = PAGE_SIZE - HEADER_SIZE;
+    const LOGICAL_PAGES: usize = (ROWS - 2) * PAGES_PER_ROW;
+
+    #[derive(Debug, PartialEq)]
+    struct PowerFail;
+
+    /// An in-memory flash area.  Programming can only clear bits, and
+    /// the power can be made to fail after a number of operations.
+    struct MemFlash {
+        data: [u8; SIZE],
+        /// The number of erases and writes left before the power fails
+        budget: Option<usize>,
+    }
+
+    impl MemFlash {
+        fn new() -> Self {
+            Self {
+                data: [0xff; SIZE],
+                budget: None,
+            }
+        }
+
+        fn powered(&mut self) -> bool {
+            match self.budget {
+                Some(0) => false,
+                Some(ref mut n) => {
+                    *n -= 1;
+                    true
+                }
+                None => true,
+            }
+        }
+    }
+
+    impl Flash for MemFlash {
+        type Error = PowerFail;
+
+        fn size(&self) -> usize {
+            SIZE
+        }
+
+        fn page_size(&self) -> usize {
+            PAGE_SIZE
+        }
+
+        fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), PowerFail> {
+            buf.copy_from_slice(&self.data[offset..offset + buf.len()]);
+            Ok(())
+        }
+
+        fn erase_row(&mut self, offset: usize) -> Result<(), PowerFail> {
+            assert_eq!(offset % ROW_SIZE, 0);
+            assert!(offset < SIZE);
+            if !self.powered() {
+                return Err(PowerFail);
+            }
+            for byte in self.data[offset..offset + ROW_SIZE].iter_mut() {
+                *byte = 0xff;
+            }
+            Ok(())
+        }
+
+        fn write_page(&mut self, offset: usize, data: &[u8]) -> Result<(), PowerFail> {
+            assert_eq!(offset % PAGE_SIZE, 0);
+            assert!(offset < SIZE);
+            assert_eq!(data.len(), PAGE_SIZE);
+            // A write cut off by the power failure programs half the page
+            let powered = self.powered();
+            let len = if powered { PAGE_SIZE } else { PAGE_SIZE / 2 };
+            for (byte, new) in self.data[offset..offset + len].iter_mut().zip(data) {
+                *byte &= *new;
+            }
+            if powered {
+                Ok(())
+            } else {
+                Err(PowerFail)
+            }
+        }
+    }
+
+    fn write(eeprom: &mut Eeprom<MemFlash>, logical: usize, value: u8) {
+        eeprom.write(logical * DATA_SIZE, &[value; DATA_SIZE]).unwrap();
+    }
+
+    /// Checks that each logical page is filled with its value in
+    /// `expected`
+    fn check(eeprom: &mut Eeprom<MemFlash>, expected: &[u8; LOGICAL_PAGES]) {
+        for (logical, value) in expected.iter().enumerate() {
+            let mut buf = [0; DATA_SIZE];
+            eeprom.read(logical * DATA_SIZE, &mut buf).unwrap();
+            assert!(buf.iter().all(|b| b == value), "logical page {}", logical);
+        }
+    }
+
+    fn remount(eeprom: Eeprom<MemFlash>) -> Eeprom<MemFlash> {
+        let mut flash = eeprom.free();
+        flash.budget = None;
+        Eeprom::new(flash).unwrap()
+    }
+
+    #[test]
+    fn capacity() {
+        let eeprom = Eeprom::new(MemFlash::new()).unwrap();
+        assert_eq!(eeprom.capacity(), LOGICAL_PAGES * DATA_SIZE);
+    }
+
+    #[test]
+    fn rewrite_wraps_around() {
+        let mut eeprom = Eeprom::new(MemFlash::new()).unwrap();
+        let mut expected = [0xff; LOGICAL_PAGES];
+        check(&mut eeprom, &expected);
+
+        for logical in 0..8 {
+            write(&mut eeprom, logical, logical as u8);
+            expected[logical] = logical as u8;
+        }
+        for logical in 4..8 {
+            write(&mut eeprom, logical, 0x10 + logical as u8);
+            expected[logical] = 0x10 + logical as u8;
+        }
+        // The head row is filled by the copies of the reclaimed row
+        write(&mut eeprom, 4, 0x20);
+        expected[4] = 0x20;
+        check(&mut eeprom, &expected);
+        eeprom = remount(eeprom);
+        check(&mut eeprom, &expected);
+
+        // Go round the area many times, rewriting some pages more often
+        for round in 0..100u8 {
+            for logical in 0..LOGICAL_PAGES {
+                if logical % 3 == 0 || round % 8 == 0 {
+                    write(&mut eeprom, logical, round);
+                    expected[logical] = round;
+                }
+            }
+            check(&mut eeprom, &expected);
+            if round % 10 == 0 {
+                eeprom = remount(eeprom);
+                check(&mut eeprom, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn unchanged_pages_are_not_rewritten() {
+        let mut eeprom = Eeprom::new(MemFlash::new()).unwrap();
+        write(&mut eeprom, 0, 1);
+        eeprom.flash.budget = Some(0);
+        write(&mut eeprom, 0, 1);
+        eeprom.write(10, &[1; 20]).unwrap();
+    }
+
+    #[test]
+    fn power_failure_before_reclaim_erase() {
+        let mut eeprom = Eeprom::new(MemFlash::new()).unwrap();
+        let mut expected = [0xff; LOGICAL_PAGES];
+        for logical in 0..8 {
+            write(&mut eeprom, logical, logical as u8);
+            expected[logical] = logical as u8;
+        }
+        for logical in 4..8 {
+            write(&mut eeprom, logical, 0x10 + logical as u8);
+            expected[logical] = 0x10 + logical as u8;
+        }
+
+        // The next write reclaims row 0, whose four pages are copied
+        // forward; the power fails before it is erased
+        eeprom.flash.budget = Some(PAGES_PER_ROW);
+        let result = eeprom.write(4 * DATA_SIZE, &[0x20; DATA_SIZE]);
+        assert_eq!(result, Err(Error::Flash(PowerFail)));
+
+        eeprom = remount(eeprom);
+        check(&mut eeprom, &expected);
+        write(&mut eeprom, 4, 0x20);
+        expected[4] = 0x20;
+        check(&mut eeprom, &expected);
+        eeprom = remount(eeprom);
+        check(&mut eeprom, &expected);
+    }
+
+    #[test]
+    fn power_failure_at_any_point() {
+        for budget in 0..64 {
+            let mut eeprom = Eeprom::new(MemFlash::new()).unwrap();
+            let mut expected = [0xff; LOGICAL_PAGES];
+            for logical in 0..LOGICAL_PAGES {
+                write(&mut eeprom, logical, logical as u8);
+                expected[logical] = logical as u8;
+            }
+
+            eeprom.flash.budget = Some(budget);
+            let mut interrupted = None;
+            'writes: for round in 1..4u8 {
+                for logical in 0..LOGICAL_PAGES {
+                    let value = round << 4 | logical as u8;
+                    if eeprom.write(logical * DATA_SIZE, &[value; DATA_SIZE]).is_err() {
+                        interrupted = Some((logical, value));
+                        break 'writes;
+                    }
+                    expected[logical] = value;
+                }
+            }
+
+            eeprom = remount(eeprom);
+            // The interrupted write either happened or it did not
+            if let Some((logical, value)) = interrupted {
+                let mut buf = [0; DATA_SIZE];
+                eeprom.read(logical * DATA_SIZE, &mut buf).unwrap();
+                if buf[0] == value {
+                    expected[logical] = value;
+                }
+            }
+            check(&mut eeprom, &expected);
+
+            for logical in 0..LOGICAL_PAGES {
+                write(&mut eeprom, logical, 0xa0 | logical as u8);
+                expected[logical] = 0xa0 | logical as u8;
+            }
+            check(&mut eeprom, &expected);
+            eeprom = remount(eeprom);
+            check(&mut eeprom, &expected);
+        }
+    }
+
+    #[test]
+    fn torn_page_is_rejected() {
+        let mut eeprom = Eeprom::new(MemFlash::new()).unwrap();
+        let mut expected = [0xff; LOGICAL_PAGES];
+        write(&mut eeprom, 0, 1);
+        write(&mut eeprom, 1, 2);
+        expected[0] = 1;
+        expected[1] = 2;
+
+        eeprom.flash.budget = Some(0);
+        let result = eeprom.write(0, &[3; DATA_SIZE]);
+        assert_eq!(result, Err(Error::Flash(PowerFail)));
+        // The header of the torn page was programmed, but not all of
+        // the data
+        assert!(eeprom.flash.data[2 * PAGE_SIZE..3 * PAGE_SIZE].iter().any(|b| *b != 0xff));
+
+        eeprom = remount(eeprom);
+        check(&mut eeprom, &expected);
+
+        // The torn page is skipped rather than programmed again
+        write(&mut eeprom, 0, 4);
+        expected[0] = 4;
+        check(&mut eeprom, &expected);
+        eeprom = remount(eeprom);
+        check(&mut eeprom, &expected);
+    }
+
+    #[test]
+    fn sequence_number_wrap() {
+        let mut eeprom = Eeprom::new(MemFlash::new()).unwrap();
+        let mut expected = [0xff; LOGICAL_PAGES];
+        eeprom.seq = u32::max_value() - 5;
+        for logical in 0..LOGICAL_PAGES {
+            write(&mut eeprom, logical, logical as u8);
+            expected[logical] = logical as u8;
+        }
+
+        // Rewrite a few of the pages, remounting after each write, so
+        // that copies from both sides of the wrap are in the area
+        for i in 0..40 {
+            let logical = i % 3;
+            let value = 0x80 | i as u8;
+            write(&mut eeprom, logical, value);
+            expected[logical] = value;
+            eeprom = remount(eeprom);
+            check(&mut eeprom, &expected);
+        }
+        assert!(eeprom.seq < 1000);
+    }
+}
@@ -0,0 +1,212 @@
+//! A typed view of the fuses in the user row.
+//!
+//! The first 64 bits of the user row are loaded into the peripherals at
+//! reset: the bootloader and EEPROM sizes into the NVM controller, the
+//! BOD33 settings into SYSCTRL and the watchdog defaults into the WDT.
+//! `UserFuses::read` decodes them so that an application can check the
+//! settings it depends on at boot.  Changes are made on a copy and
+//! programmed with `Nvm::write_user_row`; the reserved bits, which hold
+//! factory calibration, are carried over unchanged.
+// See 10.3.1 NVM User Row Mapping
+use core::ptr;
+
+use super::{NUM_LOCK_REGIONS, USER_ROW_ADDR};
+use supply::BodAction;
+
+/// The largest WDT period, window and early warning offset encoding
+const MAX_WDT_PERIOD: u8 = 11;
+
+/// Fuse values that the hardware does not define
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FuseError {
+    /// The BOD33 action is the reserved encoding
+    Bod33Action,
+    /// The WDT period is larger than 16K cycles
+    WdtPeriod,
+    /// The WDT window is larger than 16K cycles
+    WdtWindow,
+    /// The WDT early warning offset is larger than 16K cycles
+    WdtEarlyWarning,
+    /// The size passed to a setter cannot be encoded
+    InvalidSize,
+}
+
+/// The fuses at the start of the user row
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UserFuses([u32; 2]);
+
+impl UserFuses {
+    /// Reads the fuses from the user row
+    pub fn read() -> Self {
+        let row = USER_ROW_ADDR as *const u32;
+        unsafe { UserFuses([ptr::read_volatile(row), ptr::read_volatile(row.offset(1))]) }
+    }
+
+    /// Decodes fuses from the first two words of the user row, as
+    /// returned by `Nvm::read_user_row`
+    pub fn from_words(words: [u32; 2]) -> Self {
+        UserFuses(words)
+    }
+
+    /// Returns the words to program at the start of the user row
+    pub fn to_words(&self) -> [u32; 2] {
+        self.0
+    }
+
+    /// Returns the size in bytes of the bootloader section at the start
+    /// of flash that is protected by BOOTPROT
+    pub fn bootloader_size(&self) -> u32 {
+        decode_size(self.field(0, 3), 512)
+    }
+
+    /// Sets BOOTPROT to protect `size` bytes, which must be 0 or a
+    /// power of two from 512 to 32K
+    pub fn set_bootloader_size(&mut self, size: u32) -> Result<(), FuseError> {
+        let bits = encode_size(size, 512)?;
+        self.set_field(0, 3, bits);
+        Ok(())
+    }
+
+    /// Returns the size in bytes of the area at the end of flash that
+    /// is reserved for EEPROM emulation
+    pub fn eeprom_size(&self) -> u32 {
+        decode_size(self.field(4, 3), 256)
+    }
+
+    /// Sets the EEPROM fuse to reserve `size` bytes, which must be 0 or
+    /// a power of two from 256 to 16K
+    pub fn set_eeprom_size(&mut self, size: u32) -> Result<(), FuseError> {
+        let bits = encode_size(size, 256)?;
+        self.set_field(4, 3, bits);
+        Ok(())
+    }
+
+    /// The BOD33 threshold level loaded at reset
+    pub fn bod33_level(&self) -> u8 {
+        self.field(8, 6) as u8
+    }
+
+    /// Whether BOD33 is enabled at reset
+    pub fn bod33_enabled(&self) -> bool {
+        self.field(14, 1) != 0
+    }
+
+    /// The BOD33 action loaded at reset, or `None` for the
+    /// reserved encoding
+    pub fn bod33_action(&self) -> Option<BodAction> {
+        match self.field(15, 2) {
+            0 => Some(BodAction::None),
+            1 => Some(BodAction::Reset),
+            2 => Some(BodAction::Interrupt),
+            _ => None,
+        }
+    }
+
+    /// Whether BOD33 hysteresis is enabled at reset
+    pub fn bod33_hysteresis(&self) -> bool {
+        self.field(40, 1) != 0
+    }
+
+    /// Whether the watchdog is enabled at reset
+    pub fn wdt_enabled(&self) -> bool {
+        self.field(25, 1) != 0
+    }
+
+    /// Whether the watchdog is always-on from reset, in which case it
+    /// cannot be disabled
+    pub fn wdt_always_on(&self) -> bool {
+        self.field(26, 1) != 0
+    }
+
+    /// The watchdog period loaded at reset; the period is
+    /// `8 << n` watchdog clock cycles
+    pub fn wdt_period(&self) -> u8 {
+        self.field(27, 4) as u8
+    }
+
+    /// The closed window period loaded at reset, in the same encoding
+    /// as `wdt_period`
+    pub fn wdt_window(&self) -> u8 {
+        self.field(31, 4) as u8
+    }
+
+    /// The early warning interrupt offset loaded at reset, in the same
+    /// encoding as `wdt_period`
+    pub fn wdt_early_warning(&self) -> u8 {
+        self.field(35, 4) as u8
+    }
+
+    /// Whether the watchdog starts in window mode
+    pub fn wdt_window_mode(&self) -> bool {
+        self.field(39, 1) != 0
+    }
+
+    /// The lock bits of the regions, with region 0 in bit 0.  Unlike
+    /// the other fuses a set bit means unlocked; a region that is locked
+    /// here is locked again at every reset.
+    pub fn region_locks(&self) -> u16 {
+        self.field(48, 16) as u16
+    }
+
+    /// Returns true if the region is locked at reset
+    pub fn is_region_locked(&self, region: u8) -> bool {
+        region < NUM_LOCK_REGIONS && self.region_locks() & (1 << region) == 0
+    }
+
+    /// Sets whether the region is locked at reset
+    pub fn set_region_locked(&mut self, region: u8, locked: bool) {
+        if region < NUM_LOCK_REGIONS {
+            let bit = 48 + u32::from(region);
+            self.set_field(bit, 1, if locked { 0 } else { 1 });
+        }
+    }
+
+    /// Checks that every fuse holds a value defined by the hardware.
+    /// An erased user row fails this check.
+    pub fn validate(&self) -> Result<(), FuseError> {
+        if self.bod33_action().is_none() {
+            return Err(FuseError::Bod33Action);
+        }
+        if self.wdt_period() > MAX_WDT_PERIOD {
+            return Err(FuseError::WdtPeriod);
+        }
+        if self.wdt_window() > MAX_WDT_PERIOD {
+            return Err(FuseError::WdtWindow);
+        }
+        if self.wdt_early_warning() > MAX_WDT_PERIOD {
+            return Err(FuseError::WdtEarlyWarning);
+        }
+        Ok(())
+    }
+
+    /// Reads `width` bits starting at bit `offset` of the 64 bit fuses
+    fn field(&self, offset: u32, width: u32) -> u32 {
+        let value = (u64::from(self.0[1]) << 32) | u64::from(self.0[0]);
+        ((value >> offset) & ((1 << width) - 1)) as u32
+    }
+
+    fn set_field(&mut self, offset: u32, width: u32, bits: u32) {
+        let mut value = (u64::from(self.0[1]) << 32) | u64::from(self.0[0]);
+        let mask = ((1u64 << width) - 1) << offset;
+        value = (value & !mask) | ((u64::from(bits) << offset) & mask);
+        self.0 = [value as u32, (value >> 32) as u32];
+    }
+}
+
+/// BOOTPROT and EEPROM both encode `smallest << (6 - n)`, with 7 meaning
+/// that no space is reserved
+fn decode_size(bits: u32, smallest: u32) -> u32 {
+    match bits {
+        7 => 0,
+        n => smallest << (6 - n),
+    }
+}
+
+fn encode_size(size: u32, smallest: u32) -> Result<u32, FuseError> {
+    if size == 0 {
+        return Ok(7);
+    }
+    (0..7)
+        .find(|&n| smallest << (6 - n) == size)
+        .ok_or(FuseError::InvalidSize)
+}
@@ -0,0 +1,413 @@
+//! Programming the on-chip flash with the NVM controller.
+//!
+//! The flash is divided into pages, which are the unit of programming,
+//! and rows of four pages, which are the unit of erasure.  A page is
+//! programmed by filling the page buffer through normal 32-bit writes to
+//! the flash address range, followed by a write page command; in the
+//! automatic write mode the command is issued by the hardware as soon
+//! as the last word of the page buffer is written.  Programming can only
+//! clear bits, so a row must be erased before its pages are rewritten.
+//!
+//! The flash is also split into 16 lock regions, whose lock bits
+//! protect them against erasure and programming.  The user row holds the
+//! fuses that are loaded at reset, such as the bootloader protection
+//! size; it is erased and programmed with separate commands.  The
+//! `fuses` module decodes them.
+//!
+//! The `eeprom` module builds an emulated EEPROM on the flash area
+//! reserved by the EEPROM fuse.
+//!
+//! The code doing the programming must not be running from the row that
+//! is being modified.  Reads from flash stall while the controller is
+//! busy, so interrupt handlers that run from flash are delayed, but not
+//! corrupted, by these operations.
+use core::cmp;
+use core::ptr;
+use target_device::nvmctrl::ctrla;
+use target_device::NVMCTRL;
+
+pub mod eeprom;
+pub mod fuses;
+
+/// The address of the user row
+pub const USER_ROW_ADDR: u32 = 0x0080_4000;
+
+/// The number of lock regions
+pub const NUM_LOCK_REGIONS: u8 = 16;
+
+/// The number of pages in a row
+const PAGES_PER_ROW: u32 = 4;
+
+/// Failures reported by the NVM controller
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// An invalid command or a malformed write sequence (PROGE)
+    Programming,
+    /// The target address is in a locked region (LOCKE)
+    Locked,
+    /// The controller reported an error while executing the command,
+    /// which usually means the operation could not be verified (NVME)
+    Nvm,
+    /// The address or data is outside of the flash or is not
+    /// aligned as required by the operation
+    InvalidAddress,
+}
+
+/// How the page buffer is committed to flash
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WriteMode {
+    /// The page is written as soon as the last word of the page
+    /// buffer has been written
+    Automatic,
+    /// The page is only written by an explicit write page command
+    Manual,
+}
+
+/// The size of the flash as reported by NVMCTRL.PARAM
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    /// The size of a page in bytes
+    pub page_size: u32,
+    /// The number of pages in the main flash array
+    pub pages: u32,
+}
+
+impl Geometry {
+    /// The size of a row, the smallest erasable unit, in bytes
+    pub fn row_size(&self) -> u32 {
+        self.page_size * PAGES_PER_ROW
+    }
+
+    /// The size of the main flash array in bytes
+    pub fn flash_size(&self) -> u32 {
+        self.page_size * self.pages
+    }
+
+    /// The size of a lock region in bytes
+    pub fn region_size(&self) -> u32 {
+        self.flash_size() / u32::from(NUM_LOCK_REGIONS)
+    }
+
+    /// The number of the `words` words starting at `addr` that fit in
+    /// the page containing `addr`
+    fn words_in_page(&self, addr: u32, words: usize) -> usize {
+        cmp::min(((self.page_size - addr % self.page_size) / 4) as usize, words)
+    }
+
+    /// Returns true if writing `words` words starting at `addr` writes
+    /// the last word of the page buffer, which is what starts the page
+    /// write in automatic write mode; where the write started doesn't
+    /// matter.
+    fn fills_page(&self, addr: u32, words: usize) -> bool {
+        addr % self.page_size + words as u32 * 4 == self.page_size
+    }
+}
+
+/// Represents the NVMCTRL peripheral
+pub struct Nvm {
+    nvmctrl: NVMCTRL,
+    geometry: Geometry,
+}
+
+impl Nvm {
+    /// Takes ownership of the NVM controller.  Its bus clock is always
+    /// enabled, and the wait states were configured when the clocks
+    /// were set up, so no other configuration is needed.
+    pub fn new(nvmctrl: NVMCTRL) -> Self {
+        let param = nvmctrl.param.read();
+        let geometry = Geometry {
+            page_size: 8 << param.psz().bits(),
+            pages: u32::from(param.nvmp().bits()),
+        };
+        Self { nvmctrl, geometry }
+    }
+
+    /// Releases the NVMCTRL peripheral
+    pub fn free(self) -> NVMCTRL {
+        self.nvmctrl
+    }
+
+    pub fn geometry(&self) -> Geometry {
+        self.geometry
+    }
+
+    pub fn write_mode(&self) -> WriteMode {
+        if self.nvmctrl.ctrlb.read().manw().bit_is_set() {
+            WriteMode::Manual
+        } else {
+            WriteMode::Automatic
+        }
+    }
+
+    pub fn set_write_mode(&mut self, mode: WriteMode) {
+        self.nvmctrl
+            .ctrlb
+            .modify(|_, w| w.manw().bit(mode == WriteMode::Manual));
+    }
+
+    fn wait_ready(&self) {
+        while self.nvmctrl.intflag.read().ready().bit_is_clear() {}
+    }
+
+    /// Reads and clears the error flags from the last command
+    fn check_errors(&mut self) -> Result<(), Error> {
+        let status = self.nvmctrl.status.read();
+        let result = if status.locke().bit_is_set() {
+            Err(Error::Locked)
+        } else if status.proge().bit_is_set() {
+            Err(Error::Programming)
+        } else if status.nvme().bit_is_set() {
+            Err(Error::Nvm)
+        } else {
+            Ok(())
+        };
+        self.nvmctrl.status.write(|w| {
+            w.locke().set_bit();
+            w.proge().set_bit();
+            w.nvme().set_bit()
+        });
+        self.nvmctrl.intflag.write(|w| w.error().set_bit());
+        result
+    }
+
+    /// Sets ADDR, which is expressed in 16-bit words
+    fn set_address(&mut self, addr: u32) {
+        self.nvmctrl
+            .addr
+            .write(|w| unsafe { w.addr().bits(addr >> 1) });
+    }
+
+    /// Executes a command and waits for it to complete
+    fn command<F>(&mut self, f: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut ctrla::W),
+    {
+        self.wait_ready();
+        self.nvmctrl.ctrla.write(|w| {
+            f(w);
+            w.cmdex().key()
+        });
+        self.wait_ready();
+        self.check_errors()
+    }
+
+    fn check_range(&self, addr: u32, len: u32, align: u32) -> Result<(), Error> {
+        let in_range = addr
+            .checked_add(len)
+            .map(|end| end <= self.geometry.flash_size())
+            .unwrap_or(false);
+        if in_range && addr % align == 0 {
+            Ok(())
+        } else {
+            Err(Error::InvalidAddress)
+        }
+    }
+
+    /// Erases the row at `addr`, which must be row aligned.  The erased
+    /// bytes read as 0xff.
+    pub fn erase_row(&mut self, addr: u32) -> Result<(), Error> {
+        let row_size = self.geometry.row_size();
+        self.check_range(addr, row_size, row_size)?;
+        self.set_address(addr);
+        self.command(|w| {
+            w.cmd().er();
+        })
+    }
+
+    /// Erases every row overlapping `addr..addr + len`.  `addr` must be
+    /// row aligned.
+    pub fn erase(&mut self, addr: u32, len: u32) -> Result<(), Error> {
+        let row_size = self.geometry.row_size();
+        self.check_range(addr, len, row_size)?;
+        let mut row = addr;
+        while row < addr + len {
+            self.erase_row(row)?;
+            row += row_size;
+        }
+        Ok(())
+    }
+
+    /// Fills the page buffer with `data` and commits it to the page at
+    /// `addr`, using the write auxiliary page command for the user row.
+    /// The remainder of a partially filled page is left unchanged.
+    fn program_page(&mut self, addr: u32, data: &[u32], auxiliary: bool) -> Result<(), Error> {
+        self.command(|w| {
+            w.cmd().pbc();
+        })?;
+        for (i, word) in data.iter().enumerate() {
+            unsafe {
+                ptr::write_volatile((addr as *mut u32).offset(i as isize), *word);
+            }
+        }
+
+        let automatic = self.write_mode() == WriteMode::Automatic;
+        if automatic && self.geometry.fills_page(addr, data.len()) {
+            // writing the last word started the page write
+            self.wait_ready();
+            self.check_errors()
+        } else {
+            self.set_address(addr);
+            self.command(|w| {
+                if auxiliary {
+                    w.cmd().wap();
+                } else {
+                    w.cmd().wp();
+                }
+            })
+        }
+    }
+
+    /// Programs `data` starting at `addr`, which must be word aligned.
+    /// The destination must have been erased.
+    pub fn write(&mut self, addr: u32, data: &[u32]) -> Result<(), Error> {
+        self.check_range(addr, data.len() as u32 * 4, 4)?;
+        let mut addr = addr;
+        let mut data = data;
+        while !data.is_empty() {
+            let in_page = self.geometry.words_in_page(addr, data.len());
+            let (head, tail) = data.split_at(in_page);
+            self.program_page(addr, head, false)?;
+            addr += head.len() as u32 * 4;
+            data = tail;
+        }
+        Ok(())
+    }
+
+    /// Copies flash contents starting at `addr` into `buf`
+    pub fn read(&self, addr: u32, buf: &mut [u8]) -> Result<(), Error> {
+        self.check_range(addr, buf.len() as u32, 1)?;
+        for (i, byte) in buf.iter_mut().enumerate() {
+            *byte = unsafe { ptr::read_volatile((addr as *const u8).offset(i as isize)) };
+        }
+        Ok(())
+    }
+
+    /// Copies the start of the user row into `buf`.  The first 8 bytes
+    /// hold the fuses; the remainder of the row is free for application
+    /// use.
+    pub fn read_user_row(&self, buf: &mut [u32]) {
+        let words = (self.geometry.row_size() / 4) as usize;
+        let row = USER_ROW_ADDR as *const u32;
+        for (i, word) in buf.iter_mut().take(words).enumerate() {
+            *word = unsafe { ptr::read_volatile(row.offset(i as isize)) };
+        }
+    }
+
+    /// Erases the user row and programs `data` at its start.  The fuses
+    /// take effect at the next reset; writing incorrect values can
+    /// render the device unusable, so `data` should be based on the
+    /// result of `read_user_row`, or on `fuses::UserFuses::to_words`.
+    pub fn write_user_row(&mut self, data: &[u32]) -> Result<(), Error> {
+        let page_words = (self.geometry.page_size / 4) as usize;
+        if data.len() * 4 > self.geometry.row_size() as usize {
+            return Err(Error::InvalidAddress);
+        }
+        self.set_address(USER_ROW_ADDR);
+        self.command(|w| {
+            w.cmd().ear();
+        })?;
+
+        for (page, chunk) in data.chunks(page_words).enumerate() {
+            let addr = USER_ROW_ADDR + (page * page_words * 4) as u32;
+            self.program_page(addr, chunk, true)?;
+        }
+        Ok(())
+    }
+
+    /// The size of the area at the end of the flash that is reserved
+    /// for EEPROM emulation by the EEPROM fuse in the user row
+    pub fn eeprom_size(&self) -> u32 {
+        fuses::UserFuses::read().eeprom_size()
+    }
+
+    /// Returns the lock region containing `addr`
+    pub fn region(&self, addr: u32) -> u8 {
+        (addr / self.geometry.region_size()) as u8
+    }
+
+    /// Returns true if the lock region is locked
+    pub fn is_locked(&self, region: u8) -> bool {
+        self.nvmctrl.lock.read().bits() & (1 << region) == 0
+    }
+
+    /// Locks a region against erasure and programming until it is
+    /// unlocked or the device is reset.  The lock bits in the user row
+    /// select which regions are locked at reset.
+    pub fn lock_region(&mut self, region: u8) -> Result<(), Error> {
+        self.region_command(region, true)
+    }
+
+    pub fn unlock_region(&mut self, region: u8) -> Result<(), Error> {
+        self.region_command(region, false)
+    }
+
+    fn region_command(&mut self, region: u8, lock: bool) -> Result<(), Error> {
+        if region >= NUM_LOCK_REGIONS {
+            return Err(Error::InvalidAddress);
+        }
+        let addr = u32::from(region) * self.geometry.region_size();
+        self.set_address(addr);
+        self.command(|w| {
+            if lock {
+                w.cmd().lr();
+            } else {
+                w.cmd().ur();
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Geometry;
+
+    const GEOMETRY: Geometry = Geometry {
+        page_size: 64,
+        pages: 4096,
+    };
+
+    type Chunk = (u32, usize, bool);
+
+    /// Fills `out` with the (address, words, fills page) of each page
+    /// that `Nvm::write` programs for a write of `words` words at
+    /// `addr`, and returns the number of pages
+    fn chunks(addr: u32, words: usize, out: &mut [Chunk]) -> usize {
+        let mut addr = addr;
+        let mut words = words;
+        let mut n = 0;
+        while words > 0 {
+            let in_page = GEOMETRY.words_in_page(addr, words);
+            out[n] = (addr, in_page, GEOMETRY.fills_page(addr, in_page));
+            n += 1;
+            addr += in_page as u32 * 4;
+            words -= in_page;
+        }
+        n
+    }
+
+    fn check(addr: u32, words: usize, expected: &[Chunk]) {
+        let mut out = [(0, 0, false); 4];
+        let n = chunks(addr, words, &mut out);
+        assert_eq!(&out[..n], expected);
+    }
+
+    #[test]
+    fn whole_pages() {
+        check(0x100, 32, &[(0x100, 16, true), (0x140, 16, true)]);
+    }
+
+    #[test]
+    fn partial_page() {
+        check(0x100, 3, &[(0x100, 3, false)]);
+        check(0x104, 3, &[(0x104, 3, false)]);
+    }
+
+    #[test]
+    fn mid_page_to_boundary() {
+        // The buffer write that ends on the page boundary starts the
+        // page write in automatic mode, even though it is not a whole
+        // page of data
+        check(0x130, 4, &[(0x130, 4, true)]);
+        check(0x130, 7, &[(0x130, 4, true), (0x140, 3, false)]);
+    }
+}
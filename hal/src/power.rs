@@ -0,0 +1,264 @@
+//! Power management: sleep modes, resets and peripheral bus clock gating.
+//!
+//! The atsamd21 has two classes of sleep mode.  The IDLE modes stop
+//! progressively more of the synchronous clock domains and wake quickly
+//! on any enabled interrupt.  STANDBY stops all clocks except those
+//! that have been explicitly configured to run in standby; this is the
+//! mode to use for battery powered devices that spend most of their time
+//! waiting for an RTC, EIC or SERCOM event.
+//!
+//! The RTC and EIC on this device do not have their own RUNSTDBY bit;
+//! they keep running in standby as long as the generic clock that feeds
+//! them does.  Use `GenericClockController::run_in_standby` for those,
+//! and the `RunStandby` trait for the peripherals that carry their own
+//! RUNSTDBY configuration.
+//!
+//! The oscillators are left running continuously by
+//! `GenericClockController::new`.  `GenericClockController::run_on_demand`
+//! sets their ONDEMAND bit instead, so that an oscillator stops whenever
+//! nothing is using its clock, including in standby unless it is also
+//! configured to run in standby.
+use core::ptr;
+use cortex_m::asm;
+use cortex_m::peripheral::SCB;
+use target_device::PM;
+
+/// SLEEPDEEP bit in the Cortex-M System Control Register
+const SCB_SCR_SLEEPDEEP: u32 = 1 << 2;
+
+/// VECTKEY and SYSRESETREQ in the Cortex-M Application Interrupt
+/// and Reset Control Register
+const SCB_AIRCR_SYSRESETREQ: u32 = 0x05fa_0000 | (1 << 2);
+
+/// The size of SRAM, which depends on the part
+#[cfg(any(feature = "samd21e15a", feature = "samd21g15a", feature = "samd21j15a"))]
+const RAM_SIZE: u32 = 4 * 1024;
+#[cfg(any(feature = "samd21e16a", feature = "samd21g16a", feature = "samd21j16a"))]
+const RAM_SIZE: u32 = 8 * 1024;
+#[cfg(any(feature = "samd21e17a", feature = "samd21g17a", feature = "samd21j17a"))]
+const RAM_SIZE: u32 = 16 * 1024;
+#[cfg(any(feature = "samd21e18a", feature = "samd21g18a", feature = "samd21j18a"))]
+const RAM_SIZE: u32 = 32 * 1024;
+
+/// The last word of RAM, which the UF2 bootloader checks at reset
+/// to decide whether to stay in the bootloader.  SRAM starts at
+/// 0x2000_0000 on every part, so this moves with the RAM size.
+const BOOTLOADER_MAGIC_ADDR: u32 = 0x2000_0000 + RAM_SIZE - 4;
+
+/// The value that tells the UF2 bootloader to stay in the bootloader
+const UF2_BOOTLOADER_MAGIC: u32 = 0xf016_69ef;
+
+/// The sleep modes supported by the device
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SleepMode {
+    /// The CPU clock domain is stopped
+    Idle0,
+    /// The CPU and AHB clock domains are stopped
+    Idle1,
+    /// The CPU, AHB and APB clock domains are stopped
+    Idle2,
+    /// All clocks are stopped except those configured to run in standby
+    Standby,
+}
+
+/// Configures the device for the specified sleep mode, then waits
+/// for an interrupt.  Returns once an enabled interrupt has woken
+/// the device.
+pub fn sleep(mode: SleepMode, pm: &mut PM, scb: &mut SCB) {
+    prepare_sleep(mode, pm, scb);
+    // Ensure that all outstanding memory accesses complete before
+    // the clocks are stopped
+    asm::dsb();
+    asm::wfi();
+}
+
+/// Configures the sleep mode that will be entered on the next `wfi`
+/// instruction, without actually sleeping.  This is useful for
+/// frameworks that execute `wfi` in their idle loop.
+pub fn prepare_sleep(mode: SleepMode, pm: &mut PM, scb: &mut SCB) {
+    match mode {
+        SleepMode::Idle0 => pm.sleep.write(|w| w.idle().cpu()),
+        SleepMode::Idle1 => pm.sleep.write(|w| w.idle().ahb()),
+        SleepMode::Idle2 => pm.sleep.write(|w| w.idle().apb()),
+        SleepMode::Standby => {}
+    }
+    unsafe {
+        scb.scr.modify(|scr| {
+            if mode == SleepMode::Standby {
+                scr | SCB_SCR_SLEEPDEEP
+            } else {
+                scr & !SCB_SCR_SLEEPDEEP
+            }
+        });
+    }
+}
+
+/// The cause of the most recent reset, as recorded by the PM
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResetCause {
+    /// The device was powered on
+    PowerOn,
+    /// The core supply dropped below the BOD12 threshold
+    Bod12,
+    /// The VDD supply dropped below the BOD33 threshold
+    Bod33,
+    /// The RESET pin was asserted
+    External,
+    /// The watchdog timer expired or was fed outside of its window
+    Watchdog,
+    /// A system reset was requested via the Cortex-M AIRCR register
+    System,
+}
+
+/// Returns the cause of the most recent reset from PM.RCAUSE.
+/// A power-on reset also sets the brown-out flags, so it takes
+/// precedence over them.
+pub fn reset_cause(pm: &PM) -> ResetCause {
+    let rcause = pm.rcause.read();
+    if rcause.por().bit_is_set() {
+        ResetCause::PowerOn
+    } else if rcause.bod12().bit_is_set() {
+        ResetCause::Bod12
+    } else if rcause.bod33().bit_is_set() {
+        ResetCause::Bod33
+    } else if rcause.wdt().bit_is_set() {
+        ResetCause::Watchdog
+    } else if rcause.syst().bit_is_set() {
+        ResetCause::System
+    } else {
+        ResetCause::External
+    }
+}
+
+/// Resets the whole device, as if the RESET pin had been asserted.
+/// `reset_cause` reports `ResetCause::System` after this reset.
+pub fn system_reset(scb: &mut SCB) -> ! {
+    // Complete any outstanding memory accesses first
+    asm::dsb();
+    unsafe {
+        scb.aircr.write(SCB_AIRCR_SYSRESETREQ);
+    }
+    asm::dsb();
+    loop {}
+}
+
+/// Resets the device into the UF2 bootloader, which then presents
+/// its mass storage drive rather than starting the application.
+/// This has the same effect as double tapping the reset button.
+pub fn reset_into_bootloader(scb: &mut SCB) -> ! {
+    unsafe {
+        ptr::write_volatile(BOOTLOADER_MAGIC_ADDR as *mut u32, UF2_BOOTLOADER_MAGIC);
+    }
+    system_reset(scb)
+}
+
+/// Implemented by peripherals that can be configured to keep running
+/// while the device is in STANDBY sleep.  The generic clock used by
+/// the peripheral must also be configured to run in standby.
+pub trait RunStandby {
+    /// Enables or disables operation in standby
+    fn run_in_standby(&mut self, enable: bool);
+}
+
+macro_rules! bus_clocks {
+    ($($(#[$attr:meta])* $Variant:ident: [$(($mask:ident, $bit:ident)),+],)+) => {
+
+/// The peripheral bus clocks that can be gated via the PM mask registers.
+/// The clocks for PM, SYSCTRL, GCLK, NVMCTRL, PORT and the bus bridges
+/// are required for normal operation and are deliberately not listed.
+/// Neither is DSU, because gating it disconnects an attached debugger
+/// and stops the DSU CRC and MBIST functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BusClock {
+    $(
+        $(#[$attr])*
+        $Variant,
+    )+
+}
+
+impl BusClock {
+    /// Turns the bus clock(s) for the peripheral on or off.
+    /// Registers of a peripheral whose clock is off read as zero and
+    /// writes to them are discarded.
+    pub fn set_enabled(&self, pm: &mut PM, enable: bool) {
+        match *self {
+            $(
+                $(#[$attr])*
+                BusClock::$Variant => {
+                    $(
+                        pm.$mask.modify(|_, w| w.$bit().bit(enable));
+                    )+
+                }
+            )+
+        }
+    }
+
+    /// Returns true if the bus clock for the peripheral is on
+    pub fn is_enabled(&self, pm: &PM) -> bool {
+        match *self {
+            $(
+                $(#[$attr])*
+                BusClock::$Variant => true $(&& pm.$mask.read().$bit().bit_is_set())+,
+            )+
+        }
+    }
+}
+
+/// All of the bus clocks that may be gated
+const BUS_CLOCKS: &[BusClock] = &[
+    $(
+        $(#[$attr])*
+        BusClock::$Variant,
+    )+
+];
+
+    };
+}
+
+bus_clocks!(
+    Pac0: [(apbamask, pac0_)],
+    Wdt: [(apbamask, wdt_)],
+    Rtc: [(apbamask, rtc_)],
+    Eic: [(apbamask, eic_)],
+    Pac1: [(apbbmask, pac1_)],
+    Dmac: [(apbbmask, dmac_), (ahbmask, dmac_)],
+    Usb: [(apbbmask, usb_), (ahbmask, usb_)],
+    Pac2: [(apbcmask, pac2_)],
+    Evsys: [(apbcmask, evsys_)],
+    Sercom0: [(apbcmask, sercom0_)],
+    Sercom1: [(apbcmask, sercom1_)],
+    Sercom2: [(apbcmask, sercom2_)],
+    Sercom3: [(apbcmask, sercom3_)],
+    #[cfg(feature = "pins-48")]
+    Sercom4: [(apbcmask, sercom4_)],
+    #[cfg(feature = "pins-48")]
+    Sercom5: [(apbcmask, sercom5_)],
+    Tcc0: [(apbcmask, tcc0_)],
+    Tcc1: [(apbcmask, tcc1_)],
+    Tcc2: [(apbcmask, tcc2_)],
+    Tc3: [(apbcmask, tc3_)],
+    Tc4: [(apbcmask, tc4_)],
+    Tc5: [(apbcmask, tc5_)],
+    #[cfg(feature = "pins-64")]
+    Tc6: [(apbcmask, tc6_)],
+    #[cfg(feature = "pins-64")]
+    Tc7: [(apbcmask, tc7_)],
+    Adc: [(apbcmask, adc_)],
+    Ac: [(apbcmask, ac_)],
+    Dac: [(apbcmask, dac_)],
+    Ptc: [(apbcmask, ptc_)],
+    I2s: [(apbcmask, i2s_)],
+);
+
+/// Turns off the bus clock of every gateable peripheral that is not
+/// listed in `keep`.  The HAL peripheral constructors turn on the bus
+/// clock that they need, so this is typically called once after
+/// initialization with the peripherals that the application uses,
+/// to trim the clocks that are enabled by default after reset.
+pub fn gate_unused_bus_clocks(pm: &mut PM, keep: &[BusClock]) {
+    for clock in BUS_CLOCKS {
+        if !keep.contains(clock) {
+            clock.set_enabled(pm, false);
+        }
+    }
+}
@@ -2,10 +2,11 @@
 
 use clock;
 use hal::blocking::i2c::{Read, Write, WriteRead};
+use power::RunStandby;
 use sercom::pads::*;
 use target_device::sercom0::I2CM;
 use target_device::{SERCOM0, SERCOM1, SERCOM2, SERCOM3, PM};
-#[cfg(feature = "samd21g18a")]
+#[cfg(feature = "pins-48")]
 use target_device::{SERCOM4, SERCOM5};
 use time::Hertz;
 
@@ -268,6 +269,30 @@ impl $Type {
         self.fill_buffer(buffer)
     }
 }
+impl RunStandby for $Type {
+    /// Keep the I2C master running in standby.  The peripheral is
+    /// briefly disabled while the setting is changed, so this should
+    /// not be called in the middle of a transaction.
+    fn run_in_standby(&mut self, enable: bool) {
+        self.i2cm().ctrla.modify(|_, w| w.enable().clear_bit());
+        while self.i2cm().syncbusy.read().enable().bit_is_set() {}
+
+        self.i2cm().ctrla.modify(|_, w| {
+            w.runstdby().bit(enable);
+            w.enable().set_bit()
+        });
+        while self.i2cm().syncbusy.read().enable().bit_is_set() {}
+
+        // re-enabling leaves the bus state unknown; set it idle again
+        unsafe {
+            self.i2cm()
+                .status
+                .modify(|_, w| w.busstate().bits(BUS_STATE_IDLE));
+        }
+        self.wait_sync();
+    }
+}
+
 impl Write for $Type {
     type Error = I2CError;
 
@@ -337,7 +362,7 @@ i2c!([
         ),
 ]);
 
-#[cfg(feature = "samd21g18a")]
+#[cfg(feature = "pins-48")]
 i2c!([
     I2CMaster4:
         (
@@ -12,13 +12,14 @@ pub trait PadPin<T> {
 /// a little more convenient to initialize them.
 macro_rules! pad {
     ($(pub enum $PadType:ident {
-        $( $PinType:ident ($new:ident, $Pf:ident),)+
+        $( $(#[$attr:meta])* $PinType:ident ($new:ident, $Pf:ident),)+
     })+
     ) => {
 $(
 /// Represents a numbered pad for the associated sercom instance
 pub enum $PadType {
     $(
+        $(#[$attr])*
         $PinType(gpio::$PinType<gpio::$Pf>),
     )+
 }
@@ -28,6 +29,7 @@ impl $PadType {
     /// Construct pad from the appropriate pin in any mode.
     /// You may find it more convenient to use the `into_pad` trait
     /// and avoid referencing the pad type.
+    $(#[$attr])*
     pub fn $new<MODE>(pin: gpio::$PinType<MODE>, port: &mut Port) -> Self {
         $PadType::$PinType(pin.into_function(port))
     }
@@ -36,6 +38,7 @@ impl $PadType {
 }
 
 $(
+$(#[$attr])*
 impl<MODE> PadPin<$PadType> for gpio::$PinType<MODE> {
     fn into_pad(self, port: &mut Port) -> $PadType {
         $PadType::$new(self, port)
@@ -105,11 +108,13 @@ pub enum Sercom1Pad3 {
 
 pub enum Sercom2Pad0 {
     Pa8(pa8, PfD),
+    #[cfg(feature = "pins-48")]
     Pa12(pa12, PfC),
 }
 
 pub enum Sercom2Pad1 {
     Pa9(pa9, PfD),
+    #[cfg(feature = "pins-48")]
     Pa13(pa13, PfC),
 }
 
@@ -138,17 +143,19 @@ pub enum Sercom3Pad1 {
 }
 pub enum Sercom3Pad2 {
     Pa18(pa18, PfD),
+    #[cfg(feature = "pins-48")]
     Pa20(pa20, PfD),
     Pa24(pa24, PfC),
 }
 pub enum Sercom3Pad3 {
     Pa19(pa19, PfD),
+    #[cfg(feature = "pins-48")]
     Pa21(pa21, PfD),
     Pa25(pa25, PfC),
 }
 );
 
-#[cfg(feature = "samd21g18a")]
+#[cfg(feature = "pins-48")]
 pad!(
 // sercom4[0]:  PA12:D   PB08:D   PB12:C
 // sercom4[1]:  PA13:D   PB09:D   PB13:C
@@ -158,24 +165,28 @@ pad!(
 pub enum Sercom4Pad0 {
     Pa12(pa12, PfD),
     Pb8(pb8, PfD),
+    #[cfg(feature = "pins-64")]
     Pb12(pb12, PfC),
 }
 
 pub enum Sercom4Pad1 {
     Pa13(pa13, PfD),
     Pb9(pb9, PfD),
+    #[cfg(feature = "pins-64")]
     Pb13(pb13, PfC),
 }
 
 pub enum Sercom4Pad2 {
     Pa14(pa14, PfD),
     Pb10(pb10, PfD),
+    #[cfg(feature = "pins-64")]
     Pb14(pb14, PfC),
 }
 
 pub enum Sercom4Pad3 {
     Pa15(pa15, PfD),
     Pb11(pb11, PfD),
+    #[cfg(feature = "pins-64")]
     Pb15(pb15, PfC),
 }
 
@@ -187,19 +198,24 @@ pub enum Sercom4Pad3 {
 pub enum Sercom5Pad0 {
     Pa22(pa22, PfD),
     Pb2(pb2, PfD),
+    #[cfg(feature = "pins-64")]
     Pb16(pb16, PfC),
+    #[cfg(feature = "pins-64")]
     Pb30(pb30, PfD),
 }
 
 pub enum Sercom5Pad1 {
     Pa23(pa23, PfD),
     Pb3(pb3, PfD),
+    #[cfg(feature = "pins-64")]
     Pb17(pb17, PfC),
+    #[cfg(feature = "pins-64")]
     Pb31(pb31, PfD),
 }
 
 pub enum Sercom5Pad2 {
     Pa24(pa24, PfD),
+    #[cfg(feature = "pins-64")]
     Pb0(pb0, PfD),
     Pa20(pa20, PfC),
     Pb22(pb22, PfD),
@@ -207,6 +223,7 @@ pub enum Sercom5Pad2 {
 
 pub enum Sercom5Pad3 {
     Pa25(pa25, PfD),
+    #[cfg(feature = "pins-64")]
     Pb1(pb1, PfD),
     Pa21(pa21, PfC),
     Pb23(pb23, PfD),
@@ -1,10 +1,11 @@
 use clock;
 use hal::spi::{FullDuplex, Mode, Phase, Polarity};
 use nb;
+use power::RunStandby;
 use sercom::pads::*;
 use target_device::sercom0::SPI;
 use target_device::{SERCOM0, SERCOM1, SERCOM2, SERCOM3, PM};
-#[cfg(feature = "samd21g18a")]
+#[cfg(feature = "pins-48")]
 use target_device::{SERCOM4, SERCOM5};
 use time::Hertz;
 
@@ -57,6 +58,23 @@ pub enum $Type {
     Dipo2Dopo3{miso:$pad2, mosi:$pad0, sck:$pad3},
 
     Dipo3Dopo0{miso:$pad3, mosi:$pad0, sck:$pad1},
+
+    /// Construct a master pinout without miso, for devices that are
+    /// only written to, with mosi assigned to pad0 and sck to pad1.
+    /// The receiver is left sampling one of the unused pads, so reads
+    /// return meaningless data.
+    Dopo0{mosi:$pad0, sck:$pad1},
+    Dopo1{mosi:$pad2, sck:$pad3},
+    Dopo2{mosi:$pad3, sck:$pad1},
+    Dopo3{mosi:$pad0, sck:$pad3},
+
+    /// Construct a master pinout that only drives mosi, for protocols
+    /// such as the WS2812 LED data line whose timing is carried by the
+    /// data itself.  The hardware still generates sck on the pad that
+    /// the DOPO setting selects, but it is not routed to a pin.
+    Dopo0Mosi{mosi:$pad0},
+    Dopo1Mosi{mosi:$pad2},
+    Dopo2Mosi{mosi:$pad3},
 }
 
 impl $Type {
@@ -80,6 +98,15 @@ impl $Type {
             &$Type::Dipo2Dopo3{..} => (2, 3),
 
             &$Type::Dipo3Dopo0{..} => (3, 0),
+
+            &$Type::Dopo0{..} => (3, 0),
+            &$Type::Dopo1{..} => (0, 1),
+            &$Type::Dopo2{..} => (0, 2),
+            &$Type::Dopo3{..} => (1, 3),
+
+            &$Type::Dopo0Mosi{..} => (3, 0),
+            &$Type::Dopo1Mosi{..} => (0, 1),
+            &$Type::Dopo2Mosi{..} => (0, 2),
         }
     }
 }
@@ -95,7 +122,7 @@ spi_pinout!([
     SPI2Pinout: (Sercom2Pad0, Sercom2Pad1, Sercom2Pad2, Sercom2Pad3),
     SPI3Pinout: (Sercom3Pad0, Sercom3Pad1, Sercom3Pad2, Sercom3Pad3),
 ]);
-#[cfg(feature = "samd21g18a")]
+#[cfg(feature = "pins-48")]
 spi_pinout!([
     SPI4Pinout: (Sercom4Pad0, Sercom4Pad1, Sercom4Pad2, Sercom4Pad3),
     SPI5Pinout: (Sercom5Pad0, Sercom5Pad1, Sercom5Pad2, Sercom5Pad3),
@@ -232,6 +259,21 @@ impl FullDuplex<u8> for $Type {
     }
 }
 
+impl RunStandby for $Type {
+    /// Keep the SPI master running in standby.  The peripheral is
+    /// briefly disabled while the setting is changed.
+    fn run_in_standby(&mut self, enable: bool) {
+        self.spi().ctrla.modify(|_, w| w.enable().clear_bit());
+        while self.spi().syncbusy.read().enable().bit_is_set() {}
+
+        self.spi().ctrla.modify(|_, w| {
+            w.runstdby().bit(enable);
+            w.enable().set_bit()
+        });
+        while self.spi().syncbusy.read().enable().bit_is_set() {}
+    }
+}
+
 impl ::hal::blocking::spi::transfer::Default<u8> for $Type {}
 impl ::hal::blocking::spi::write::Default<u8> for $Type {}
 
@@ -246,7 +288,7 @@ spi!([
     SPIMaster2: (SPI2Pinout, SERCOM2, sercom2_, Sercom2CoreClock),
     SPIMaster3: (SPI3Pinout, SERCOM3, sercom3_, Sercom3CoreClock),
 ]);
-#[cfg(feature = "samd21g18a")]
+#[cfg(feature = "pins-48")]
 spi!([
     SPIMaster4: (SPI4Pinout, SERCOM4, sercom4_, Sercom4CoreClock),
     SPIMaster5: (SPI5Pinout, SERCOM5, sercom5_, Sercom5CoreClock),
@@ -0,0 +1,261 @@
+use clock;
+use core::cmp;
+use hal::serial::{Read, Write};
+use nb;
+use power::RunStandby;
+use sercom::pads::*;
+use target_device::sercom0::USART;
+use target_device::{SERCOM0, SERCOM1, SERCOM2, SERCOM3, PM};
+#[cfg(feature = "pins-48")]
+use target_device::{SERCOM4, SERCOM5};
+use time::Hertz;
+
+/// Errors detected while receiving
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UARTError {
+    /// A received byte was lost because the receive buffer was full
+    Overflow,
+    /// A stop bit was not found where it was expected
+    Framing,
+    /// The parity bit did not match
+    Parity,
+}
+
+macro_rules! uart_pinout {
+    ([$($Type:ident:
+        ($pad0:ident, $pad1:ident, $pad2:ident, $pad3:ident),)+
+    ]) => {
+$(
+/// When configured as a UART, TX can be assigned to pad0 or pad2 and
+/// RX to any of the other pads.
+/// The variant names refer to the RXPO and TXPO settings that
+/// are used to configure the UART peripheral.
+pub enum $Type {
+    Rx1Tx0{rx:$pad1, tx:$pad0},
+    Rx2Tx0{rx:$pad2, tx:$pad0},
+    Rx3Tx0{rx:$pad3, tx:$pad0},
+
+    Rx0Tx2{rx:$pad0, tx:$pad2},
+    Rx1Tx2{rx:$pad1, tx:$pad2},
+    Rx3Tx2{rx:$pad3, tx:$pad2},
+}
+
+impl $Type {
+    /// Return the rx pad, tx pad values for
+    /// this pinout configuration
+    fn rxpo_txpo(&self) -> (u8, u8) {
+        match self {
+            &$Type::Rx1Tx0{..} => (1, 0),
+            &$Type::Rx2Tx0{..} => (2, 0),
+            &$Type::Rx3Tx0{..} => (3, 0),
+
+            &$Type::Rx0Tx2{..} => (0, 1),
+            &$Type::Rx1Tx2{..} => (1, 1),
+            &$Type::Rx3Tx2{..} => (3, 1),
+        }
+    }
+}
+
+)+
+
+}
+}
+
+uart_pinout!([
+    UART0Pinout: (Sercom0Pad0, Sercom0Pad1, Sercom0Pad2, Sercom0Pad3),
+    UART1Pinout: (Sercom1Pad0, Sercom1Pad1, Sercom1Pad2, Sercom1Pad3),
+    UART2Pinout: (Sercom2Pad0, Sercom2Pad1, Sercom2Pad2, Sercom2Pad3),
+    UART3Pinout: (Sercom3Pad0, Sercom3Pad1, Sercom3Pad2, Sercom3Pad3),
+]);
+#[cfg(feature = "pins-48")]
+uart_pinout!([
+    UART4Pinout: (Sercom4Pad0, Sercom4Pad1, Sercom4Pad2, Sercom4Pad3),
+    UART5Pinout: (Sercom5Pad0, Sercom5Pad1, Sercom5Pad2, Sercom5Pad3),
+]);
+
+macro_rules! uart {
+    ([
+        $($Type:ident: (
+                        $PinOut:ident,
+                        $SERCOM:ident, $powermask:ident, $clock:ident),)+
+    ]) => {
+$(
+
+/// UARTX represents the corresponding SERCOMX instance configured to
+/// act as an asynchronous serial port with 8 data bits, no parity and
+/// one stop bit.
+/// Objects of this type implement the HAL serial `Read` and `Write`
+/// traits and the blocking serial `Write` trait.
+pub struct $Type {
+    pinout: $PinOut,
+    sercom: $SERCOM,
+}
+
+impl $Type {
+    /// Power on and configure SERCOMX to work as a UART running at
+    /// the specified baud rate.  The pinout specifies which pins are
+    /// bound to the RX and TX functions.  The baud rate is limited to
+    /// 1/16 of the clock frequency; a faster rate is clamped to that.
+    pub fn new<F: Into<Hertz>>(
+        clock: &clock::$clock,
+        baud: F,
+        sercom: $SERCOM,
+        pm: &mut PM,
+        pinout: $PinOut,
+    ) -> Self {
+        // Power up the peripheral bus clock.
+        // safe because we're exclusively owning SERCOM
+        pm.apbcmask.modify(|_, w| w.$powermask().set_bit());
+
+        unsafe {
+            // reset the sercom instance
+            sercom.usart.ctrla.modify(|_, w| w.swrst().set_bit());
+            // wait for reset to complete
+            while sercom.usart.syncbusy.read().swrst().bit_is_set()
+                || sercom.usart.ctrla.read().swrst().bit_is_set()
+            {}
+
+            let (rxpo, txpo) = pinout.rxpo_txpo();
+            sercom.usart.ctrla.modify(|_, w| {
+                // Asynchronous mode with the internal clock
+                w.mode().usart_int_clk();
+                w.cmode().clear_bit();
+                w.rxpo().bits(rxpo);
+                w.txpo().bits(txpo);
+                // 16x oversampling with arithmetic baud generation
+                w.sampr().bits(0);
+                // No parity
+                w.form().bits(0);
+                // LSB first
+                w.dord().set_bit()
+            });
+
+            // 8 data bits, one stop bit, and enable both directions
+            sercom.usart.ctrlb.modify(|_, w| {
+                w.chsize().bits(0);
+                w.sbmode().clear_bit();
+                w.txen().set_bit();
+                w.rxen().set_bit()
+            });
+            while sercom.usart.syncbusy.read().ctrlb().bit_is_set() {}
+
+            // BAUD = 65536 * (1 - 16 * baud / gclk).  A baud rate outside
+            // of what the clock can generate is clamped to the nearest
+            // one it can, which is gclk / 16 at the top end.
+            let gclk = u64::from(clock.freq().0);
+            let ratio = (16 * u64::from(baud.into().0) << 16) / gclk;
+            let value = (65536 - cmp::min(cmp::max(ratio, 1), 65536)) as u16;
+            sercom.usart.baud.modify(|_, w| w.baud().bits(value));
+
+            sercom.usart.ctrla.modify(|_, w| w.enable().set_bit());
+            // wait for configuration to take effect
+            while sercom.usart.syncbusy.read().enable().bit_is_set() {}
+        }
+
+        Self {
+            pinout,
+            sercom,
+        }
+    }
+
+    /// Tear down the UART instance and yield the constituent pins and
+    /// SERCOM instance.  No explicit de-initialization is performed.
+    pub fn free(self) -> ($PinOut, $SERCOM) {
+        (self.pinout, self.sercom)
+    }
+
+    /// Helper for accessing the usart member of the sercom instance
+    fn usart(&mut self) -> &USART {
+        unsafe { &self.sercom.usart }
+    }
+}
+
+impl Read<u8> for $Type {
+    type Error = UARTError;
+
+    fn read(&mut self) -> nb::Result<u8, UARTError> {
+        let status = self.usart().status.read();
+        let error = if status.bufovf().bit_is_set() {
+            Some(UARTError::Overflow)
+        } else if status.ferr().bit_is_set() {
+            Some(UARTError::Framing)
+        } else if status.perr().bit_is_set() {
+            Some(UARTError::Parity)
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            // The error flags are cleared by writing a one
+            self.usart().status.write(|w| {
+                w.bufovf().set_bit();
+                w.ferr().set_bit();
+                w.perr().set_bit()
+            });
+            return Err(nb::Error::Other(error));
+        }
+
+        // rxc is receive complete
+        if self.usart().intflag.read().rxc().bit_is_set() {
+            Ok(self.usart().data.read().data().bits() as u8)
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+impl Write<u8> for $Type {
+    type Error = UARTError;
+
+    fn write(&mut self, byte: u8) -> nb::Result<(), UARTError> {
+        // dre is data register empty
+        if self.usart().intflag.read().dre().bit_is_set() {
+            self.usart().data.write(|w| unsafe { w.data().bits(u16::from(byte)) });
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), UARTError> {
+        // txc is set once the last byte has been shifted out
+        if self.usart().intflag.read().txc().bit_is_set() {
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+impl ::hal::blocking::serial::write::Default<u8> for $Type {}
+
+impl RunStandby for $Type {
+    /// Keep the UART running in standby, so that received data can
+    /// wake the device.  The peripheral is briefly disabled while the
+    /// setting is changed.
+    fn run_in_standby(&mut self, enable: bool) {
+        self.usart().ctrla.modify(|_, w| w.enable().clear_bit());
+        while self.usart().syncbusy.read().enable().bit_is_set() {}
+
+        self.usart().ctrla.modify(|_, w| {
+            w.runstdby().bit(enable);
+            w.enable().set_bit()
+        });
+        while self.usart().syncbusy.read().enable().bit_is_set() {}
+    }
+}
+
+)+
+    };
+}
+
+uart!([
+    UART0: (UART0Pinout, SERCOM0, sercom0_, Sercom0CoreClock),
+    UART1: (UART1Pinout, SERCOM1, sercom1_, Sercom1CoreClock),
+    UART2: (UART2Pinout, SERCOM2, sercom2_, Sercom2CoreClock),
+    UART3: (UART3Pinout, SERCOM3, sercom3_, Sercom3CoreClock),
+]);
+#[cfg(feature = "pins-48")]
+uart!([
+    UART4: (UART4Pinout, SERCOM4, sercom4_, Sercom4CoreClock),
+    UART5: (UART5Pinout, SERCOM5, sercom5_, Sercom5CoreClock),
+]);
@@ -0,0 +1,228 @@
+//! Driver for the SPI NOR flash chips fitted to many samd21 boards.
+//!
+//! `SpiFlash` drives a JEDEC compatible SPI NOR flash chip, such as the
+//! 2MB GD25Q16 on the Metro M0 Express, and implements the `BlockDevice`
+//! trait so that it can be exposed to a host with `usb::msc::MassStorage`.
+//! Flash can only be erased in 4K sectors, so writes are gathered in a
+//! sector sized cache that is written back when a block from another
+//! sector is written, or when the block device is flushed.
+use hal::blocking::spi::{Transfer, Write};
+use hal::digital::OutputPin;
+use usb::msc::{BlockDevice, BLOCK_SIZE};
+
+const CMD_PAGE_PROGRAM: u8 = 0x02;
+const CMD_READ: u8 = 0x03;
+const CMD_READ_STATUS: u8 = 0x05;
+const CMD_WRITE_ENABLE: u8 = 0x06;
+const CMD_SECTOR_ERASE: u8 = 0x20;
+const CMD_JEDEC_ID: u8 = 0x9f;
+
+const STATUS_BUSY: u8 = 1;
+
+/// The smallest erasable unit
+const SECTOR_SIZE: usize = 4096;
+/// The largest programmable unit
+const PAGE_SIZE: usize = 256;
+const BLOCKS_PER_SECTOR: u32 = (SECTOR_SIZE / BLOCK_SIZE) as u32;
+
+/// A SPI NOR flash chip
+pub struct SpiFlash<SPI, CS> {
+    spi: SPI,
+    cs: CS,
+    capacity: u32,
+    /// The sector held in `cache`, if any
+    cached_sector: Option<u32>,
+    dirty: bool,
+    cache: [u8; SECTOR_SIZE],
+}
+
+impl<SPI, CS, E> SpiFlash<SPI, CS>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+{
+    /// Identifies the flash chip; its capacity is derived from the
+    /// JEDEC ID.  The board support crates provide a `flash_spi_master`
+    /// function that returns a suitable `spi` and `cs`.
+    pub fn new(spi: SPI, cs: CS) -> Result<Self, E> {
+        let mut flash = Self {
+            spi,
+            cs,
+            capacity: 0,
+            cached_sector: None,
+            dirty: false,
+            cache: [0; SECTOR_SIZE],
+        };
+        let id = flash.jedec_id()?;
+        // The third byte is log2 of the capacity for most vendors
+        flash.capacity = match id[2] {
+            0x10...0x1f => 1 << id[2],
+            _ => 2 * 1024 * 1024,
+        };
+        Ok(flash)
+    }
+
+    /// Releases the SPI peripheral and CS pin, discarding any
+    /// unflushed writes
+    pub fn free(self) -> (SPI, CS) {
+        (self.spi, self.cs)
+    }
+
+    /// The capacity of the chip in bytes
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Returns the manufacturer ID, memory type and capacity bytes
+    pub fn jedec_id(&mut self) -> Result<[u8; 3], E> {
+        let mut buf = [CMD_JEDEC_ID, 0, 0, 0];
+        self.cs.set_low();
+        let result = self.spi.transfer(&mut buf).map(|_| ());
+        self.cs.set_high();
+        result?;
+        Ok([buf[1], buf[2], buf[3]])
+    }
+
+    /// Sends a command with a 24 bit address, then runs `f`
+    /// while CS is still asserted
+    fn command<F>(&mut self, cmd: u8, addr: u32, f: F) -> Result<(), E>
+    where
+        F: FnOnce(&mut SPI) -> Result<(), E>,
+    {
+        self.cs.set_low();
+        let result = self
+            .spi
+            .write(&[cmd, (addr >> 16) as u8, (addr >> 8) as u8, addr as u8])
+            .and_then(|_| f(&mut self.spi));
+        self.cs.set_high();
+        result
+    }
+
+    /// Reads directly from the chip, bypassing the sector cache
+    pub fn read(&mut self, addr: u32, buf: &mut [u8]) -> Result<(), E> {
+        for byte in buf.iter_mut() {
+            *byte = 0;
+        }
+        self.command(CMD_READ, addr, |spi| spi.transfer(buf).map(|_| ()))
+    }
+
+    fn wait_ready(&mut self) -> Result<(), E> {
+        loop {
+            let mut buf = [CMD_READ_STATUS, 0];
+            self.cs.set_low();
+            let result = self.spi.transfer(&mut buf).map(|_| ());
+            self.cs.set_high();
+            result?;
+            if buf[1] & STATUS_BUSY == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    fn write_enable(&mut self) -> Result<(), E> {
+        self.cs.set_low();
+        let result = self.spi.write(&[CMD_WRITE_ENABLE]);
+        self.cs.set_high();
+        result
+    }
+
+    /// Erases and reprograms the cached sector if it has been modified
+    fn write_back(&mut self) -> Result<(), E> {
+        let sector = match self.cached_sector {
+            Some(sector) if self.dirty => sector,
+            _ => return Ok(()),
+        };
+        let base = sector * SECTOR_SIZE as u32;
+
+        self.write_enable()?;
+        self.command(CMD_SECTOR_ERASE, base, |_| Ok(()))?;
+        self.wait_ready()?;
+
+        for page in 0..SECTOR_SIZE / PAGE_SIZE {
+            let start = page * PAGE_SIZE;
+            self.write_enable()?;
+            {
+                let data = &self.cache[start..start + PAGE_SIZE];
+                let spi = &mut self.spi;
+                let addr = base + start as u32;
+                self.cs.set_low();
+                let result = spi
+                    .write(&[
+                        CMD_PAGE_PROGRAM,
+                        (addr >> 16) as u8,
+                        (addr >> 8) as u8,
+                        addr as u8,
+                    ])
+                    .and_then(|_| spi.write(data));
+                self.cs.set_high();
+                result?;
+            }
+            self.wait_ready()?;
+        }
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Makes `sector` the cached sector, writing back the previous one
+    fn load_sector(&mut self, sector: u32) -> Result<(), E> {
+        if self.cached_sector == Some(sector) {
+            return Ok(());
+        }
+        self.write_back()?;
+        self.cached_sector = None;
+
+        // The cache is borrowed alongside the SPI, so this
+        // cannot go through `read`
+        let addr = sector * SECTOR_SIZE as u32;
+        self.cs.set_low();
+        let result = {
+            let spi = &mut self.spi;
+            let buf = &mut self.cache;
+            for byte in buf.iter_mut() {
+                *byte = 0;
+            }
+            spi.write(&[CMD_READ, (addr >> 16) as u8, (addr >> 8) as u8, addr as u8])
+                .and_then(|_| spi.transfer(buf).map(|_| ()))
+        };
+        self.cs.set_high();
+        result?;
+        self.cached_sector = Some(sector);
+        Ok(())
+    }
+}
+
+impl<SPI, CS, E> BlockDevice for SpiFlash<SPI, CS>
+where
+    SPI: Transfer<u8, Error = E> + Write<u8, Error = E>,
+    CS: OutputPin,
+{
+    type Error = E;
+
+    fn block_count(&self) -> u32 {
+        self.capacity / BLOCK_SIZE as u32
+    }
+
+    fn read_block(&mut self, lba: u32, block: &mut [u8; BLOCK_SIZE]) -> Result<(), E> {
+        let sector = lba / BLOCKS_PER_SECTOR;
+        if self.cached_sector == Some(sector) {
+            let start = (lba % BLOCKS_PER_SECTOR) as usize * BLOCK_SIZE;
+            block.copy_from_slice(&self.cache[start..start + BLOCK_SIZE]);
+            return Ok(());
+        }
+        self.read(lba * BLOCK_SIZE as u32, block)
+    }
+
+    fn write_block(&mut self, lba: u32, block: &[u8; BLOCK_SIZE]) -> Result<(), E> {
+        self.load_sector(lba / BLOCKS_PER_SECTOR)?;
+        let start = (lba % BLOCKS_PER_SECTOR) as usize * BLOCK_SIZE;
+        if &self.cache[start..start + BLOCK_SIZE] != &block[..] {
+            self.cache[start..start + BLOCK_SIZE].copy_from_slice(block);
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), E> {
+        self.write_back()
+    }
+}
@@ -0,0 +1,142 @@
+//! Supply voltage monitoring and regulation.
+//!
+//! This module covers the parts of SYSCTRL that are not clock sources:
+//! the BOD33 brown-out detector that monitors VDD, the behavior of the
+//! main voltage regulator in standby and the VREF block that provides
+//! the bandgap reference and temperature sensor to the ADC.
+//! Devices running from a battery can configure BOD33 to raise an
+//! interrupt when the supply sags, giving the application a chance to
+//! save state and shut down gracefully before the supply collapses.
+use target_device::SYSCTRL;
+
+/// What the BOD33 should do when VDD drops below the threshold
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BodAction {
+    /// Only update the status flag
+    None,
+    /// Reset the device
+    Reset,
+    /// Raise the BOD33DET interrupt
+    Interrupt,
+}
+
+/// How the BOD33 monitors the supply
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BodMode {
+    /// VDD is monitored continuously
+    Continuous,
+    /// VDD is sampled periodically, which uses much less power.
+    /// The value selects the prescaler applied to the 1Khz output of
+    /// OSCULP32K; the sampling period is `2^(value+1)` milliseconds.
+    Sampling(u8),
+}
+
+/// Configuration for the BOD33 brown-out detector
+#[derive(Debug, Clone, Copy)]
+pub struct Bod33Config {
+    /// The threshold level.  Typical thresholds are 1.64V at level 6,
+    /// 2.84V at level 39 and 3.2V at level 48; see the BOD33
+    /// electrical characteristics in the datasheet for the others.
+    pub level: u8,
+    /// Adds hysteresis (about 50mV) to the threshold to avoid
+    /// repeated detections on a noisy supply
+    pub hysteresis: bool,
+    pub mode: BodMode,
+    pub action: BodAction,
+    /// Keep monitoring while the device is in standby
+    pub run_in_standby: bool,
+}
+
+fn wait_for_bod33_sync(sysctrl: &mut SYSCTRL) {
+    while sysctrl.pclksr.read().b33srdy().bit_is_clear() {}
+}
+
+/// Configures and enables the BOD33 brown-out detector.
+/// The detector is disabled while it is being reconfigured; this
+/// function waits for it to become ready again before returning.
+pub fn configure_bod33(sysctrl: &mut SYSCTRL, config: &Bod33Config) {
+    disable_bod33(sysctrl);
+
+    sysctrl.bod33.write(|w| {
+        unsafe {
+            w.level().bits(config.level);
+        }
+        w.hyst().bit(config.hysteresis);
+        match config.action {
+            BodAction::None => w.action().none(),
+            BodAction::Reset => w.action().reset(),
+            BodAction::Interrupt => w.action().interrupt(),
+        };
+        match config.mode {
+            BodMode::Continuous => w.mode().clear_bit(),
+            BodMode::Sampling(prescaler) => {
+                w.psel().bits(prescaler);
+                w.cen().set_bit();
+                w.mode().set_bit()
+            }
+        };
+        w.runstdby().bit(config.run_in_standby)
+    });
+    wait_for_bod33_sync(sysctrl);
+
+    sysctrl.bod33.modify(|_, w| w.enable().set_bit());
+    wait_for_bod33_sync(sysctrl);
+    while sysctrl.pclksr.read().bod33rdy().bit_is_clear() {}
+}
+
+/// Disables the BOD33 brown-out detector.  Note that the detector
+/// may also have been enabled by the user row fuses at reset.
+pub fn disable_bod33(sysctrl: &mut SYSCTRL) {
+    sysctrl.bod33.modify(|_, w| w.enable().clear_bit());
+    wait_for_bod33_sync(sysctrl);
+}
+
+/// Returns true if VDD is currently below the BOD33 threshold
+pub fn bod33_detected(sysctrl: &SYSCTRL) -> bool {
+    sysctrl.pclksr.read().bod33det().bit_is_set()
+}
+
+/// Enables the BOD33DET interrupt.  The detector must be configured
+/// with `BodAction::Interrupt` for the interrupt to be raised.
+/// This method only configures SYSCTRL to trigger the interrupt; it
+/// does not configure the interrupt controller or define an interrupt
+/// handler.
+pub fn enable_bod33_interrupt(sysctrl: &mut SYSCTRL) {
+    sysctrl.intenset.write(|w| w.bod33det().set_bit());
+}
+
+/// Disables the BOD33DET interrupt
+pub fn disable_bod33_interrupt(sysctrl: &mut SYSCTRL) {
+    sysctrl.intenclr.write(|w| w.bod33det().set_bit());
+}
+
+/// Clears the BOD33DET interrupt flag
+pub fn clear_bod33_interrupt(sysctrl: &mut SYSCTRL) {
+    // Writing a 1 clears the flag
+    sysctrl.intflag.write(|w| w.bod33det().set_bit());
+}
+
+/// Configures the behavior of the main voltage regulator in standby.
+/// By default the regulator switches to its low power mode in standby,
+/// which can only supply a limited current.  `run_in_standby` keeps it
+/// in normal mode, which is required if peripherals that draw more
+/// current are left running in standby.  `force_ldo` forces the
+/// regulator to remain in LDO mode rather than switching to its low
+/// power mode, trading standby current for faster wake up.
+pub fn configure_regulator(sysctrl: &mut SYSCTRL, run_in_standby: bool, force_ldo: bool) {
+    sysctrl.vreg.modify(|_, w| {
+        w.runstdby().bit(run_in_standby);
+        w.forceldo().bit(force_ldo)
+    });
+}
+
+/// Configures the VREF block.  `bandgap_output` routes the 1.1V bandgap
+/// reference to the ADC and AC, and `temperature_sensor` enables the
+/// internal temperature sensor so that it can be sampled by the ADC.
+/// The factory bandgap calibration is left untouched.
+pub fn configure_vref(sysctrl: &mut SYSCTRL, bandgap_output: bool, temperature_sensor: bool) {
+    sysctrl.vref.modify(|_, w| {
+        w.bgouten().bit(bandgap_output);
+        w.tsen().bit(temperature_sensor)
+    });
+}
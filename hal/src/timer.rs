@@ -3,6 +3,8 @@ use hal::timer::{CountDown, Periodic};
 use target_device::tc3::COUNT16;
 #[allow(unused)]
 use target_device::{TC3, TC4, TC5, PM};
+#[cfg(feature = "pins-64")]
+use target_device::{TC6, TC7};
 
 use clock;
 use nb;
@@ -181,3 +183,8 @@ tc! {
     TimerCounter4: (TC4, tc4_, Tc4Tc5Clock),
     TimerCounter5: (TC5, tc5_, Tc4Tc5Clock),
 }
+#[cfg(feature = "pins-64")]
+tc! {
+    TimerCounter6: (TC6, tc6_, Tc6Tc7Clock),
+    TimerCounter7: (TC7, tc7_, Tc6Tc7Clock),
+}
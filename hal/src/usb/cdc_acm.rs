@@ -0,0 +1,440 @@
+//! USB CDC-ACM virtual serial port.
+//!
+//! `CdcAcm` presents the device to the host as a serial port
+//! (`/dev/ttyACM*` on Linux, a COM port on Windows).  Received data and
+//! data waiting to be sent are held in small ring buffers, so `read` and
+//! `write` never wait on the bus themselves; the transfers are driven by
+//! `UsbDevice::poll`.  If `poll` is called from the USB interrupt handler,
+//! the application must access the class inside a critical section.
+//!
+//! The line coding set by the host is recorded but has no other effect.
+//! By convention, a host that opens the port at 1200 baud and then drops
+//! DTR is asking the device to reset into its bootloader; this is how the
+//! Arduino IDE and BOSSA upload new firmware.  `bootloader_requested`
+//! reports that request and `power::reset_into_bootloader` performs it.
+use core::cmp;
+use core::fmt;
+use hal::serial;
+use nb;
+
+use super::class::{ControlIn, ControlOut, UsbClass};
+use super::control::{Recipient, RequestKind, SetupPacket};
+use super::descriptor::DescriptorWriter;
+use super::endpoint::{EndpointAddress, EndpointIn, EndpointOut, EndpointType};
+use super::{UsbDevice, UsbError};
+
+const USB_CLASS_CDC: u8 = 0x02;
+const USB_CLASS_CDC_DATA: u8 = 0x0a;
+const CDC_SUBCLASS_ACM: u8 = 0x02;
+const CDC_PROTOCOL_NONE: u8 = 0x00;
+
+const CS_INTERFACE: u8 = 0x24;
+const CDC_TYPE_HEADER: u8 = 0x00;
+const CDC_TYPE_CALL_MANAGEMENT: u8 = 0x01;
+const CDC_TYPE_ACM: u8 = 0x02;
+const CDC_TYPE_UNION: u8 = 0x06;
+
+const REQ_SET_LINE_CODING: u8 = 0x20;
+const REQ_GET_LINE_CODING: u8 = 0x21;
+const REQ_SET_CONTROL_LINE_STATE: u8 = 0x22;
+const REQ_SEND_BREAK: u8 = 0x23;
+
+/// The baud rate that signals a request to reset into the bootloader
+const BOOTLOADER_TOUCH_BAUD: u32 = 1200;
+
+/// The packet size of the bulk data endpoints
+const PACKET_SIZE: usize = 64;
+
+/// The size of each of the receive and transmit buffers
+const BUFFER_SIZE: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopBits {
+    One,
+    OnePointFive,
+    Two,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Parity {
+    None,
+    Odd,
+    Even,
+    Mark,
+    Space,
+}
+
+/// The serial parameters selected by the host
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineCoding {
+    pub data_rate: u32,
+    pub stop_bits: StopBits,
+    pub parity: Parity,
+    pub data_bits: u8,
+}
+
+impl LineCoding {
+    fn parse(data: &[u8]) -> Self {
+        Self {
+            data_rate: u32::from(data[0])
+                | (u32::from(data[1]) << 8)
+                | (u32::from(data[2]) << 16)
+                | (u32::from(data[3]) << 24),
+            stop_bits: match data[4] {
+                1 => StopBits::OnePointFive,
+                2 => StopBits::Two,
+                _ => StopBits::One,
+            },
+            parity: match data[5] {
+                1 => Parity::Odd,
+                2 => Parity::Even,
+                3 => Parity::Mark,
+                4 => Parity::Space,
+                _ => Parity::None,
+            },
+            data_bits: data[6],
+        }
+    }
+
+    fn serialize(&self, buf: &mut [u8]) -> usize {
+        buf[0] = self.data_rate as u8;
+        buf[1] = (self.data_rate >> 8) as u8;
+        buf[2] = (self.data_rate >> 16) as u8;
+        buf[3] = (self.data_rate >> 24) as u8;
+        buf[4] = match self.stop_bits {
+            StopBits::One => 0,
+            StopBits::OnePointFive => 1,
+            StopBits::Two => 2,
+        };
+        buf[5] = match self.parity {
+            Parity::None => 0,
+            Parity::Odd => 1,
+            Parity::Even => 2,
+            Parity::Mark => 3,
+            Parity::Space => 4,
+        };
+        buf[6] = self.data_bits;
+        7
+    }
+}
+
+impl Default for LineCoding {
+    fn default() -> Self {
+        Self {
+            data_rate: 115_200,
+            stop_bits: StopBits::One,
+            parity: Parity::None,
+            data_bits: 8,
+        }
+    }
+}
+
+/// A fixed size byte queue
+struct RingBuffer {
+    buf: [u8; BUFFER_SIZE],
+    head: usize,
+    len: usize,
+}
+
+impl RingBuffer {
+    fn new() -> Self {
+        Self {
+            buf: [0; BUFFER_SIZE],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    fn space(&self) -> usize {
+        BUFFER_SIZE - self.len
+    }
+
+    fn push(&mut self, byte: u8) -> bool {
+        if self.len == BUFFER_SIZE {
+            return false;
+        }
+        self.buf[(self.head + self.len) % BUFFER_SIZE] = byte;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        let byte = self.buf[self.head];
+        self.head = (self.head + 1) % BUFFER_SIZE;
+        self.len -= 1;
+        Some(byte)
+    }
+
+    /// Copies queued bytes into `dest` without removing them
+    fn peek(&self, dest: &mut [u8]) -> usize {
+        let count = cmp::min(dest.len(), self.len);
+        for (i, byte) in dest[..count].iter_mut().enumerate() {
+            *byte = self.buf[(self.head + i) % BUFFER_SIZE];
+        }
+        count
+    }
+
+    fn consume(&mut self, count: usize) {
+        let count = cmp::min(count, self.len);
+        self.head = (self.head + count) % BUFFER_SIZE;
+        self.len -= count;
+    }
+}
+
+/// A CDC-ACM serial port
+pub struct CdcAcm<'a> {
+    comm_if: u8,
+    data_if: u8,
+    comm_ep: EndpointIn<'a>,
+    read_ep: EndpointOut<'a>,
+    write_ep: EndpointIn<'a>,
+    rx: RingBuffer,
+    tx: RingBuffer,
+    line_coding: LineCoding,
+    dtr: bool,
+    rts: bool,
+    configured: bool,
+    /// The last packet sent was full sized, so the host is waiting for
+    /// a short packet to know that the transfer has ended
+    need_zlp: bool,
+    bootloader_requested: bool,
+}
+
+impl<'a> CdcAcm<'a> {
+    /// Allocates the two interfaces and three endpoints of the class
+    pub fn new(usb: &mut UsbDevice<'a>) -> Result<Self, UsbError> {
+        Ok(Self {
+            comm_if: usb.alloc_interface(),
+            data_if: usb.alloc_interface(),
+            comm_ep: usb.alloc_endpoint_in(EndpointType::Interrupt, 8, 255)?,
+            read_ep: usb.alloc_endpoint_out(EndpointType::Bulk, PACKET_SIZE as u16, 0)?,
+            write_ep: usb.alloc_endpoint_in(EndpointType::Bulk, PACKET_SIZE as u16, 0)?,
+            rx: RingBuffer::new(),
+            tx: RingBuffer::new(),
+            line_coding: LineCoding::default(),
+            dtr: false,
+            rts: false,
+            configured: false,
+            need_zlp: false,
+            bootloader_requested: false,
+        })
+    }
+
+    pub fn line_coding(&self) -> LineCoding {
+        self.line_coding
+    }
+
+    /// Data Terminal Ready; set by most hosts while the port is open
+    pub fn dtr(&self) -> bool {
+        self.dtr
+    }
+
+    /// Request To Send
+    pub fn rts(&self) -> bool {
+        self.rts
+    }
+
+    /// Returns true if the port is configured and open on the host
+    pub fn is_connected(&self) -> bool {
+        self.configured && self.dtr
+    }
+
+    /// Returns true once the host has performed the 1200 baud touch
+    pub fn bootloader_requested(&self) -> bool {
+        self.bootloader_requested
+    }
+
+    /// Moves received packets into the receive buffer
+    /// for as long as there is room for a whole packet
+    fn fill_rx(&mut self) {
+        let mut packet = [0u8; PACKET_SIZE];
+        while self.rx.space() >= PACKET_SIZE {
+            match self.read_ep.read(&mut packet) {
+                Ok(count) => {
+                    for &byte in &packet[..count] {
+                        self.rx.push(byte);
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// Starts sending the next packet from the transmit buffer
+    /// if the endpoint is idle
+    fn flush_tx(&mut self) {
+        if !self.configured || self.write_ep.is_busy() {
+            return;
+        }
+        if self.tx.len == 0 && !self.need_zlp {
+            return;
+        }
+        let mut packet = [0u8; PACKET_SIZE];
+        let count = self.tx.peek(&mut packet);
+        if let Ok(count) = self.write_ep.write(&packet[..count]) {
+            self.tx.consume(count);
+            self.need_zlp = count == PACKET_SIZE;
+        }
+    }
+
+    fn is_comm_request(&self, req: &SetupPacket) -> bool {
+        req.kind() == RequestKind::Class
+            && req.recipient() == Recipient::Interface
+            && req.index == u16::from(self.comm_if)
+    }
+}
+
+impl<'a> UsbClass for CdcAcm<'a> {
+    fn reset(&mut self) {
+        self.rx.clear();
+        self.tx.clear();
+        self.line_coding = LineCoding::default();
+        self.dtr = false;
+        self.rts = false;
+        self.configured = false;
+        self.need_zlp = false;
+    }
+
+    fn configured(&mut self, configured: bool) {
+        self.configured = configured;
+    }
+
+    fn configuration_descriptors(&self, w: &mut DescriptorWriter) -> Result<(), UsbError> {
+        w.interface_association(
+            self.comm_if,
+            2,
+            USB_CLASS_CDC,
+            CDC_SUBCLASS_ACM,
+            CDC_PROTOCOL_NONE,
+        )?;
+
+        w.interface(
+            self.comm_if,
+            0,
+            USB_CLASS_CDC,
+            CDC_SUBCLASS_ACM,
+            CDC_PROTOCOL_NONE,
+            0,
+        )?;
+        // CDC 1.10
+        w.write(CS_INTERFACE, &[CDC_TYPE_HEADER, 0x10, 0x01])?;
+        // The device does not handle call management itself
+        w.write(CS_INTERFACE, &[CDC_TYPE_CALL_MANAGEMENT, 0x00, self.data_if])?;
+        // Supports the line coding and serial state requests
+        w.write(CS_INTERFACE, &[CDC_TYPE_ACM, 0x02])?;
+        w.write(CS_INTERFACE, &[CDC_TYPE_UNION, self.comm_if, self.data_if])?;
+        w.endpoint(&self.comm_ep.info())?;
+
+        w.interface(self.data_if, 0, USB_CLASS_CDC_DATA, 0, 0, 0)?;
+        w.endpoint(&self.write_ep.info())?;
+        w.endpoint(&self.read_ep.info())
+    }
+
+    fn control_in(&mut self, req: &SetupPacket, buf: &mut [u8]) -> ControlIn {
+        if !self.is_comm_request(req) {
+            return ControlIn::Ignored;
+        }
+        match req.request {
+            REQ_GET_LINE_CODING => ControlIn::Data(self.line_coding.serialize(buf)),
+            _ => ControlIn::Rejected,
+        }
+    }
+
+    fn control_out(&mut self, req: &SetupPacket, data: &[u8]) -> ControlOut {
+        if !self.is_comm_request(req) {
+            return ControlOut::Ignored;
+        }
+        match req.request {
+            REQ_SET_LINE_CODING if data.len() >= 7 => {
+                self.line_coding = LineCoding::parse(data);
+                ControlOut::Accepted
+            }
+            REQ_SET_CONTROL_LINE_STATE => {
+                self.dtr = req.value & 1 != 0;
+                self.rts = req.value & 2 != 0;
+                if !self.dtr && self.line_coding.data_rate == BOOTLOADER_TOUCH_BAUD {
+                    self.bootloader_requested = true;
+                }
+                ControlOut::Accepted
+            }
+            REQ_SEND_BREAK => ControlOut::Accepted,
+            _ => ControlOut::Rejected,
+        }
+    }
+
+    fn endpoint_out(&mut self, addr: EndpointAddress) {
+        if addr == self.read_ep.address() {
+            self.fill_rx();
+        }
+    }
+
+    fn endpoint_in_complete(&mut self, addr: EndpointAddress) {
+        if addr == self.write_ep.address() {
+            self.flush_tx();
+        }
+    }
+}
+
+impl<'a> serial::Read<u8> for CdcAcm<'a> {
+    type Error = UsbError;
+
+    fn read(&mut self) -> nb::Result<u8, UsbError> {
+        if let Some(byte) = self.rx.pop() {
+            return Ok(byte);
+        }
+        self.fill_rx();
+        self.rx.pop().ok_or(nb::Error::WouldBlock)
+    }
+}
+
+impl<'a> serial::Write<u8> for CdcAcm<'a> {
+    type Error = UsbError;
+
+    fn write(&mut self, byte: u8) -> nb::Result<(), UsbError> {
+        if !self.tx.push(byte) {
+            self.flush_tx();
+            return Err(nb::Error::WouldBlock);
+        }
+        self.flush_tx();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> nb::Result<(), UsbError> {
+        self.flush_tx();
+        if self.tx.len == 0 && !self.need_zlp && !self.write_ep.is_busy() {
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+/// Output is discarded while the port is not open on the host, so
+/// that logging does not stall the application when nobody is
+/// listening.  Otherwise this waits for room in the transmit buffer,
+/// which requires `UsbDevice::poll` to be called from an interrupt.
+impl<'a> fmt::Write for CdcAcm<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &byte in s.as_bytes() {
+            loop {
+                if !self.is_connected() {
+                    return Ok(());
+                }
+                match serial::Write::write(self, byte) {
+                    Ok(()) => break,
+                    Err(nb::Error::WouldBlock) => {}
+                    Err(nb::Error::Other(_)) => return Err(fmt::Error),
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+//! The extension point for USB device classes.
+//!
+//! A class allocates its interfaces and endpoints from the `UsbDevice`
+//! when it is constructed, then is passed to `UsbDevice::poll` alongside
+//! any other classes that make up the device.  The device handles the
+//! standard requests itself and offers everything else to each class in
+//! turn.
+use super::control::SetupPacket;
+use super::descriptor::DescriptorWriter;
+use super::endpoint::EndpointAddress;
+use super::UsbError;
+
+/// The outcome of offering a control request with an IN data stage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ControlIn {
+    /// The request is not for this class
+    Ignored,
+    /// The request was handled and this many bytes of the buffer
+    /// should be returned to the host
+    Data(usize),
+    /// The request is for this class but is not supported;
+    /// the control pipe will be stalled
+    Rejected,
+}
+
+/// The outcome of offering a control request with an OUT
+/// or no data stage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ControlOut {
+    /// The request is not for this class
+    Ignored,
+    /// The request was handled
+    Accepted,
+    /// The request is for this class but is not supported;
+    /// the control pipe will be stalled
+    Rejected,
+}
+
+/// Implemented by USB device classes.  All of the methods have
+/// default implementations that do nothing.
+pub trait UsbClass {
+    /// Called when the host resets the bus.  Any data that was
+    /// in flight has been lost.
+    fn reset(&mut self) {}
+
+    /// Called when the host selects or deselects the configuration
+    fn configured(&mut self, _configured: bool) {}
+
+    /// Appends the interface, endpoint and class specific descriptors
+    /// of the class to the configuration descriptor
+    fn configuration_descriptors(&self, _writer: &mut DescriptorWriter) -> Result<(), UsbError> {
+        Ok(())
+    }
+
+    /// Returns the string for a string descriptor index
+    /// that the class uses in its descriptors
+    fn string(&self, _index: u8) -> Option<&str> {
+        None
+    }
+
+    /// Offers a control request with an IN data stage.  The response
+    /// is written into `buf`, which holds at least 64 bytes.
+    fn control_in(&mut self, _req: &SetupPacket, _buf: &mut [u8]) -> ControlIn {
+        ControlIn::Ignored
+    }
+
+    /// Offers a control request with an OUT or no data stage.
+    /// `data` holds the data stage, if any.
+    fn control_out(&mut self, _req: &SetupPacket, _data: &[u8]) -> ControlOut {
+        ControlOut::Ignored
+    }
+
+    /// Called when a packet has been received on an OUT endpoint.
+    /// The packet remains in the endpoint until it is read.
+    fn endpoint_out(&mut self, _addr: EndpointAddress) {}
+
+    /// Called when the host has collected the packet written
+    /// to an IN endpoint
+    fn endpoint_in_complete(&mut self, _addr: EndpointAddress) {}
+}
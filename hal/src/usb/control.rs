@@ -0,0 +1,167 @@
+//! Control transfer requests.
+//!
+//! Every control transfer begins with an 8 byte SETUP packet sent by the
+//! host to endpoint 0.  This module decodes that packet and names the
+//! standard requests defined in chapter 9 of the USB 2.0 specification.
+//! The host driver uses the same type to build the requests it sends.
+
+/// The direction of the data stage of a control transfer,
+/// from the perspective of the host
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// Host to device
+    Out,
+    /// Device to host
+    In,
+}
+
+/// Who defines the meaning of a request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RequestKind {
+    Standard,
+    Class,
+    Vendor,
+    Reserved,
+}
+
+/// What a request is addressed to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Recipient {
+    Device,
+    Interface,
+    Endpoint,
+    Other,
+    Reserved,
+}
+
+/// The standard request codes (bRequest)
+pub mod request {
+    pub const GET_STATUS: u8 = 0;
+    pub const CLEAR_FEATURE: u8 = 1;
+    pub const SET_FEATURE: u8 = 3;
+    pub const SET_ADDRESS: u8 = 5;
+    pub const GET_DESCRIPTOR: u8 = 6;
+    pub const SET_DESCRIPTOR: u8 = 7;
+    pub const GET_CONFIGURATION: u8 = 8;
+    pub const SET_CONFIGURATION: u8 = 9;
+    pub const GET_INTERFACE: u8 = 10;
+    pub const SET_INTERFACE: u8 = 11;
+    pub const SYNCH_FRAME: u8 = 12;
+}
+
+/// The standard feature selectors used with SET_FEATURE/CLEAR_FEATURE
+pub mod feature {
+    pub const ENDPOINT_HALT: u16 = 0;
+    pub const DEVICE_REMOTE_WAKEUP: u16 = 1;
+    pub const TEST_MODE: u16 = 2;
+}
+
+/// A decoded SETUP packet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetupPacket {
+    pub request_type: u8,
+    pub request: u8,
+    pub value: u16,
+    pub index: u16,
+    pub length: u16,
+}
+
+impl SetupPacket {
+    /// Builds a packet for the host to send
+    pub fn new(
+        direction: Direction,
+        kind: RequestKind,
+        recipient: Recipient,
+        request: u8,
+        value: u16,
+        index: u16,
+        length: u16,
+    ) -> Self {
+        let direction = match direction {
+            Direction::Out => 0,
+            Direction::In => 0x80,
+        };
+        let kind = match kind {
+            RequestKind::Standard => 0,
+            RequestKind::Class => 1,
+            RequestKind::Vendor => 2,
+            RequestKind::Reserved => 3,
+        };
+        let recipient = match recipient {
+            Recipient::Device => 0,
+            Recipient::Interface => 1,
+            Recipient::Endpoint => 2,
+            Recipient::Other => 3,
+            Recipient::Reserved => 31,
+        };
+        Self {
+            request_type: direction | (kind << 5) | recipient,
+            request,
+            value,
+            index,
+            length,
+        }
+    }
+
+    /// Decodes the little endian wire format of a SETUP packet
+    pub fn parse(buf: &[u8; 8]) -> Self {
+        Self {
+            request_type: buf[0],
+            request: buf[1],
+            value: u16::from(buf[2]) | (u16::from(buf[3]) << 8),
+            index: u16::from(buf[4]) | (u16::from(buf[5]) << 8),
+            length: u16::from(buf[6]) | (u16::from(buf[7]) << 8),
+        }
+    }
+
+    /// Encodes the packet in its little endian wire format
+    pub fn to_bytes(&self) -> [u8; 8] {
+        [
+            self.request_type,
+            self.request,
+            self.value as u8,
+            (self.value >> 8) as u8,
+            self.index as u8,
+            (self.index >> 8) as u8,
+            self.length as u8,
+            (self.length >> 8) as u8,
+        ]
+    }
+
+    pub fn direction(&self) -> Direction {
+        if self.request_type & 0x80 != 0 {
+            Direction::In
+        } else {
+            Direction::Out
+        }
+    }
+
+    pub fn kind(&self) -> RequestKind {
+        match (self.request_type >> 5) & 0b11 {
+            0 => RequestKind::Standard,
+            1 => RequestKind::Class,
+            2 => RequestKind::Vendor,
+            _ => RequestKind::Reserved,
+        }
+    }
+
+    pub fn recipient(&self) -> Recipient {
+        match self.request_type & 0b1_1111 {
+            0 => Recipient::Device,
+            1 => Recipient::Interface,
+            2 => Recipient::Endpoint,
+            3 => Recipient::Other,
+            _ => Recipient::Reserved,
+        }
+    }
+
+    /// The high byte of wValue; the descriptor type for GET_DESCRIPTOR
+    pub fn value_high(&self) -> u8 {
+        (self.value >> 8) as u8
+    }
+
+    /// The low byte of wValue; the descriptor index for GET_DESCRIPTOR
+    pub fn value_low(&self) -> u8 {
+        self.value as u8
+    }
+}
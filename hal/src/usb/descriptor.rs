@@ -0,0 +1,183 @@
+//! Building USB descriptors.
+//!
+//! The device and configuration descriptors are generated on demand when
+//! the host asks for them; the configuration descriptor is assembled from
+//! the interface and endpoint descriptors contributed by each class.
+use super::endpoint::EndpointInfo;
+use super::UsbError;
+
+/// The standard descriptor types
+pub mod descriptor_type {
+    pub const DEVICE: u8 = 1;
+    pub const CONFIGURATION: u8 = 2;
+    pub const STRING: u8 = 3;
+    pub const INTERFACE: u8 = 4;
+    pub const ENDPOINT: u8 = 5;
+    pub const DEVICE_QUALIFIER: u8 = 6;
+    pub const INTERFACE_ASSOCIATION: u8 = 11;
+    pub const BOS: u8 = 15;
+    pub const DEVICE_CAPABILITY: u8 = 16;
+}
+
+/// The device capability types used in the BOS descriptor
+pub mod capability_type {
+    pub const USB_2_0_EXTENSION: u8 = 2;
+}
+
+/// The language ID reported in string descriptor 0 (English, US)
+pub const LANGUAGE_ID_EN_US: u16 = 0x0409;
+
+/// The string descriptor indices used for the device strings.
+/// Classes that provide their own strings should use indices
+/// from `FIRST_CLASS_STRING` upwards.
+pub const MANUFACTURER_STRING: u8 = 1;
+pub const PRODUCT_STRING: u8 = 2;
+pub const SERIAL_NUMBER_STRING: u8 = 3;
+pub const FIRST_CLASS_STRING: u8 = 4;
+
+/// Describes the device to the host
+#[derive(Debug, Clone, Copy)]
+pub struct UsbDeviceInfo<'a> {
+    pub vendor_id: u16,
+    pub product_id: u16,
+    /// The device release number in binary coded decimal
+    pub device_release: u16,
+    /// Use 0 to have each interface specify its own class, or
+    /// 0xef/2/1 for composite devices that use interface associations
+    pub device_class: u8,
+    pub device_sub_class: u8,
+    pub device_protocol: u8,
+    pub manufacturer: &'a str,
+    pub product: &'a str,
+    /// `calibration::SerialNumber::to_hex` provides a string
+    /// that is unique to the device
+    pub serial_number: Option<&'a str>,
+    /// The maximum current drawn from the bus, in milliamps
+    pub max_power_ma: u16,
+    pub self_powered: bool,
+    /// Whether the device can signal remote wakeup
+    pub remote_wakeup: bool,
+    /// Whether the device accepts Link Power Management requests
+    /// to enter L1 sleep.  This advertises USB 2.01 and a BOS
+    /// descriptor to the host.
+    pub link_power_management: bool,
+}
+
+impl<'a> UsbDeviceInfo<'a> {
+    /// Returns a description with the given IDs and strings, bus powered
+    /// at 100mA and without remote wakeup or link power management
+    pub fn new(vendor_id: u16, product_id: u16, manufacturer: &'a str, product: &'a str) -> Self {
+        Self {
+            vendor_id,
+            product_id,
+            device_release: 0x0100,
+            device_class: 0,
+            device_sub_class: 0,
+            device_protocol: 0,
+            manufacturer,
+            product,
+            serial_number: None,
+            max_power_ma: 100,
+            self_powered: false,
+            remote_wakeup: false,
+            link_power_management: false,
+        }
+    }
+}
+
+/// Appends descriptors to a buffer
+pub struct DescriptorWriter<'b> {
+    buf: &'b mut [u8],
+    pos: usize,
+    num_endpoints_pos: Option<usize>,
+}
+
+impl<'b> DescriptorWriter<'b> {
+    pub fn new(buf: &'b mut [u8]) -> Self {
+        Self {
+            buf,
+            pos: 0,
+            num_endpoints_pos: None,
+        }
+    }
+
+    /// The number of bytes written so far
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Writes a descriptor with the given type; the length field
+    /// is computed from the size of `body`
+    pub fn write(&mut self, descriptor_type: u8, body: &[u8]) -> Result<(), UsbError> {
+        let len = body.len() + 2;
+        if len > 255 || self.pos + len > self.buf.len() {
+            return Err(UsbError::BufferOverflow);
+        }
+        self.buf[self.pos] = len as u8;
+        self.buf[self.pos + 1] = descriptor_type;
+        self.buf[self.pos + 2..self.pos + len].copy_from_slice(body);
+        self.pos += len;
+        Ok(())
+    }
+
+    /// Writes an interface descriptor.  The number of endpoints
+    /// is filled in as endpoint descriptors are written.
+    pub fn interface(
+        &mut self,
+        number: u8,
+        alternate_setting: u8,
+        class: u8,
+        sub_class: u8,
+        protocol: u8,
+        string_index: u8,
+    ) -> Result<(), UsbError> {
+        self.write(
+            descriptor_type::INTERFACE,
+            &[
+                number,
+                alternate_setting,
+                0,
+                class,
+                sub_class,
+                protocol,
+                string_index,
+            ],
+        )?;
+        self.num_endpoints_pos = Some(self.pos - 5);
+        Ok(())
+    }
+
+    /// Writes an endpoint descriptor
+    pub fn endpoint(&mut self, ep: &EndpointInfo) -> Result<(), UsbError> {
+        self.write(
+            descriptor_type::ENDPOINT,
+            &[
+                ep.address.0,
+                ep.ep_type.attributes(),
+                ep.max_packet_size as u8,
+                (ep.max_packet_size >> 8) as u8,
+                ep.interval,
+            ],
+        )?;
+        if let Some(pos) = self.num_endpoints_pos {
+            self.buf[pos] += 1;
+        }
+        Ok(())
+    }
+
+    /// Writes an interface association descriptor, which groups the
+    /// interfaces of a function in a composite device
+    pub fn interface_association(
+        &mut self,
+        first_interface: u8,
+        interface_count: u8,
+        class: u8,
+        sub_class: u8,
+        protocol: u8,
+    ) -> Result<(), UsbError> {
+        self.write(
+            descriptor_type::INTERFACE_ASSOCIATION,
+            &[first_interface, interface_count, class, sub_class, protocol, 0],
+        )
+    }
+}
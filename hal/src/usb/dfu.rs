@@ -0,0 +1,139 @@
+//! USB Device Firmware Upgrade runtime interface.
+//!
+//! `DfuRuntime` advertises that the application can be switched into a
+//! firmware upgrade mode.  When a host tool sends DFU_DETACH the class
+//! records the request; the application then calls `detach_if_requested`
+//! (or `power::reset_into_bootloader` itself, after any cleanup) to reset
+//! into the bootloader, which receives the new image.  The device detaches
+//! from the bus itself, so the host does not need to issue a bus reset.
+use cortex_m::peripheral::SCB;
+
+use super::class::{ControlIn, ControlOut, UsbClass};
+use super::control::{Recipient, RequestKind, SetupPacket};
+use super::descriptor::DescriptorWriter;
+use super::{UsbDevice, UsbError};
+use power;
+
+const USB_CLASS_APPLICATION_SPECIFIC: u8 = 0xfe;
+const DFU_SUBCLASS: u8 = 0x01;
+const DFU_PROTOCOL_RUNTIME: u8 = 0x01;
+
+const DFU_DESCRIPTOR_FUNCTIONAL: u8 = 0x21;
+
+const DFU_REQ_DETACH: u8 = 0x00;
+const DFU_REQ_GETSTATUS: u8 = 0x03;
+const DFU_REQ_GETSTATE: u8 = 0x05;
+
+/// bmAttributes: the device detaches itself after DFU_DETACH
+/// and can download new firmware
+const DFU_WILL_DETACH: u8 = 1 << 3;
+const DFU_CAN_DNLOAD: u8 = 1 << 0;
+
+/// The runtime states from the DFU 1.1 specification
+const DFU_STATE_APP_IDLE: u8 = 0;
+const DFU_STATE_APP_DETACH: u8 = 1;
+
+/// The time the host should allow for the detach, in milliseconds
+const DETACH_TIMEOUT_MS: u16 = 1000;
+
+/// The largest block the bootloader accepts per DFU_DNLOAD
+const TRANSFER_SIZE: u16 = 64;
+
+/// A DFU runtime interface
+pub struct DfuRuntime {
+    interface: u8,
+    detach_requested: bool,
+}
+
+impl DfuRuntime {
+    pub fn new(usb: &mut UsbDevice) -> Result<Self, UsbError> {
+        Ok(Self {
+            interface: usb.alloc_interface(),
+            detach_requested: false,
+        })
+    }
+
+    /// Returns true once the host has sent DFU_DETACH
+    pub fn detach_requested(&self) -> bool {
+        self.detach_requested
+    }
+
+    /// Resets into the bootloader if the host has sent DFU_DETACH.
+    /// This should be called from the main loop rather than the USB
+    /// interrupt, so that the status stage of the request completes.
+    pub fn detach_if_requested(&self, scb: &mut SCB) {
+        if self.detach_requested {
+            power::reset_into_bootloader(scb);
+        }
+    }
+
+    fn is_for_interface(&self, req: &SetupPacket) -> bool {
+        req.kind() == RequestKind::Class
+            && req.recipient() == Recipient::Interface
+            && req.index == u16::from(self.interface)
+    }
+
+    fn state(&self) -> u8 {
+        if self.detach_requested {
+            DFU_STATE_APP_DETACH
+        } else {
+            DFU_STATE_APP_IDLE
+        }
+    }
+}
+
+impl UsbClass for DfuRuntime {
+    fn configuration_descriptors(&self, w: &mut DescriptorWriter) -> Result<(), UsbError> {
+        w.interface(
+            self.interface,
+            0,
+            USB_CLASS_APPLICATION_SPECIFIC,
+            DFU_SUBCLASS,
+            DFU_PROTOCOL_RUNTIME,
+            0,
+        )?;
+        w.write(
+            DFU_DESCRIPTOR_FUNCTIONAL,
+            &[
+                DFU_WILL_DETACH | DFU_CAN_DNLOAD,
+                DETACH_TIMEOUT_MS as u8,
+                (DETACH_TIMEOUT_MS >> 8) as u8,
+                TRANSFER_SIZE as u8,
+                (TRANSFER_SIZE >> 8) as u8,
+                0x10,
+                0x01, // bcdDFUVersion 1.10
+            ],
+        )
+    }
+
+    fn control_in(&mut self, req: &SetupPacket, buf: &mut [u8]) -> ControlIn {
+        if !self.is_for_interface(req) {
+            return ControlIn::Ignored;
+        }
+        match req.request {
+            DFU_REQ_GETSTATUS => {
+                // bStatus OK, bwPollTimeout 0, bState, iString 0
+                buf[..6].copy_from_slice(&[0, 0, 0, 0, self.state(), 0]);
+                ControlIn::Data(6)
+            }
+            DFU_REQ_GETSTATE => {
+                buf[0] = self.state();
+                ControlIn::Data(1)
+            }
+            _ => ControlIn::Rejected,
+        }
+    }
+
+    fn control_out(&mut self, req: &SetupPacket, _data: &[u8]) -> ControlOut {
+        if !self.is_for_interface(req) {
+            return ControlOut::Ignored;
+        }
+        match req.request {
+            DFU_REQ_DETACH => {
+                self.detach_requested = true;
+                ControlOut::Accepted
+            }
+            _ => ControlOut::Rejected,
+        }
+    }
+}
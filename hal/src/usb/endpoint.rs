@@ -0,0 +1,287 @@
+//! Endpoint handles.
+//!
+//! Endpoints other than endpoint 0 are allocated from the `UsbDevice`
+//! before the device is attached.  Each allocation returns a handle that
+//! owns one bank of one endpoint; bank 0 serves the OUT direction and
+//! bank 1 the IN direction, so an endpoint number can be used for both an
+//! IN and an OUT endpoint at the same time.  The handles access only the
+//! registers and descriptor bank of their own endpoint, which allows them
+//! to be moved into class drivers while the `UsbDevice` keeps servicing
+//! the bus.
+use core::cmp;
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{self, Ordering};
+use nb;
+
+use super::{endpoint_registers, DeviceDescBank, UsbError};
+
+/// The transfer type of an endpoint
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndpointType {
+    Control,
+    Isochronous,
+    Bulk,
+    Interrupt,
+}
+
+impl EndpointType {
+    /// The EPCFG.EPTYPEn encoding
+    pub(super) fn eptype(&self) -> u8 {
+        match *self {
+            EndpointType::Control => 1,
+            EndpointType::Isochronous => 2,
+            EndpointType::Bulk => 3,
+            EndpointType::Interrupt => 4,
+        }
+    }
+
+    /// The bmAttributes encoding used in endpoint descriptors
+    pub fn attributes(&self) -> u8 {
+        match *self {
+            EndpointType::Control => 0,
+            EndpointType::Isochronous => 1,
+            EndpointType::Bulk => 2,
+            EndpointType::Interrupt => 3,
+        }
+    }
+}
+
+/// An endpoint number combined with its direction; bit 7 is set
+/// for IN endpoints, as in the bEndpointAddress descriptor field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EndpointAddress(pub u8);
+
+impl EndpointAddress {
+    pub fn new_in(index: u8) -> Self {
+        EndpointAddress(0x80 | (index & 0xf))
+    }
+
+    pub fn new_out(index: u8) -> Self {
+        EndpointAddress(index & 0xf)
+    }
+
+    /// The endpoint number
+    pub fn index(&self) -> u8 {
+        self.0 & 0xf
+    }
+
+    pub fn is_in(&self) -> bool {
+        self.0 & 0x80 != 0
+    }
+
+    /// The descriptor bank used by this direction
+    pub(super) fn bank(&self) -> usize {
+        if self.is_in() {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// Everything needed to describe an endpoint in a descriptor
+#[derive(Debug, Clone, Copy)]
+pub struct EndpointInfo {
+    pub address: EndpointAddress,
+    pub ep_type: EndpointType,
+    pub max_packet_size: u16,
+    /// The polling interval in frames for interrupt and
+    /// isochronous endpoints; ignored for bulk endpoints
+    pub interval: u8,
+}
+
+/// Returns the EPSTATUS.STALLRQn state for an endpoint
+pub(super) fn is_stalled(address: EndpointAddress) -> bool {
+    let regs = endpoint_registers(address.index());
+    let status = regs.epstatus.read();
+    if address.is_in() {
+        status.stallrq1().bit_is_set()
+    } else {
+        status.stallrq0().bit_is_set()
+    }
+}
+
+/// Sets or clears the STALL handshake for an endpoint.  Clearing the
+/// halt also resets the data toggle, as required by CLEAR_FEATURE.
+pub(super) fn set_stalled(address: EndpointAddress, stalled: bool) {
+    let regs = endpoint_registers(address.index());
+    match (address.is_in(), stalled) {
+        (true, true) => regs.epstatusset.write(|w| w.stallrq1().set_bit()),
+        (false, true) => regs.epstatusset.write(|w| w.stallrq0().set_bit()),
+        (true, false) => regs.epstatusclr.write(|w| {
+            w.stallrq1().set_bit();
+            w.dtglin().set_bit()
+        }),
+        (false, false) => regs.epstatusclr.write(|w| {
+            w.stallrq0().set_bit();
+            w.dtglout().set_bit()
+        }),
+    }
+}
+
+/// A handle to an allocated IN endpoint
+pub struct EndpointIn<'a> {
+    info: EndpointInfo,
+    bank: *mut DeviceDescBank,
+    buf: *mut u8,
+    _memory: PhantomData<&'a mut [u32]>,
+}
+
+impl<'a> EndpointIn<'a> {
+    pub(super) fn new(info: EndpointInfo, bank: *mut DeviceDescBank, buf: *mut u8) -> Self {
+        Self {
+            info,
+            bank,
+            buf,
+            _memory: PhantomData,
+        }
+    }
+
+    pub fn info(&self) -> EndpointInfo {
+        self.info
+    }
+
+    pub fn address(&self) -> EndpointAddress {
+        self.info.address
+    }
+
+    pub fn max_packet_size(&self) -> u16 {
+        self.info.max_packet_size
+    }
+
+    /// Returns true if a previously written packet has not yet been
+    /// collected by the host
+    pub fn is_busy(&self) -> bool {
+        endpoint_registers(self.info.address.index())
+            .epstatus
+            .read()
+            .bk1rdy()
+            .bit_is_set()
+    }
+
+    /// Queues a single packet to be sent on the next IN token.
+    /// Returns `WouldBlock` while the previous packet is still pending.
+    /// An empty slice sends a zero length packet.
+    pub fn write(&mut self, data: &[u8]) -> nb::Result<usize, UsbError> {
+        if data.len() > self.info.max_packet_size as usize {
+            return Err(nb::Error::Other(UsbError::BufferOverflow));
+        }
+        if self.is_busy() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), self.buf, data.len());
+            (*self.bank).set_multi_packet_size(0);
+            (*self.bank).set_byte_count(data.len() as u16);
+        }
+        // The buffer must be populated before the hardware is told
+        // that the bank is ready
+        atomic::compiler_fence(Ordering::SeqCst);
+        endpoint_registers(self.info.address.index())
+            .epstatusset
+            .write(|w| w.bk1rdy().set_bit());
+        Ok(data.len())
+    }
+
+    /// Sets or clears the halt condition of the endpoint
+    pub fn set_stalled(&mut self, stalled: bool) {
+        set_stalled(self.info.address, stalled);
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        is_stalled(self.info.address)
+    }
+}
+
+/// A handle to an allocated OUT endpoint
+pub struct EndpointOut<'a> {
+    info: EndpointInfo,
+    bank: *mut DeviceDescBank,
+    buf: *mut u8,
+    _memory: PhantomData<&'a mut [u32]>,
+}
+
+impl<'a> EndpointOut<'a> {
+    pub(super) fn new(info: EndpointInfo, bank: *mut DeviceDescBank, buf: *mut u8) -> Self {
+        Self {
+            info,
+            bank,
+            buf,
+            _memory: PhantomData,
+        }
+    }
+
+    pub fn info(&self) -> EndpointInfo {
+        self.info
+    }
+
+    pub fn address(&self) -> EndpointAddress {
+        self.info.address
+    }
+
+    pub fn max_packet_size(&self) -> u16 {
+        self.info.max_packet_size
+    }
+
+    /// Returns true if a received packet is waiting to be read
+    pub fn is_ready(&self) -> bool {
+        endpoint_registers(self.info.address.index())
+            .epstatus
+            .read()
+            .bk0rdy()
+            .bit_is_set()
+    }
+
+    /// Returns the size of the packet waiting to be read, if any
+    pub fn pending_len(&self) -> Option<usize> {
+        if self.is_ready() {
+            Some(unsafe { (*self.bank).byte_count() } as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Reads a single received packet into `buf` and makes the bank
+    /// available to the host again.  Returns `WouldBlock` if no packet
+    /// has been received.  If `buf` is too small the packet is left in
+    /// place and `BufferOverflow` is returned.
+    pub fn read(&mut self, buf: &mut [u8]) -> nb::Result<usize, UsbError> {
+        let len = match self.pending_len() {
+            Some(len) => cmp::min(len, self.info.max_packet_size as usize),
+            None => return Err(nb::Error::WouldBlock),
+        };
+        if len > buf.len() {
+            return Err(nb::Error::Other(UsbError::BufferOverflow));
+        }
+
+        atomic::compiler_fence(Ordering::SeqCst);
+        unsafe {
+            ptr::copy_nonoverlapping(self.buf, buf.as_mut_ptr(), len);
+        }
+        self.release();
+        Ok(len)
+    }
+
+    /// Discards any received packet and makes the bank available
+    /// to the host again
+    pub fn release(&mut self) {
+        unsafe {
+            (*self.bank).set_byte_count(0);
+            (*self.bank).set_multi_packet_size(self.info.max_packet_size);
+        }
+        endpoint_registers(self.info.address.index())
+            .epstatusclr
+            .write(|w| w.bk0rdy().set_bit());
+    }
+
+    /// Sets or clears the halt condition of the endpoint
+    pub fn set_stalled(&mut self, stalled: bool) {
+        set_stalled(self.info.address, stalled);
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        is_stalled(self.info.address)
+    }
+}
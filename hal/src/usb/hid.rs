@@ -0,0 +1,584 @@
+//! USB Human Interface Device class.
+//!
+//! `HidClass` implements a HID interface with an interrupt IN endpoint
+//! for input reports and an interrupt OUT endpoint for output reports.
+//! The reports are described to the host by a report descriptor, which
+//! can be written by hand or assembled with `ReportDescriptorWriter`.
+//!
+//! `HidClass::boot_keyboard` and `HidClass::boot_mouse` create interfaces
+//! that also support the boot protocol, so that they work in BIOS setup
+//! screens; `KeyboardReport` and `MouseReport` produce their reports.
+use core::cmp;
+use nb;
+
+use super::class::{ControlIn, ControlOut, UsbClass};
+use super::control::{request, Recipient, RequestKind, SetupPacket};
+use super::descriptor::DescriptorWriter;
+use super::endpoint::{EndpointIn, EndpointOut, EndpointType};
+use super::{UsbDevice, UsbError};
+
+pub(super) const USB_CLASS_HID: u8 = 0x03;
+pub(super) const HID_SUBCLASS_BOOT: u8 = 0x01;
+
+const HID_DESCRIPTOR_HID: u8 = 0x21;
+const HID_DESCRIPTOR_REPORT: u8 = 0x22;
+
+const HID_REQ_GET_REPORT: u8 = 0x01;
+const HID_REQ_GET_IDLE: u8 = 0x02;
+const HID_REQ_GET_PROTOCOL: u8 = 0x03;
+pub(super) const HID_REQ_SET_REPORT: u8 = 0x09;
+pub(super) const HID_REQ_SET_IDLE: u8 = 0x0a;
+pub(super) const HID_REQ_SET_PROTOCOL: u8 = 0x0b;
+
+/// The largest report supported; also the endpoint packet size
+const MAX_REPORT_SIZE: usize = 64;
+
+/// The boot interface protocol advertised in the interface descriptor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BootInterface {
+    None,
+    Keyboard,
+    Mouse,
+}
+
+/// The protocol selected by the host with SET_PROTOCOL
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Protocol {
+    Boot,
+    Report,
+}
+
+/// The flags for `input`, `output` and `feature` main items.
+/// Combine them with `|`; the zero valued flags are the defaults.
+pub mod item_flags {
+    pub const DATA: u8 = 0;
+    pub const CONSTANT: u8 = 1 << 0;
+    pub const ARRAY: u8 = 0;
+    pub const VARIABLE: u8 = 1 << 1;
+    pub const ABSOLUTE: u8 = 0;
+    pub const RELATIVE: u8 = 1 << 2;
+    pub const WRAP: u8 = 1 << 3;
+    pub const NON_LINEAR: u8 = 1 << 4;
+    pub const NO_PREFERRED: u8 = 1 << 5;
+    pub const NULL_STATE: u8 = 1 << 6;
+    pub const VOLATILE: u8 = 1 << 7;
+}
+
+/// The collection types for `ReportDescriptorWriter::collection`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Collection {
+    Physical = 0,
+    Application = 1,
+    Logical = 2,
+    Report = 3,
+}
+
+/// Assembles a report descriptor from items.  The methods can be
+/// chained; an overflow of the buffer is reported by `finish`.
+pub struct ReportDescriptorWriter<'b> {
+    buf: &'b mut [u8],
+    pos: usize,
+    overflow: bool,
+}
+
+impl<'b> ReportDescriptorWriter<'b> {
+    pub fn new(buf: &'b mut [u8]) -> Self {
+        Self {
+            buf,
+            pos: 0,
+            overflow: false,
+        }
+    }
+
+    /// Returns the length of the descriptor
+    pub fn finish(&self) -> Result<usize, UsbError> {
+        if self.overflow {
+            Err(UsbError::BufferOverflow)
+        } else {
+            Ok(self.pos)
+        }
+    }
+
+    /// Writes a short item with the smallest data size that holds `data`.
+    /// `prefix` holds the tag and type bits of the item.
+    fn item(&mut self, prefix: u8, data: u32, size: usize) -> &mut Self {
+        if self.pos + 1 + size > self.buf.len() {
+            self.overflow = true;
+            return self;
+        }
+        let size_code = match size {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            _ => 3,
+        };
+        self.buf[self.pos] = prefix | size_code;
+        for i in 0..size {
+            self.buf[self.pos + 1 + i] = (data >> (8 * i)) as u8;
+        }
+        self.pos += 1 + size;
+        self
+    }
+
+    fn unsigned_item(&mut self, prefix: u8, data: u32) -> &mut Self {
+        let size = if data <= 0xff {
+            1
+        } else if data <= 0xffff {
+            2
+        } else {
+            4
+        };
+        self.item(prefix, data, size)
+    }
+
+    fn signed_item(&mut self, prefix: u8, data: i32) -> &mut Self {
+        let size = if data >= -0x80 && data <= 0x7f {
+            1
+        } else if data >= -0x8000 && data <= 0x7fff {
+            2
+        } else {
+            4
+        };
+        self.item(prefix, data as u32, size)
+    }
+
+    pub fn input(&mut self, flags: u8) -> &mut Self {
+        self.item(0x80, u32::from(flags), 1)
+    }
+
+    pub fn output(&mut self, flags: u8) -> &mut Self {
+        self.item(0x90, u32::from(flags), 1)
+    }
+
+    pub fn feature(&mut self, flags: u8) -> &mut Self {
+        self.item(0xb0, u32::from(flags), 1)
+    }
+
+    pub fn collection(&mut self, collection: Collection) -> &mut Self {
+        self.item(0xa0, collection as u32, 1)
+    }
+
+    pub fn end_collection(&mut self) -> &mut Self {
+        self.item(0xc0, 0, 0)
+    }
+
+    pub fn usage_page(&mut self, page: u16) -> &mut Self {
+        self.unsigned_item(0x04, u32::from(page))
+    }
+
+    pub fn logical_minimum(&mut self, value: i32) -> &mut Self {
+        self.signed_item(0x14, value)
+    }
+
+    pub fn logical_maximum(&mut self, value: i32) -> &mut Self {
+        self.signed_item(0x24, value)
+    }
+
+    /// The size of each report field, in bits
+    pub fn report_size(&mut self, bits: u8) -> &mut Self {
+        self.unsigned_item(0x74, u32::from(bits))
+    }
+
+    /// Prefixes the reports that follow with this ID.  A descriptor
+    /// that uses report IDs must use them for every report.
+    pub fn report_id(&mut self, id: u8) -> &mut Self {
+        self.unsigned_item(0x84, u32::from(id))
+    }
+
+    /// The number of fields in the main item that follows
+    pub fn report_count(&mut self, count: u8) -> &mut Self {
+        self.unsigned_item(0x94, u32::from(count))
+    }
+
+    pub fn usage(&mut self, usage: u16) -> &mut Self {
+        self.unsigned_item(0x08, u32::from(usage))
+    }
+
+    pub fn usage_minimum(&mut self, usage: u16) -> &mut Self {
+        self.unsigned_item(0x18, u32::from(usage))
+    }
+
+    pub fn usage_maximum(&mut self, usage: u16) -> &mut Self {
+        self.unsigned_item(0x28, u32::from(usage))
+    }
+}
+
+/// The report descriptor of the boot protocol keyboard, from
+/// appendix B.1 of the HID specification
+pub const BOOT_KEYBOARD_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01, // Usage Page (Generic Desktop)
+    0x09, 0x06, // Usage (Keyboard)
+    0xa1, 0x01, // Collection (Application)
+    0x05, 0x07, //   Usage Page (Key Codes)
+    0x19, 0xe0, //   Usage Minimum (224)
+    0x29, 0xe7, //   Usage Maximum (231)
+    0x15, 0x00, //   Logical Minimum (0)
+    0x25, 0x01, //   Logical Maximum (1)
+    0x75, 0x01, //   Report Size (1)
+    0x95, 0x08, //   Report Count (8)
+    0x81, 0x02, //   Input (Data, Variable, Absolute): modifier byte
+    0x95, 0x01, //   Report Count (1)
+    0x75, 0x08, //   Report Size (8)
+    0x81, 0x01, //   Input (Constant): reserved byte
+    0x95, 0x05, //   Report Count (5)
+    0x75, 0x01, //   Report Size (1)
+    0x05, 0x08, //   Usage Page (LEDs)
+    0x19, 0x01, //   Usage Minimum (1)
+    0x29, 0x05, //   Usage Maximum (5)
+    0x91, 0x02, //   Output (Data, Variable, Absolute): LED report
+    0x95, 0x01, //   Report Count (1)
+    0x75, 0x03, //   Report Size (3)
+    0x91, 0x01, //   Output (Constant): LED report padding
+    0x95, 0x06, //   Report Count (6)
+    0x75, 0x08, //   Report Size (8)
+    0x15, 0x00, //   Logical Minimum (0)
+    0x25, 0x65, //   Logical Maximum (101)
+    0x05, 0x07, //   Usage Page (Key Codes)
+    0x19, 0x00, //   Usage Minimum (0)
+    0x29, 0x65, //   Usage Maximum (101)
+    0x81, 0x00, //   Input (Data, Array): key arrays
+    0xc0, // End Collection
+];
+
+/// The report descriptor of the boot protocol mouse, from appendix B.2
+/// of the HID specification, with a wheel added after the boot fields
+pub const BOOT_MOUSE_REPORT_DESCRIPTOR: &[u8] = &[
+    0x05, 0x01, // Usage Page (Generic Desktop)
+    0x09, 0x02, // Usage (Mouse)
+    0xa1, 0x01, // Collection (Application)
+    0x09, 0x01, //   Usage (Pointer)
+    0xa1, 0x00, //   Collection (Physical)
+    0x05, 0x09, //     Usage Page (Buttons)
+    0x19, 0x01, //     Usage Minimum (1)
+    0x29, 0x03, //     Usage Maximum (3)
+    0x15, 0x00, //     Logical Minimum (0)
+    0x25, 0x01, //     Logical Maximum (1)
+    0x95, 0x03, //     Report Count (3)
+    0x75, 0x01, //     Report Size (1)
+    0x81, 0x02, //     Input (Data, Variable, Absolute): buttons
+    0x95, 0x01, //     Report Count (1)
+    0x75, 0x05, //     Report Size (5)
+    0x81, 0x01, //     Input (Constant): padding
+    0x05, 0x01, //     Usage Page (Generic Desktop)
+    0x09, 0x30, //     Usage (X)
+    0x09, 0x31, //     Usage (Y)
+    0x09, 0x38, //     Usage (Wheel)
+    0x15, 0x81, //     Logical Minimum (-127)
+    0x25, 0x7f, //     Logical Maximum (127)
+    0x75, 0x08, //     Report Size (8)
+    0x95, 0x03, //     Report Count (3)
+    0x81, 0x06, //     Input (Data, Variable, Relative): X, Y, wheel
+    0xc0, //   End Collection
+    0xc0, // End Collection
+];
+
+/// The bits of the keyboard modifier byte
+pub mod modifiers {
+    pub const LEFT_CTRL: u8 = 1 << 0;
+    pub const LEFT_SHIFT: u8 = 1 << 1;
+    pub const LEFT_ALT: u8 = 1 << 2;
+    pub const LEFT_GUI: u8 = 1 << 3;
+    pub const RIGHT_CTRL: u8 = 1 << 4;
+    pub const RIGHT_SHIFT: u8 = 1 << 5;
+    pub const RIGHT_ALT: u8 = 1 << 6;
+    pub const RIGHT_GUI: u8 = 1 << 7;
+}
+
+/// The bits of the keyboard LED output report
+pub mod leds {
+    pub const NUM_LOCK: u8 = 1 << 0;
+    pub const CAPS_LOCK: u8 = 1 << 1;
+    pub const SCROLL_LOCK: u8 = 1 << 2;
+    pub const COMPOSE: u8 = 1 << 3;
+    pub const KANA: u8 = 1 << 4;
+}
+
+/// An input report for `BOOT_KEYBOARD_REPORT_DESCRIPTOR`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct KeyboardReport {
+    /// The pressed modifier keys; see the `modifiers` module
+    pub modifiers: u8,
+    /// The usage codes of up to six pressed keys; unused entries are 0
+    pub keys: [u8; 6],
+}
+
+impl KeyboardReport {
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let k = &self.keys;
+        [self.modifiers, 0, k[0], k[1], k[2], k[3], k[4], k[5]]
+    }
+
+    /// Decodes a boot protocol keyboard report
+    pub fn from_bytes(buf: &[u8; 8]) -> Self {
+        let mut keys = [0; 6];
+        keys.copy_from_slice(&buf[2..]);
+        Self {
+            modifiers: buf[0],
+            keys,
+        }
+    }
+}
+
+/// An input report for `BOOT_MOUSE_REPORT_DESCRIPTOR`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MouseReport {
+    /// Bit 0 is the left button, bit 1 the right and bit 2 the middle
+    pub buttons: u8,
+    pub x: i8,
+    pub y: i8,
+    pub wheel: i8,
+}
+
+impl MouseReport {
+    pub fn to_bytes(&self) -> [u8; 4] {
+        [self.buttons, self.x as u8, self.y as u8, self.wheel as u8]
+    }
+
+    /// Decodes a boot protocol mouse report.  The boot protocol
+    /// only requires the first three bytes; the wheel is 0 if the
+    /// report is shorter than four bytes.
+    pub fn from_bytes(buf: &[u8]) -> Self {
+        let byte = |i: usize| buf.get(i).cloned().unwrap_or(0);
+        Self {
+            buttons: byte(0),
+            x: byte(1) as i8,
+            y: byte(2) as i8,
+            wheel: byte(3) as i8,
+        }
+    }
+}
+
+/// A HID interface
+pub struct HidClass<'a> {
+    interface: u8,
+    boot: BootInterface,
+    report_descriptor: &'a [u8],
+    in_ep: EndpointIn<'a>,
+    out_ep: EndpointOut<'a>,
+    protocol: Protocol,
+    idle_rate: u8,
+    /// The most recent input report, returned by GET_REPORT
+    last_input: [u8; MAX_REPORT_SIZE],
+    last_input_len: usize,
+    /// An output report delivered by SET_REPORT
+    output: [u8; MAX_REPORT_SIZE],
+    output_len: Option<usize>,
+}
+
+impl<'a> HidClass<'a> {
+    /// Allocates a HID interface.  `report_size` is the size of the
+    /// largest report, up to 64 bytes, and `poll_interval` is how often
+    /// the host should ask for input reports, in milliseconds.
+    pub fn new(
+        usb: &mut UsbDevice<'a>,
+        report_descriptor: &'a [u8],
+        report_size: u16,
+        poll_interval: u8,
+    ) -> Result<Self, UsbError> {
+        Self::with_boot_interface(
+            usb,
+            report_descriptor,
+            report_size,
+            poll_interval,
+            BootInterface::None,
+        )
+    }
+
+    /// Allocates a boot protocol keyboard, which sends `KeyboardReport`s
+    pub fn boot_keyboard(usb: &mut UsbDevice<'a>) -> Result<Self, UsbError> {
+        Self::with_boot_interface(
+            usb,
+            BOOT_KEYBOARD_REPORT_DESCRIPTOR,
+            8,
+            10,
+            BootInterface::Keyboard,
+        )
+    }
+
+    /// Allocates a boot protocol mouse, which sends `MouseReport`s
+    pub fn boot_mouse(usb: &mut UsbDevice<'a>) -> Result<Self, UsbError> {
+        Self::with_boot_interface(
+            usb,
+            BOOT_MOUSE_REPORT_DESCRIPTOR,
+            8,
+            10,
+            BootInterface::Mouse,
+        )
+    }
+
+    pub fn with_boot_interface(
+        usb: &mut UsbDevice<'a>,
+        report_descriptor: &'a [u8],
+        report_size: u16,
+        poll_interval: u8,
+        boot: BootInterface,
+    ) -> Result<Self, UsbError> {
+        let packet_size = match report_size {
+            0...8 => 8,
+            9...16 => 16,
+            17...32 => 32,
+            33...64 => 64,
+            _ => return Err(UsbError::InvalidPacketSize),
+        };
+        Ok(Self {
+            interface: usb.alloc_interface(),
+            boot,
+            report_descriptor,
+            in_ep: usb.alloc_endpoint_in(EndpointType::Interrupt, packet_size, poll_interval)?,
+            out_ep: usb.alloc_endpoint_out(EndpointType::Interrupt, packet_size, poll_interval)?,
+            protocol: Protocol::Report,
+            idle_rate: 0,
+            last_input: [0; MAX_REPORT_SIZE],
+            last_input_len: 0,
+            output: [0; MAX_REPORT_SIZE],
+            output_len: None,
+        })
+    }
+
+    /// Queues an input report.  Returns `WouldBlock` while the host
+    /// has not yet collected the previous report.
+    pub fn write_report(&mut self, report: &[u8]) -> nb::Result<usize, UsbError> {
+        let len = self.in_ep.write(report)?;
+        self.last_input[..len].copy_from_slice(&report[..len]);
+        self.last_input_len = len;
+        Ok(len)
+    }
+
+    /// Reads an output report sent by the host, either on the OUT
+    /// endpoint or with SET_REPORT.  For the boot keyboard this
+    /// is the LED state.
+    pub fn read_report(&mut self, buf: &mut [u8]) -> nb::Result<usize, UsbError> {
+        if let Some(len) = self.output_len {
+            if len > buf.len() {
+                return Err(nb::Error::Other(UsbError::BufferOverflow));
+            }
+            buf[..len].copy_from_slice(&self.output[..len]);
+            self.output_len = None;
+            return Ok(len);
+        }
+        self.out_ep.read(buf)
+    }
+
+    /// The protocol selected by the host.  A boot interface must send
+    /// reports in the boot format while the boot protocol is selected.
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// The idle rate set by the host, in units of 4ms.  While it is
+    /// nonzero the current input report should be repeated at this
+    /// rate even if it has not changed; zero means only on change.
+    pub fn idle_rate(&self) -> u8 {
+        self.idle_rate
+    }
+
+    fn hid_descriptor(&self, buf: &mut [u8]) -> usize {
+        let len = self.report_descriptor.len();
+        let body = [
+            0x11,
+            0x01, // bcdHID 1.11
+            0,    // bCountryCode
+            1,    // bNumDescriptors
+            HID_DESCRIPTOR_REPORT,
+            len as u8,
+            (len >> 8) as u8,
+        ];
+        let mut writer = DescriptorWriter::new(buf);
+        writer.write(HID_DESCRIPTOR_HID, &body).ok();
+        writer.position()
+    }
+
+    fn is_for_interface(&self, req: &SetupPacket) -> bool {
+        req.recipient() == Recipient::Interface && req.index == u16::from(self.interface)
+    }
+}
+
+impl<'a> UsbClass for HidClass<'a> {
+    fn reset(&mut self) {
+        self.protocol = Protocol::Report;
+        self.idle_rate = 0;
+        self.last_input_len = 0;
+        self.output_len = None;
+    }
+
+    fn configuration_descriptors(&self, w: &mut DescriptorWriter) -> Result<(), UsbError> {
+        let (sub_class, protocol) = match self.boot {
+            BootInterface::None => (0, 0),
+            BootInterface::Keyboard => (HID_SUBCLASS_BOOT, 1),
+            BootInterface::Mouse => (HID_SUBCLASS_BOOT, 2),
+        };
+        w.interface(self.interface, 0, USB_CLASS_HID, sub_class, protocol, 0)?;
+
+        let mut hid = [0u8; 9];
+        let len = self.hid_descriptor(&mut hid);
+        w.write(HID_DESCRIPTOR_HID, &hid[2..len])?;
+
+        w.endpoint(&self.in_ep.info())?;
+        w.endpoint(&self.out_ep.info())
+    }
+
+    fn control_in(&mut self, req: &SetupPacket, buf: &mut [u8]) -> ControlIn {
+        if !self.is_for_interface(req) {
+            return ControlIn::Ignored;
+        }
+        match (req.kind(), req.request) {
+            (RequestKind::Standard, request::GET_DESCRIPTOR) => match req.value_high() {
+                HID_DESCRIPTOR_REPORT => {
+                    let len = self.report_descriptor.len();
+                    if len > buf.len() {
+                        return ControlIn::Rejected;
+                    }
+                    buf[..len].copy_from_slice(self.report_descriptor);
+                    ControlIn::Data(len)
+                }
+                HID_DESCRIPTOR_HID => ControlIn::Data(self.hid_descriptor(buf)),
+                _ => ControlIn::Rejected,
+            },
+            (RequestKind::Class, HID_REQ_GET_REPORT) => {
+                let len = cmp::min(self.last_input_len, buf.len());
+                buf[..len].copy_from_slice(&self.last_input[..len]);
+                ControlIn::Data(len)
+            }
+            (RequestKind::Class, HID_REQ_GET_IDLE) => {
+                buf[0] = self.idle_rate;
+                ControlIn::Data(1)
+            }
+            (RequestKind::Class, HID_REQ_GET_PROTOCOL) => {
+                buf[0] = match self.protocol {
+                    Protocol::Boot => 0,
+                    Protocol::Report => 1,
+                };
+                ControlIn::Data(1)
+            }
+            (RequestKind::Class, _) => ControlIn::Rejected,
+            _ => ControlIn::Ignored,
+        }
+    }
+
+    fn control_out(&mut self, req: &SetupPacket, data: &[u8]) -> ControlOut {
+        if !self.is_for_interface(req) || req.kind() != RequestKind::Class {
+            return ControlOut::Ignored;
+        }
+        match req.request {
+            HID_REQ_SET_IDLE => {
+                self.idle_rate = req.value_high();
+                ControlOut::Accepted
+            }
+            HID_REQ_SET_PROTOCOL => {
+                self.protocol = if req.value == 0 {
+                    Protocol::Boot
+                } else {
+                    Protocol::Report
+                };
+                ControlOut::Accepted
+            }
+            HID_REQ_SET_REPORT => {
+                let len = cmp::min(data.len(), MAX_REPORT_SIZE);
+                self.output[..len].copy_from_slice(&data[..len]);
+                self.output_len = Some(len);
+                ControlOut::Accepted
+            }
+            _ => ControlOut::Rejected,
+        }
+    }
+}
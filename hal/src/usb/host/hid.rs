@@ -0,0 +1,146 @@
+//! Host driver for HID boot keyboards and mice.
+//!
+//! `HidDevice` finds the first boot protocol keyboard or mouse interface
+//! of an enumerated device, selects the configuration and switches the
+//! interface to the boot protocol, whose report format is fixed.  Input
+//! reports are then read from its interrupt IN endpoint with `poll`.
+use nb;
+
+use super::super::control::{Direction, Recipient, RequestKind, SetupPacket};
+use super::super::descriptor::descriptor_type;
+use super::super::endpoint::EndpointType;
+use super::super::hid::{
+    BootInterface, KeyboardReport, MouseReport, HID_REQ_SET_IDLE, HID_REQ_SET_PROTOCOL,
+    HID_REQ_SET_REPORT, HID_SUBCLASS_BOOT, USB_CLASS_HID,
+};
+use super::{parse_endpoint, Descriptors, HostError, Pipe, UsbHost};
+
+/// The largest configuration descriptor that is examined
+const MAX_CONFIGURATION_SIZE: usize = 256;
+
+/// The SET_REPORT wValue of output report 0
+const OUTPUT_REPORT: u16 = 0x0200;
+
+/// An input report from a boot keyboard or mouse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HidReport {
+    Keyboard(KeyboardReport),
+    Mouse(MouseReport),
+}
+
+/// A boot protocol keyboard or mouse
+pub struct HidDevice {
+    interface: u8,
+    kind: BootInterface,
+    pipe: Pipe,
+}
+
+impl HidDevice {
+    /// Configures the enumerated device if it has a boot keyboard or
+    /// mouse interface.  Returns `Ok(None)` if it has neither.
+    pub fn attach(host: &mut UsbHost) -> Result<Option<Self>, HostError> {
+        let mut buf = [0; MAX_CONFIGURATION_SIZE];
+        let len = host.configuration_descriptor(&mut buf)?;
+
+        let mut configuration = None;
+        let mut found = None;
+        let mut current = None;
+        for desc in Descriptors::new(&buf[..len]) {
+            match desc[1] {
+                descriptor_type::CONFIGURATION if desc.len() >= 9 => {
+                    configuration = Some(desc[5]);
+                }
+                descriptor_type::INTERFACE if desc.len() >= 9 => {
+                    current = match (desc[5], desc[6], desc[7]) {
+                        (USB_CLASS_HID, HID_SUBCLASS_BOOT, 1) => {
+                            Some((desc[2], BootInterface::Keyboard))
+                        }
+                        (USB_CLASS_HID, HID_SUBCLASS_BOOT, 2) => {
+                            Some((desc[2], BootInterface::Mouse))
+                        }
+                        _ => None,
+                    };
+                }
+                descriptor_type::ENDPOINT => {
+                    let info = parse_endpoint(desc);
+                    if let (Some((interface, kind)), Some(info)) = (current, info) {
+                        if info.address.is_in() && info.ep_type == EndpointType::Interrupt {
+                            found = Some((interface, kind, info));
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (configuration, (interface, kind, info)) = match (configuration, found) {
+            (Some(configuration), Some(found)) => (configuration, found),
+            _ => return Ok(None),
+        };
+        host.set_configuration(configuration)?;
+
+        let mut device = Self {
+            interface,
+            kind,
+            pipe: host.alloc_pipe(info)?,
+        };
+        device.class_request(host, HID_REQ_SET_PROTOCOL, 0, &[])?;
+        // Only report changes; some devices do not support SET_IDLE
+        match device.class_request(host, HID_REQ_SET_IDLE, 0, &[]) {
+            Ok(()) | Err(HostError::Stall) => {}
+            Err(e) => return Err(e),
+        }
+        Ok(Some(device))
+    }
+
+    /// Whether this is a keyboard or a mouse
+    pub fn kind(&self) -> BootInterface {
+        self.kind
+    }
+
+    /// Returns the next input report, or `WouldBlock` until the
+    /// device has one to send
+    pub fn poll(&mut self, host: &mut UsbHost) -> nb::Result<HidReport, HostError> {
+        let mut buf = [0; 8];
+        let len = host.read(&mut self.pipe, &mut buf)?;
+        match self.kind {
+            BootInterface::Keyboard if len >= 8 => {
+                Ok(HidReport::Keyboard(KeyboardReport::from_bytes(&buf)))
+            }
+            BootInterface::Mouse if len >= 3 => {
+                Ok(HidReport::Mouse(MouseReport::from_bytes(&buf[..len])))
+            }
+            _ => Err(nb::Error::Other(HostError::InvalidDescriptor)),
+        }
+    }
+
+    /// Sets the keyboard LEDs; see the `hid::leds` module
+    pub fn set_leds(&mut self, host: &mut UsbHost, leds: u8) -> Result<(), HostError> {
+        self.class_request(host, HID_REQ_SET_REPORT, OUTPUT_REPORT, &[leds])
+    }
+
+    /// Releases the interrupt pipe
+    pub fn detach(self, host: &mut UsbHost) {
+        host.free_pipe(self.pipe);
+    }
+
+    fn class_request(
+        &mut self,
+        host: &mut UsbHost,
+        request: u8,
+        value: u16,
+        data: &[u8],
+    ) -> Result<(), HostError> {
+        let setup = SetupPacket::new(
+            Direction::Out,
+            RequestKind::Class,
+            Recipient::Interface,
+            request,
+            value,
+            u16::from(self.interface),
+            data.len() as u16,
+        );
+        host.control_out(&setup, data)
+    }
+}
@@ -0,0 +1,948 @@
+//! USB Host support
+//!
+//! `UsbHost` drives the USB peripheral in host mode with a single device
+//! connected directly to the port.  It detects connection and removal,
+//! resets the port, enumerates the device and then exchanges data with
+//! it through pipes; pipe 0 is the default control pipe and the others
+//! are allocated for the interrupt and bulk endpoints of the device.
+//!
+//! The typical sequence is to switch on the VBUS supply, call
+//! `set_port_power(true)`, wait for `poll` to report
+//! `PortEvent::Connected` and then call `enumerate`.  A class driver,
+//! such as `hid::HidDevice`, reads the configuration descriptor and
+//! selects the configuration.
+//!
+//! Transfers on the control pipe block until they complete, fail or
+//! time out.  Transfers on the other pipes are non-blocking, since
+//! interrupt endpoints only return data when they have something to
+//! report.
+//!
+//! Like device mode, host mode requires an accurate 48Mhz clock.
+
+use calibration::{usb_transn_cal, usb_transp_cal, usb_trim_cal};
+use clock;
+use core::cmp;
+use core::ptr::{self, null_mut};
+use core::sync::atomic::{self, Ordering};
+use hal::blocking::delay::DelayMs;
+use nb;
+use target_device::usb::host::{BINTERVAL, PCFG, PINTFLAG, PSTATUSCLR, PSTATUSSET};
+use target_device::usb::HOST;
+use target_device::{PM, USB};
+
+use super::control::{request, Direction, Recipient, RequestKind, SetupPacket};
+use super::descriptor::descriptor_type;
+use super::endpoint::{EndpointAddress, EndpointInfo, EndpointType};
+use super::{DmPad, DpPad};
+
+pub mod hid;
+
+/// The number of pipes supported by the hardware, including
+/// the control pipe
+const NUM_PIPES: usize = 8;
+
+/// The buffer size of each pipe.  Full speed interrupt, bulk and
+/// control endpoints all have a maximum packet size of 64 bytes.
+const PIPE_BUFFER_SIZE: usize = 64;
+
+/// The number of words of pipe memory needed by `UsbHost`
+pub const HOST_MEMORY_WORDS: usize = NUM_PIPES * PIPE_BUFFER_SIZE / 4;
+
+/// The address assigned to the device during enumeration
+const DEVICE_ADDRESS: u8 = 1;
+
+/// The number of consecutive errors a pipe tolerates before the
+/// transfer fails
+const PIPE_MAX_ERRORS: u16 = 3;
+
+/// How long a control transfer stage may take, in frames
+const CONTROL_TIMEOUT_FRAMES: u16 = 500;
+
+/// The PCFG.PTOKEN encoding
+const TOKEN_SETUP: u8 = 0;
+const TOKEN_IN: u8 = 1;
+const TOKEN_OUT: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HostError {
+    /// No device is connected, or it was removed during the transfer
+    NotConnected,
+    /// `enumerate` has not completed
+    NotEnumerated,
+    /// The device responded with a STALL handshake
+    Stall,
+    /// The device did not respond correctly after repeated attempts
+    TransferFailed,
+    /// The transfer did not complete in time
+    Timeout,
+    /// The data does not fit in the buffer or packet
+    BufferOverflow,
+    /// All of the pipes are in use
+    PipeOverflow,
+    /// A descriptor returned by the device is malformed
+    InvalidDescriptor,
+}
+
+/// The signalling speed of the connected device
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Speed {
+    Full,
+    Low,
+}
+
+/// A change in the state of the port, reported by `UsbHost::poll`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PortEvent {
+    Connected,
+    Disconnected,
+}
+
+/// The interesting fields of the device descriptor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceInfo {
+    pub speed: Speed,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub device_release: u16,
+    pub device_class: u8,
+    pub device_sub_class: u8,
+    pub device_protocol: u8,
+    pub max_packet_size_0: u8,
+    pub num_configurations: u8,
+}
+
+/// References to the registers of one pipe.  Like the endpoint
+/// registers, these are separately named fields in the PAC.
+struct PipeRegisters<'r> {
+    pcfg: &'r PCFG,
+    binterval: &'r BINTERVAL,
+    pstatusclr: &'r PSTATUSCLR,
+    pstatusset: &'r PSTATUSSET,
+    pintflag: &'r PINTFLAG,
+}
+
+macro_rules! pipe_registers {
+    ($($index:expr => ($pcfg:ident, $binterval:ident, $pstatusclr:ident, $pstatusset:ident,
+                       $pintflag:ident),)+) => {
+
+fn pipe_registers(index: u8) -> PipeRegisters<'static> {
+    let usb = unsafe { &(*USB::ptr()).host };
+    match index {
+        $(
+            $index => PipeRegisters {
+                pcfg: &usb.$pcfg,
+                binterval: &usb.$binterval,
+                pstatusclr: &usb.$pstatusclr,
+                pstatusset: &usb.$pstatusset,
+                pintflag: &usb.$pintflag,
+            },
+        )+
+        _ => unreachable!(),
+    }
+}
+    };
+}
+
+pipe_registers!(
+    0 => (pcfg0, binterval0, pstatusclr0, pstatusset0, pintflag0),
+    1 => (pcfg1, binterval1, pstatusclr1, pstatusset1, pintflag1),
+    2 => (pcfg2, binterval2, pstatusclr2, pstatusset2, pintflag2),
+    3 => (pcfg3, binterval3, pstatusclr3, pstatusset3, pintflag3),
+    4 => (pcfg4, binterval4, pstatusclr4, pstatusset4, pintflag4),
+    5 => (pcfg5, binterval5, pstatusclr5, pstatusset5, pintflag5),
+    6 => (pcfg6, binterval6, pstatusclr6, pstatusset6, pintflag6),
+    7 => (pcfg7, binterval7, pstatusclr7, pstatusset7, pintflag7),
+);
+
+/// A pipe bank descriptor, read directly by the USB hardware.  The
+/// layout matches the device mode bank, with the addition of the
+/// device address, endpoint number and error status of the pipe.
+#[repr(C)]
+pub struct HostDescBank {
+    /// pipe data buffer, must be 32-bit aligned
+    addr: *mut u8,
+    pcksize: u32,
+    _extreg: u16,
+    status_bk: u8,
+    _reserved: u8,
+    ctrl_pipe: u16,
+    status_pipe: u16,
+}
+
+impl HostDescBank {
+    fn new() -> Self {
+        Self {
+            addr: null_mut(),
+            pcksize: 0,
+            _extreg: 0,
+            status_bk: 0,
+            _reserved: 0,
+            ctrl_pipe: 0,
+            status_pipe: 0,
+        }
+    }
+
+    fn pcksize(&self) -> u32 {
+        unsafe { ptr::read_volatile(&self.pcksize) }
+    }
+
+    fn set_pcksize(&mut self, value: u32) {
+        unsafe { ptr::write_volatile(&mut self.pcksize, value) }
+    }
+
+    fn set_address(&mut self, addr: *mut u8) {
+        unsafe { ptr::write_volatile(&mut self.addr, addr) }
+    }
+
+    /// Sets the maximum packet size of the pipe
+    fn set_pipe_size(&mut self, size: u16) {
+        let size = match size {
+            8 => 0u32,
+            16 => 1,
+            32 => 2,
+            _ => 3,
+        };
+        let pcksize = (self.pcksize() & !(7 << 28)) | (size << 28);
+        self.set_pcksize(pcksize);
+    }
+
+    /// Sets the number of bytes to send, or clears the received
+    /// count, and limits the transfer to a single packet
+    fn set_byte_count(&mut self, count: u16) {
+        let pcksize = self.pcksize() & !((0b11_1111_1111_1111 << 14) | 0b11_1111_1111_1111);
+        self.set_pcksize(pcksize | u32::from(count));
+    }
+
+    fn byte_count(&self) -> u16 {
+        (self.pcksize() & 0b11_1111_1111_1111) as u16
+    }
+
+    /// Sets the target device address and endpoint number, and the
+    /// number of errors tolerated before the pipe is frozen
+    fn set_target(&mut self, device_address: u8, endpoint: u8) {
+        let value = u16::from(device_address & 0x7f)
+            | (u16::from(endpoint & 0xf) << 8)
+            | (PIPE_MAX_ERRORS << 12);
+        unsafe { ptr::write_volatile(&mut self.ctrl_pipe, value) }
+    }
+
+    fn clear_status(&mut self) {
+        unsafe {
+            ptr::write_volatile(&mut self.status_bk, 0);
+            ptr::write_volatile(&mut self.status_pipe, 0);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct HostDescriptor {
+    bank: [HostDescBank; 2],
+}
+
+impl HostDescriptor {
+    fn new() -> Self {
+        Self {
+            bank: [HostDescBank::new(), HostDescBank::new()],
+        }
+    }
+}
+
+/// The pipe descriptor table pointed to by DESCADD.  It must not
+/// move while the `UsbHost` exists, so is usually a `static mut`.
+#[repr(C)]
+pub struct HostDescriptors {
+    desc: [HostDescriptor; NUM_PIPES],
+}
+
+impl HostDescriptors {
+    pub fn new() -> Self {
+        Self {
+            desc: [
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+                HostDescriptor::new(),
+            ],
+        }
+    }
+}
+
+/// A pipe to one endpoint of the device, returned by
+/// `UsbHost::alloc_pipe`
+#[derive(Debug)]
+pub struct Pipe {
+    index: u8,
+    info: EndpointInfo,
+    /// A transaction has been started and not yet collected
+    busy: bool,
+}
+
+impl Pipe {
+    /// The endpoint of the device served by this pipe
+    pub fn info(&self) -> &EndpointInfo {
+        &self.info
+    }
+}
+
+pub struct UsbHost<'a> {
+    desc: &'a mut HostDescriptors,
+    memory: &'a mut [u32],
+    dm_pad: DmPad,
+    dp_pad: DpPad,
+    usb: USB,
+    connected: bool,
+    device: Option<DeviceInfo>,
+    /// Pipes in use, other than pipe 0
+    allocated: u8,
+}
+
+impl<'a> UsbHost<'a> {
+    /// Creates the host and enables the peripheral in host mode.
+    /// `memory` provides the pipe buffers and must hold at least
+    /// `HOST_MEMORY_WORDS` words.
+    pub fn new(
+        _clock: &clock::UsbClock,
+        pm: &mut PM,
+        dm_pad: DmPad,
+        dp_pad: DpPad,
+        usb: USB,
+        desc: &'a mut HostDescriptors,
+        memory: &'a mut [u32],
+    ) -> Self {
+        assert!(memory.len() >= HOST_MEMORY_WORDS);
+        pm.apbbmask.modify(|_, w| w.usb_().set_bit());
+        pm.ahbmask.modify(|_, w| w.usb_().set_bit());
+
+        let mut host = Self {
+            desc,
+            memory,
+            dm_pad,
+            dp_pad,
+            usb,
+            connected: false,
+            device: None,
+            allocated: 0,
+        };
+        host.reset();
+        host
+    }
+
+    fn usb(&self) -> &HOST {
+        unsafe { &self.usb.host }
+    }
+
+    /// Resets the USB hardware and selects host mode
+    pub fn reset(&mut self) {
+        let addr = self.desc as *mut _ as u32;
+        for index in 0..NUM_PIPES {
+            let buf = self.pipe_buffer(index);
+            self.desc.desc[index].bank[0].set_address(buf);
+        }
+
+        {
+            let usb = self.usb();
+            usb.ctrla.modify(|_, w| w.swrst().set_bit());
+            while usb.syncbusy.read().swrst().bit_is_set() {}
+
+            usb.descadd.write(|w| unsafe { w.descadd().bits(addr) });
+            usb.padcal.modify(|_, w| unsafe {
+                w.transn().bits(usb_transn_cal());
+                w.transp().bits(usb_transp_cal());
+                w.trim().bits(usb_trim_cal())
+            });
+            usb.ctrla.modify(|_, w| {
+                w.mode().host();
+                w.runstdby().set_bit()
+            });
+            usb.ctrlb.modify(|_, w| w.spdconf().normal());
+
+            usb.ctrla.modify(|_, w| w.enable().set_bit());
+            while usb.syncbusy.read().enable().bit_is_set() {}
+        }
+
+        self.connected = false;
+        self.device = None;
+        self.allocated = 0;
+    }
+
+    /// Tells the controller whether VBUS is being supplied to the
+    /// port.  The supply itself is switched by the board, usually
+    /// with a GPIO controlled load switch, which should be turned
+    /// on first.  Connections are only detected while this is set.
+    pub fn set_port_power(&mut self, on: bool) {
+        self.usb().ctrlb.modify(|_, w| w.vbusok().bit(on));
+        if !on {
+            self.disconnected();
+        }
+    }
+
+    /// Checks for a device being connected or removed
+    pub fn poll(&mut self) -> Option<PortEvent> {
+        let flags = self.usb().intflag.read();
+        if flags.ddisc().bit_is_set() {
+            self.usb().intflag.write(|w| {
+                w.ddisc().set_bit();
+                w.dconn().set_bit()
+            });
+            if self.connected {
+                self.disconnected();
+                return Some(PortEvent::Disconnected);
+            }
+        } else if flags.dconn().bit_is_set() {
+            self.usb().intflag.write(|w| w.dconn().set_bit());
+            if !self.connected {
+                self.connected = true;
+                return Some(PortEvent::Connected);
+            }
+        }
+        None
+    }
+
+    /// Enables the USB interrupt on connection and removal.  This
+    /// only configures the peripheral; the interrupt handler should
+    /// call `poll`.
+    pub fn enable_interrupts(&mut self) {
+        self.usb().intenset.write(|w| {
+            w.dconn().set_bit();
+            w.ddisc().set_bit()
+        });
+    }
+
+    /// Disables the USB interrupt
+    pub fn disable_interrupts(&mut self) {
+        self.usb().intenclr.write(|w| {
+            w.dconn().set_bit();
+            w.ddisc().set_bit()
+        });
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// Returns the descriptor of the enumerated device
+    pub fn device(&self) -> Option<DeviceInfo> {
+        self.device
+    }
+
+    /// Returns the number of the most recent frame sent
+    pub fn frame_number(&self) -> u16 {
+        self.usb().fnum.read().fnum().bits()
+    }
+
+    fn disconnected(&mut self) {
+        self.connected = false;
+        self.device = None;
+        self.allocated = 0;
+        self.usb().ctrlb.modify(|_, w| w.sofe().clear_bit());
+        for index in 0..NUM_PIPES as u8 {
+            let regs = pipe_registers(index);
+            regs.pstatusset.write(|w| w.pfreeze().set_bit());
+            regs.pcfg.write(|w| unsafe { w.bits(0) });
+        }
+    }
+
+    /// Signals a bus reset to the device, then starts sending
+    /// start of frame packets so that it does not suspend
+    pub fn reset_port<D: DelayMs<u8>>(&mut self, delay: &mut D) -> Result<(), HostError> {
+        if !self.connected {
+            return Err(HostError::NotConnected);
+        }
+        self.usb().intflag.write(|w| w.rst().set_bit());
+        self.usb().ctrlb.modify(|_, w| w.busreset().set_bit());
+        while self.usb().intflag.read().rst().bit_is_clear() {
+            if self.usb().intflag.read().ddisc().bit_is_set() {
+                return Err(HostError::NotConnected);
+            }
+        }
+        self.usb().intflag.write(|w| w.rst().set_bit());
+        self.usb().ctrlb.modify(|_, w| w.sofe().set_bit());
+        // reset recovery time
+        delay.delay_ms(20);
+        Ok(())
+    }
+
+    /// The speed of the connected device, which the hardware
+    /// determines when the port is reset
+    pub fn speed(&self) -> Speed {
+        match self.usb().status.read().speed().bits() {
+            1 => Speed::Low,
+            _ => Speed::Full,
+        }
+    }
+
+    /// Resets the port and assigns an address to the connected
+    /// device, returning its device descriptor.  Any pipes allocated
+    /// for a previous device are released.
+    pub fn enumerate<D: DelayMs<u8>>(&mut self, delay: &mut D) -> Result<DeviceInfo, HostError> {
+        self.device = None;
+        self.allocated = 0;
+        // let the connection settle before resetting
+        delay.delay_ms(100);
+        self.reset_port(delay)?;
+
+        // Until the device descriptor has been read the packet size of
+        // the control endpoint is unknown; 8 is always supported
+        self.configure_pipe(0, EndpointType::Control, 0, 0, 8, 0);
+        let mut buf = [0; 18];
+        let setup = get_descriptor(descriptor_type::DEVICE, 0, 8);
+        self.control_in(&setup, &mut buf[..8])?;
+        let max_packet_size_0 = buf[7];
+        if max_packet_size_0 == 0 {
+            return Err(HostError::InvalidDescriptor);
+        }
+
+        let setup = SetupPacket::new(
+            Direction::Out,
+            RequestKind::Standard,
+            Recipient::Device,
+            request::SET_ADDRESS,
+            u16::from(DEVICE_ADDRESS),
+            0,
+            0,
+        );
+        self.control_out(&setup, &[])?;
+        // set address recovery time
+        delay.delay_ms(2);
+        self.configure_pipe(
+            0,
+            EndpointType::Control,
+            DEVICE_ADDRESS,
+            0,
+            u16::from(max_packet_size_0),
+            0,
+        );
+
+        let setup = get_descriptor(descriptor_type::DEVICE, 0, buf.len() as u16);
+        if self.control_in(&setup, &mut buf)? < buf.len() {
+            return Err(HostError::InvalidDescriptor);
+        }
+        let info = DeviceInfo {
+            speed: self.speed(),
+            device_class: buf[4],
+            device_sub_class: buf[5],
+            device_protocol: buf[6],
+            max_packet_size_0,
+            vendor_id: u16::from(buf[8]) | (u16::from(buf[9]) << 8),
+            product_id: u16::from(buf[10]) | (u16::from(buf[11]) << 8),
+            device_release: u16::from(buf[12]) | (u16::from(buf[13]) << 8),
+            num_configurations: buf[17],
+        };
+        self.device = Some(info);
+        Ok(info)
+    }
+
+    /// Reads the complete first configuration descriptor, including
+    /// the interface and endpoint descriptors, into `buf`
+    pub fn configuration_descriptor(&mut self, buf: &mut [u8]) -> Result<usize, HostError> {
+        if buf.len() < 9 {
+            return Err(HostError::BufferOverflow);
+        }
+        let setup = get_descriptor(descriptor_type::CONFIGURATION, 0, 9);
+        if self.control_in(&setup, &mut buf[..9])? < 9 {
+            return Err(HostError::InvalidDescriptor);
+        }
+        let total = usize::from(buf[2]) | (usize::from(buf[3]) << 8);
+        if total > buf.len() {
+            return Err(HostError::BufferOverflow);
+        }
+        let setup = get_descriptor(descriptor_type::CONFIGURATION, 0, total as u16);
+        self.control_in(&setup, &mut buf[..total])
+    }
+
+    /// Selects a configuration, using the bConfigurationValue from
+    /// its descriptor
+    pub fn set_configuration(&mut self, value: u8) -> Result<(), HostError> {
+        let setup = SetupPacket::new(
+            Direction::Out,
+            RequestKind::Standard,
+            Recipient::Device,
+            request::SET_CONFIGURATION,
+            u16::from(value),
+            0,
+            0,
+        );
+        self.control_out(&setup, &[])
+    }
+
+    /// Performs a control transfer with an IN data stage on the
+    /// default pipe, returning the number of bytes received
+    pub fn control_in(&mut self, setup: &SetupPacket, buf: &mut [u8]) -> Result<usize, HostError> {
+        let len = cmp::min(usize::from(setup.length), buf.len());
+        self.setup_stage(setup)?;
+
+        let max_packet_size = self.pipe_size(0);
+        let mut pos = 0;
+        // The data stage starts with DATA1
+        pipe_registers(0).pstatusset.write(|w| w.dtgl().set_bit());
+        while pos < len {
+            self.start(0, TOKEN_IN, 0);
+            self.wait(0)?;
+            let received = usize::from(self.desc.desc[0].bank[0].byte_count());
+            let count = cmp::min(received, len - pos);
+            self.read_buffer(0, &mut buf[pos..pos + count]);
+            pos += count;
+            if received < max_packet_size {
+                break;
+            }
+        }
+
+        // The status stage is a zero length DATA1 packet
+        pipe_registers(0).pstatusset.write(|w| w.dtgl().set_bit());
+        self.start(0, TOKEN_OUT, 0);
+        self.wait(0)?;
+        Ok(pos)
+    }
+
+    /// Performs a control transfer on the default pipe, sending
+    /// `data` in the data stage if it is not empty
+    pub fn control_out(&mut self, setup: &SetupPacket, data: &[u8]) -> Result<(), HostError> {
+        if data.len() > usize::from(setup.length) {
+            return Err(HostError::BufferOverflow);
+        }
+        self.setup_stage(setup)?;
+
+        let max_packet_size = self.pipe_size(0);
+        pipe_registers(0).pstatusset.write(|w| w.dtgl().set_bit());
+        for packet in data.chunks(max_packet_size) {
+            self.write_buffer(0, packet);
+            self.start(0, TOKEN_OUT, packet.len() as u16);
+            self.wait(0)?;
+        }
+
+        pipe_registers(0).pstatusset.write(|w| w.dtgl().set_bit());
+        self.start(0, TOKEN_IN, 0);
+        self.wait(0)
+    }
+
+    /// Allocates a pipe for an interrupt or bulk endpoint of the
+    /// enumerated device.  Isochronous endpoints are not supported.
+    pub fn alloc_pipe(&mut self, info: EndpointInfo) -> Result<Pipe, HostError> {
+        if self.device.is_none() {
+            return Err(HostError::NotEnumerated);
+        }
+        match info.ep_type {
+            EndpointType::Bulk | EndpointType::Interrupt => {}
+            _ => return Err(HostError::InvalidDescriptor),
+        }
+        if usize::from(info.max_packet_size) > PIPE_BUFFER_SIZE {
+            return Err(HostError::BufferOverflow);
+        }
+        let index = (1..NUM_PIPES as u8)
+            .find(|i| self.allocated & (1 << *i) == 0)
+            .ok_or(HostError::PipeOverflow)?;
+        self.allocated |= 1 << index;
+
+        self.configure_pipe(
+            index,
+            info.ep_type,
+            DEVICE_ADDRESS,
+            info.address.index(),
+            info.max_packet_size,
+            info.interval,
+        );
+        let token = if info.address.is_in() {
+            TOKEN_IN
+        } else {
+            TOKEN_OUT
+        };
+        pipe_registers(index)
+            .pcfg
+            .modify(|_, w| unsafe { w.ptoken().bits(token) });
+        Ok(Pipe {
+            index,
+            info,
+            busy: false,
+        })
+    }
+
+    /// Releases a pipe so that it can be allocated again
+    pub fn free_pipe(&mut self, pipe: Pipe) {
+        let regs = pipe_registers(pipe.index);
+        regs.pstatusset.write(|w| w.pfreeze().set_bit());
+        regs.pcfg.write(|w| unsafe { w.bits(0) });
+        self.allocated &= !(1 << pipe.index);
+    }
+
+    /// Reads a packet from an IN pipe.  The first call starts the
+    /// transaction; the hardware then polls the endpoint until the
+    /// device returns data, at the interval given in its descriptor.
+    pub fn read(&mut self, pipe: &mut Pipe, buf: &mut [u8]) -> nb::Result<usize, HostError> {
+        if !pipe.info.address.is_in() {
+            return Err(nb::Error::Other(HostError::InvalidDescriptor));
+        }
+        if !pipe.busy {
+            self.check_connected()?;
+            self.start(pipe.index, TOKEN_IN, 0);
+            pipe.busy = true;
+        }
+        let result = self.status(pipe.index);
+        if let Err(nb::Error::WouldBlock) = result {
+            return Err(nb::Error::WouldBlock);
+        }
+        pipe.busy = false;
+        result?;
+
+        let received = usize::from(self.desc.desc[usize::from(pipe.index)].bank[0].byte_count());
+        if received > buf.len() {
+            return Err(nb::Error::Other(HostError::BufferOverflow));
+        }
+        self.read_buffer(pipe.index, &mut buf[..received]);
+        Ok(received)
+    }
+
+    /// Writes a packet to an OUT pipe.  Returns `WouldBlock` until
+    /// the device has accepted the previous packet.
+    pub fn write(&mut self, pipe: &mut Pipe, data: &[u8]) -> nb::Result<usize, HostError> {
+        if pipe.info.address.is_in() {
+            return Err(nb::Error::Other(HostError::InvalidDescriptor));
+        }
+        if pipe.busy {
+            self.status(pipe.index)?;
+            pipe.busy = false;
+        }
+        if data.len() > usize::from(pipe.info.max_packet_size) {
+            return Err(nb::Error::Other(HostError::BufferOverflow));
+        }
+        self.check_connected()?;
+        self.write_buffer(pipe.index, data);
+        self.start(pipe.index, TOKEN_OUT, data.len() as u16);
+        pipe.busy = true;
+        Ok(data.len())
+    }
+
+    /// Clears a halt on the endpoint served by `pipe` and resets
+    /// the data toggle of the pipe, after it has returned `Stall`
+    pub fn clear_halt(&mut self, pipe: &mut Pipe) -> Result<(), HostError> {
+        let setup = SetupPacket::new(
+            Direction::Out,
+            RequestKind::Standard,
+            Recipient::Endpoint,
+            request::CLEAR_FEATURE,
+            0,
+            u16::from(pipe.info.address.0),
+            0,
+        );
+        self.control_out(&setup, &[])?;
+        pipe.busy = false;
+        clear_data_toggle(pipe.index);
+        Ok(())
+    }
+
+    fn check_connected(&self) -> Result<(), HostError> {
+        if self.connected && self.usb().intflag.read().ddisc().bit_is_clear() {
+            Ok(())
+        } else {
+            Err(HostError::NotConnected)
+        }
+    }
+
+    /// The 64 byte buffer of a pipe
+    fn pipe_buffer(&mut self, index: usize) -> *mut u8 {
+        let words = PIPE_BUFFER_SIZE / 4;
+        self.memory[index * words..].as_mut_ptr() as *mut u8
+    }
+
+    fn pipe_size(&self, index: usize) -> usize {
+        match (self.desc.desc[index].bank[0].pcksize() >> 28) & 7 {
+            0 => 8,
+            1 => 16,
+            2 => 32,
+            _ => 64,
+        }
+    }
+
+    fn configure_pipe(
+        &mut self,
+        index: u8,
+        ep_type: EndpointType,
+        device_address: u8,
+        endpoint: u8,
+        max_packet_size: u16,
+        interval: u8,
+    ) {
+        let regs = pipe_registers(index);
+        regs.pstatusset.write(|w| w.pfreeze().set_bit());
+        regs.pcfg
+            .write(|w| unsafe { w.ptype().bits(ep_type.eptype()) });
+        regs.binterval
+            .write(|w| unsafe { w.bitinterval().bits(interval) });
+        clear_data_toggle(index);
+
+        let bank = &mut self.desc.desc[usize::from(index)].bank[0];
+        bank.set_pipe_size(max_packet_size);
+        bank.set_target(device_address, endpoint);
+    }
+
+    /// Starts a single transaction on a pipe.  For OUT and SETUP
+    /// tokens the data must already be in the pipe buffer.
+    fn start(&mut self, index: u8, token: u8, len: u16) {
+        let regs = pipe_registers(index);
+        regs.pstatusset.write(|w| w.pfreeze().set_bit());
+        regs.pintflag.write(|w| {
+            w.trcpt0().set_bit();
+            w.trcpt1().set_bit();
+            w.trfail().set_bit();
+            w.perr().set_bit();
+            w.txstp().set_bit();
+            w.stall().set_bit()
+        });
+        regs.pcfg
+            .modify(|_, w| unsafe { w.ptoken().bits(token) });
+
+        {
+            let bank = &mut self.desc.desc[usize::from(index)].bank[0];
+            bank.clear_status();
+            bank.set_byte_count(if token == TOKEN_IN { 0 } else { len });
+        }
+        atomic::compiler_fence(Ordering::SeqCst);
+
+        if token == TOKEN_IN {
+            // an empty bank is filled by the IN transaction
+            regs.pstatusclr.write(|w| w.bk0rdy().set_bit());
+        } else {
+            // a full bank is sent by the OUT or SETUP transaction
+            regs.pstatusset.write(|w| w.bk0rdy().set_bit());
+        }
+        regs.pstatusclr.write(|w| w.pfreeze().set_bit());
+    }
+
+    /// Returns the outcome of the transaction started on a pipe,
+    /// freezing the pipe once it has finished
+    fn status(&mut self, index: u8) -> nb::Result<(), HostError> {
+        let regs = pipe_registers(index);
+        let flags = regs.pintflag.read();
+        let result = if flags.stall().bit_is_set() {
+            Err(HostError::Stall)
+        } else if flags.perr().bit_is_set() || flags.trfail().bit_is_set() {
+            Err(HostError::TransferFailed)
+        } else if flags.trcpt0().bit_is_set() || flags.txstp().bit_is_set() {
+            Ok(())
+        } else if self.check_connected().is_err() {
+            Err(HostError::NotConnected)
+        } else {
+            return Err(nb::Error::WouldBlock);
+        };
+        regs.pstatusset.write(|w| w.pfreeze().set_bit());
+        atomic::compiler_fence(Ordering::SeqCst);
+        result.map_err(nb::Error::Other)
+    }
+
+    /// Waits for a control pipe transaction to finish
+    fn wait(&mut self, index: u8) -> Result<(), HostError> {
+        let start = self.frame_number();
+        loop {
+            match self.status(index) {
+                Err(nb::Error::WouldBlock) => {}
+                Err(nb::Error::Other(e)) => return Err(e),
+                Ok(()) => return Ok(()),
+            }
+            // the frame number is 11 bits wide
+            if self.frame_number().wrapping_sub(start) & 0x7ff > CONTROL_TIMEOUT_FRAMES {
+                pipe_registers(index)
+                    .pstatusset
+                    .write(|w| w.pfreeze().set_bit());
+                return Err(HostError::Timeout);
+            }
+        }
+    }
+
+    fn setup_stage(&mut self, setup: &SetupPacket) -> Result<(), HostError> {
+        self.check_connected()?;
+        self.write_buffer(0, &setup.to_bytes());
+        // SETUP is always sent with DATA0
+        clear_data_toggle(0);
+        self.start(0, TOKEN_SETUP, 8);
+        self.wait(0)
+    }
+
+    fn write_buffer(&mut self, index: u8, data: &[u8]) {
+        let buf = self.pipe_buffer(usize::from(index));
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len());
+        }
+    }
+
+    fn read_buffer(&mut self, index: u8, data: &mut [u8]) {
+        let buf = self.pipe_buffer(usize::from(index));
+        unsafe {
+            ptr::copy_nonoverlapping(buf, data.as_mut_ptr(), data.len());
+        }
+    }
+
+    /// Disables the peripheral and returns the parts
+    pub fn free(self) -> (DmPad, DpPad, USB) {
+        self.usb().ctrla.modify(|_, w| w.enable().clear_bit());
+        (self.dm_pad, self.dp_pad, self.usb)
+    }
+}
+
+/// Resets the data toggle of a pipe to DATA0.  The PAC omits the
+/// DTGL field of PSTATUSCLR, so the bit is written directly.
+fn clear_data_toggle(index: u8) {
+    pipe_registers(index)
+        .pstatusclr
+        .write(|w| unsafe { w.bits(1) });
+}
+
+fn get_descriptor(descriptor_type: u8, index: u8, length: u16) -> SetupPacket {
+    SetupPacket::new(
+        Direction::In,
+        RequestKind::Standard,
+        Recipient::Device,
+        request::GET_DESCRIPTOR,
+        (u16::from(descriptor_type) << 8) | u16::from(index),
+        0,
+        length,
+    )
+}
+
+/// Iterates over the descriptors in a configuration descriptor,
+/// yielding each one including its length and type bytes
+pub struct Descriptors<'b> {
+    buf: &'b [u8],
+}
+
+impl<'b> Descriptors<'b> {
+    pub fn new(buf: &'b [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'b> Iterator for Descriptors<'b> {
+    type Item = &'b [u8];
+
+    fn next(&mut self) -> Option<&'b [u8]> {
+        let len = usize::from(*self.buf.first()?);
+        if len < 2 || len > self.buf.len() {
+            self.buf = &[];
+            return None;
+        }
+        let (desc, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Some(desc)
+    }
+}
+
+/// Decodes an endpoint descriptor
+pub fn parse_endpoint(desc: &[u8]) -> Option<EndpointInfo> {
+    if desc.len() < 7 || desc[1] != descriptor_type::ENDPOINT {
+        return None;
+    }
+    let ep_type = match desc[3] & 0b11 {
+        0 => EndpointType::Control,
+        1 => EndpointType::Isochronous,
+        2 => EndpointType::Bulk,
+        _ => EndpointType::Interrupt,
+    };
+    Some(EndpointInfo {
+        address: EndpointAddress(desc[2]),
+        ep_type,
+        max_packet_size: (u16::from(desc[4]) | (u16::from(desc[5]) << 8)) & 0x7ff,
+        interval: desc[6],
+    })
+}
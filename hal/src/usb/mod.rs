@@ -1,12 +1,56 @@
 //! USB Device support
+//!
+//! `UsbDevice` drives the USB peripheral in device mode.  It owns the
+//! control endpoint and answers the standard requests from chapter 9 of
+//! the USB specification; the interfaces that make up the device are
+//! provided by types implementing `class::UsbClass`.
+//!
+//! The typical sequence is to create the `UsbDevice`, construct the
+//! classes (which allocate their interfaces and endpoints from it),
+//! call `attach` and then call `poll` with the classes from the USB
+//! interrupt handler, or frequently from the main loop.
+//!
+//! The same peripheral can instead act as a host; see `host::UsbHost`.
+//!
+//! When the host suspends the bus, or puts the link into L1 sleep with a
+//! Link Power Management request, `poll` reports it and `sleep` can be
+//! used to enter the deepest sleep mode that still allows the device to
+//! resume in time.  A device that was described with `remote_wakeup` can
+//! wake the host with `remote_wakeup` once the host has enabled it.
+//!
+//! The USB peripheral requires a 48Mhz clock; see
+//! `GenericClockController::with_usb_clock_recovery` for a crystal-less
+//! way to obtain an accurate one.
 
 use calibration::{usb_transn_cal, usb_transp_cal, usb_trim_cal};
 use clock;
-use core::ptr::null_mut;
+use core::cmp;
+use core::ptr::{self, null_mut};
+use core::sync::atomic::{self, Ordering};
+use cortex_m::peripheral::SCB;
 use gpio;
+use power::{self, SleepMode};
+use target_device::usb::device::{
+    EPCFG, EPINTENCLR, EPINTENSET, EPINTFLAG, EPSTATUS, EPSTATUSCLR, EPSTATUSSET,
+};
 use target_device::usb::DEVICE;
 use target_device::{PM, USB};
 
+pub mod cdc_acm;
+pub mod class;
+pub mod control;
+pub mod descriptor;
+pub mod dfu;
+pub mod endpoint;
+pub mod hid;
+pub mod host;
+pub mod msc;
+
+use self::class::{ControlIn, ControlOut, UsbClass};
+use self::control::{feature, request, Direction, Recipient, RequestKind, SetupPacket};
+use self::descriptor::{descriptor_type, DescriptorWriter, UsbDeviceInfo};
+use self::endpoint::{EndpointAddress, EndpointIn, EndpointInfo, EndpointOut, EndpointType};
+
 /// Emit SOF at 1Khz on this pin when configured as function G
 pub type SofPad = gpio::Pa23<gpio::PfG>;
 
@@ -16,14 +60,117 @@ pub type DmPad = gpio::Pa24<gpio::PfG>;
 /// USB D+ is connected here
 pub type DpPad = gpio::Pa25<gpio::PfG>;
 
-pub struct UsbDevice<'a> {
-    desc: &'a mut Descriptors,
-    dm_pad: DmPad,
-    dp_pad: DpPad,
-    usb: USB,
+/// The number of endpoints supported by the hardware, including
+/// the control endpoint
+const NUM_ENDPOINTS: usize = 8;
+
+/// The maximum packet size of the control endpoint
+const EP0_SIZE: u16 = 64;
+
+/// The size of the buffer used to assemble control transfer data.
+/// This bounds the size of the configuration descriptor and of
+/// the data stage of class specific requests.
+const CONTROL_BUFFER_SIZE: usize = 256;
+
+/// The number of words of endpoint memory used by the control endpoint
+pub const EP0_MEMORY_WORDS: usize = 2 * EP0_SIZE as usize / 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsbError {
+    /// The data does not fit in the buffer or packet
+    BufferOverflow,
+    /// All of the endpoints for the requested direction are in use
+    EndpointOverflow,
+    /// The endpoint memory supplied to `UsbDevice::new` is exhausted
+    EndpointMemoryOverflow,
+    /// The maximum packet size is not supported for the endpoint type
+    InvalidPacketSize,
+}
+
+/// The visible state of the device, as defined in chapter 9
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsbDeviceState {
+    /// Attached and reset, but not yet addressed by the host
+    Default,
+    /// The host has assigned an address
+    Addressed,
+    /// The host has selected the configuration; the class
+    /// endpoints are active
+    Configured,
+    /// The bus has been idle for 3ms
+    Suspended,
+    /// The host has put the link into L1 sleep
+    Sleep,
+}
+
+/// The bus events reported by `UsbDevice::poll`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BusEvents {
+    /// The host reset the bus
+    pub reset: bool,
+    /// The bus was suspended
+    pub suspend: bool,
+    /// The host put the link into L1 sleep with an LPM request
+    pub lpm_sleep: bool,
+    /// The bus resumed after being suspended or from L1 sleep
+    pub resume: bool,
+    /// A start of frame was received, with this frame number
+    pub sof: Option<u16>,
+}
+
+/// References to the registers of one endpoint.  The endpoint registers
+/// are laid out as separately named fields in the PAC.
+struct EndpointRegisters<'r> {
+    epcfg: &'r EPCFG,
+    epstatusclr: &'r EPSTATUSCLR,
+    epstatusset: &'r EPSTATUSSET,
+    epstatus: &'r EPSTATUS,
+    epintflag: &'r EPINTFLAG,
+    epintenclr: &'r EPINTENCLR,
+    epintenset: &'r EPINTENSET,
+}
+
+macro_rules! endpoint_registers {
+    ($($index:expr => ($epcfg:ident, $epstatusclr:ident, $epstatusset:ident, $epstatus:ident,
+                       $epintflag:ident, $epintenclr:ident, $epintenset:ident),)+) => {
+/// Returns the registers of an endpoint.  The endpoint handles only
+/// touch the registers of their own endpoint, which is what makes it
+/// safe for them to access the peripheral without owning it.
+fn endpoint_registers(index: u8) -> EndpointRegisters<'static> {
+    let usb = unsafe { &(*USB::ptr()).device };
+    match index {
+        $(
+            $index => EndpointRegisters {
+                epcfg: &usb.$epcfg,
+                epstatusclr: &usb.$epstatusclr,
+                epstatusset: &usb.$epstatusset,
+                epstatus: &usb.$epstatus,
+                epintflag: &usb.$epintflag,
+                epintenclr: &usb.$epintenclr,
+                epintenset: &usb.$epintenset,
+            },
+        )+
+        _ => unreachable!(),
+    }
+}
+    };
 }
 
-#[repr(packed)]
+endpoint_registers!(
+    0 => (epcfg0, epstatusclr0, epstatusset0, epstatus0, epintflag0, epintenclr0, epintenset0),
+    1 => (epcfg1, epstatusclr1, epstatusset1, epstatus1, epintflag1, epintenclr1, epintenset1),
+    2 => (epcfg2, epstatusclr2, epstatusset2, epstatus2, epintflag2, epintenclr2, epintenset2),
+    3 => (epcfg3, epstatusclr3, epstatusset3, epstatus3, epintflag3, epintenclr3, epintenset3),
+    4 => (epcfg4, epstatusclr4, epstatusset4, epstatus4, epintflag4, epintenclr4, epintenset4),
+    5 => (epcfg5, epstatusclr5, epstatusset5, epstatus5, epintflag5, epintenclr5, epintenset5),
+    6 => (epcfg6, epstatusclr6, epstatusset6, epstatus6, epintflag6, epintenclr6, epintenset6),
+    7 => (epcfg7, epstatusclr7, epstatusset7, epstatus7, epintflag7, epintenclr7, epintenset7),
+);
+
+/// An endpoint bank descriptor, read directly by the USB hardware.
+/// All accesses are volatile because the hardware updates the byte
+/// count and status as transfers complete.
+#[repr(C)]
 pub struct DeviceDescBank {
     /// endpoint data buffer, must be 32-bit aligned
     addr: *mut u8,
@@ -44,13 +191,36 @@ impl DeviceDescBank {
         }
     }
 
+    fn pcksize(&self) -> u32 {
+        unsafe { ptr::read_volatile(&self.pcksize) }
+    }
+
+    fn set_pcksize(&mut self, value: u32) {
+        unsafe { ptr::write_volatile(&mut self.pcksize, value) }
+    }
+
+    fn extreg(&self) -> u16 {
+        unsafe { ptr::read_volatile(&self.extreg) }
+    }
+
+    fn status_bk(&self) -> u8 {
+        unsafe { ptr::read_volatile(&self.status_bk) }
+    }
+
+    /// Sets the endpoint data buffer, which must be 32-bit aligned
+    /// and at least as large as the endpoint size
+    fn set_address(&mut self, addr: *mut u8) {
+        unsafe { ptr::write_volatile(&mut self.addr, addr) }
+    }
+
     /// This bit defines the automatic Zero Length Packet mode of the endpoint.
     /// When enabled, the USB module will manage the ZLP handshake by hardware.
     /// This bit is for IN endpoints only. When disabled the handshake should be
     /// managed by firmware.
     pub fn set_auto_zlp(&mut self, enable: bool) {
         let val = if enable { 1u32 << 31 } else { 0 };
-        self.pcksize = (self.pcksize & !(1 << 31)) | val;
+        let pcksize = (self.pcksize() & !(1 << 31)) | val;
+        self.set_pcksize(pcksize);
     }
 
     /// These bits contains the maximum packet size of the endpoint.
@@ -66,7 +236,8 @@ impl DeviceDescBank {
             1023 => 7,
             _ => 0,
         };
-        self.pcksize = (self.pcksize & !(7 << 28)) | (size << 28);
+        let pcksize = (self.pcksize() & !(7 << 28)) | (size << 28);
+        self.set_pcksize(pcksize);
     }
 
     /// For IN endpoints, MULTI_PACKET_SIZE holds the total number of bytes
@@ -75,7 +246,9 @@ impl DeviceDescBank {
     /// size for the complete transfer. This value must be a multiple of the
     /// maximum packet size.
     pub fn set_multi_packet_size(&mut self, size: u16) {
-        self.pcksize = (self.pcksize & !(0b11_1111_1111_1111 << 14)) | ((size as u32) << 14);
+        let pcksize =
+            (self.pcksize() & !(0b11_1111_1111_1111 << 14)) | ((size as u32) << 14);
+        self.set_pcksize(pcksize);
     }
 
     /// For IN endpoints, BYTE_COUNT holds the number of bytes to be sent in the
@@ -83,28 +256,34 @@ impl DeviceDescBank {
     /// holds the number of bytes received upon the last OUT or SETUP
     /// transaction.
     pub fn set_byte_count(&mut self, size: u16) {
-        self.pcksize = (self.pcksize & !0b11_1111_1111_1111) | (size as u32);
+        let pcksize = (self.pcksize() & !0b11_1111_1111_1111) | (size as u32);
+        self.set_pcksize(pcksize);
+    }
+
+    /// Returns the BYTE_COUNT field; see `set_byte_count`
+    pub fn byte_count(&self) -> u16 {
+        (self.pcksize() & 0b11_1111_1111_1111) as u16
     }
 
     pub fn link_state(&self) -> u8 {
         // every value except 1 (L1 sleep) is reserved
-        (self.extreg >> 4) as u8 & 0b1111
+        (self.extreg() >> 4) as u8 & 0b1111
     }
 
     /// best effort service latency
     pub fn besl(&self) -> u8 {
-        (self.extreg >> 8) as u8 & 0b1111
+        (self.extreg() >> 8) as u8 & 0b1111
     }
 
     pub fn remote_wake(&self) -> bool {
-        ((self.extreg >> 12) & 1) != 0
+        ((self.extreg() >> 12) & 1) != 0
     }
 
     /// These bits define the SUBPID field of a received extended token. These
     /// bits are updated when the USB has answered by an handshake token
     /// ACK to a LPM transaction
     pub fn subpid(&self) -> u8 {
-        self.extreg as u8 & 4
+        self.extreg() as u8 & 0b1111
     }
 
     /// This bit defines the Error Flow Status.  This bit is set when a Error
@@ -114,17 +293,17 @@ impl DeviceDescBank {
     /// valid. EPSTATUS.TRFAIL0 and EPSTATUS.TRFAIL1 should reflect the flow
     /// errors.
     pub fn error_flow(&self) -> bool {
-        (self.status_bk & 0b10) != 0
+        (self.status_bk() & 0b10) != 0
     }
 
     /// This bit defines the CRC Error Status.  This bit is set when a CRC
     /// error has been detected in an isochronous OUT endpoint bank
     pub fn crc_error(&self) -> bool {
-        (self.status_bk & 1) != 0
+        (self.status_bk() & 1) != 0
     }
 }
 
-#[repr(packed)]
+#[repr(C)]
 pub struct DeviceDescriptor {
     bank: [DeviceDescBank; 2],
 }
@@ -137,7 +316,9 @@ impl DeviceDescriptor {
     }
 }
 
-#[repr(packed)]
+/// The endpoint descriptor table pointed to by DESCADD.  It must not
+/// move while the `UsbDevice` exists, so is usually a `static mut`.
+#[repr(C)]
 pub struct Descriptors {
     desc: [DeviceDescriptor; 8],
 }
@@ -159,7 +340,57 @@ impl Descriptors {
     }
 }
 
+/// An allocated endpoint bank, retained so that the endpoint
+/// can be reconfigured after a bus reset
+#[derive(Clone, Copy)]
+struct EndpointSlot {
+    ep_type: EndpointType,
+    max_packet_size: u16,
+    buf: *mut u8,
+}
+
+/// The progress of the current control transfer
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ControlState {
+    Idle,
+    /// Sending the data stage from the control buffer.  `zlp` is set
+    /// if a zero length packet must follow to end the data stage.
+    DataIn { pos: usize, len: usize, zlp: bool },
+    /// Waiting for the host to acknowledge the IN data stage
+    StatusOut,
+    /// Receiving the data stage into the control buffer
+    DataOut { pos: usize },
+    /// Waiting for the zero length status packet to be collected
+    StatusIn,
+}
+
+pub struct UsbDevice<'a> {
+    desc: &'a mut Descriptors,
+    memory: &'a mut [u32],
+    memory_used: usize,
+    dm_pad: DmPad,
+    dp_pad: DpPad,
+    usb: USB,
+    info: UsbDeviceInfo<'a>,
+    endpoints: [[Option<EndpointSlot>; 2]; NUM_ENDPOINTS],
+    num_interfaces: u8,
+    state: UsbDeviceState,
+    suspended: bool,
+    lpm_sleep: bool,
+    interrupts_enabled: bool,
+    configuration: u8,
+    remote_wakeup_enabled: bool,
+    pending_address: Option<u8>,
+    setup: SetupPacket,
+    control: ControlState,
+    control_buf: [u8; CONTROL_BUFFER_SIZE],
+}
+
 impl<'a> UsbDevice<'a> {
+    /// Creates the device.  `memory` provides the endpoint buffers,
+    /// which the hardware accesses directly; it must hold at least
+    /// `EP0_MEMORY_WORDS` words for the control endpoint plus a
+    /// packet's worth for every endpoint allocated by the classes.
     pub fn new(
         _clock: &clock::UsbClock,
         pm: &mut PM,
@@ -167,14 +398,44 @@ impl<'a> UsbDevice<'a> {
         dp_pad: DpPad,
         usb: USB,
         desc: &'a mut Descriptors,
+        memory: &'a mut [u32],
+        info: UsbDeviceInfo<'a>,
     ) -> Self {
+        assert!(memory.len() >= EP0_MEMORY_WORDS);
         pm.apbbmask.modify(|_, w| w.usb_().set_bit());
-        Self {
+        pm.ahbmask.modify(|_, w| w.usb_().set_bit());
+
+        let mut device = Self {
             dm_pad,
             dp_pad,
             usb,
             desc,
+            memory,
+            memory_used: 0,
+            info,
+            endpoints: [[None; 2]; NUM_ENDPOINTS],
+            num_interfaces: 0,
+            state: UsbDeviceState::Default,
+            suspended: false,
+            lpm_sleep: false,
+            interrupts_enabled: false,
+            configuration: 0,
+            remote_wakeup_enabled: false,
+            pending_address: None,
+            setup: SetupPacket::parse(&[0; 8]),
+            control: ControlState::Idle,
+            control_buf: [0; CONTROL_BUFFER_SIZE],
+        };
+
+        for bank in 0..2 {
+            let buf = device.alloc_memory(EP0_SIZE).unwrap();
+            device.endpoints[0][bank] = Some(EndpointSlot {
+                ep_type: EndpointType::Control,
+                max_packet_size: EP0_SIZE,
+                buf,
+            });
         }
+        device
     }
 
     fn usb(&self) -> &DEVICE {
@@ -198,10 +459,937 @@ impl<'a> UsbDevice<'a> {
             w.mode().device();
             w.runstdby().set_bit()
         });
-        // full speed
-        usb.ctrlb.modify(|_, w| w.spdconf().fs());
+        // full speed, acknowledging LPM requests if they are supported
+        let lpm = self.info.link_power_management;
+        usb.ctrlb.modify(|_, w| {
+            if lpm {
+                w.lpmhdsk().ack();
+            }
+            w.spdconf().fs()
+        });
 
         usb.ctrla.modify(|_, w| w.enable().set_bit());
+        while usb.syncbusy.read().enable().bit_is_set() {}
+    }
+
+    /// Resets the hardware and connects the pull-up on D+, which
+    /// signals the host to begin enumeration.  All of the classes
+    /// should have been constructed before calling this.
+    pub fn attach(&mut self) {
+        self.reset();
+        self.bus_reset();
+        self.usb().ctrlb.modify(|_, w| w.detach().clear_bit());
+    }
+
+    /// Disconnects from the bus
+    pub fn detach(&mut self) {
+        self.usb().ctrlb.modify(|_, w| w.detach().set_bit());
+        self.state = UsbDeviceState::Default;
+        self.suspended = false;
+        self.lpm_sleep = false;
+    }
+
+    /// Enables the USB interrupt for bus reset, suspend and resume
+    /// and for endpoint activity.  This method only configures the
+    /// peripheral to trigger the interrupt; it does not configure the
+    /// interrupt controller or define an interrupt handler, which
+    /// should call `poll`.
+    pub fn enable_interrupts(&mut self) {
+        self.interrupts_enabled = true;
+        let lpm = self.info.link_power_management;
+        self.usb().intenset.write(|w| {
+            if lpm {
+                w.lpmsusp().set_bit();
+            }
+            w.eorst().set_bit();
+            w.eorsm().set_bit();
+            if self.suspended {
+                w.wakeup().set_bit()
+            } else {
+                w.suspend().set_bit()
+            }
+        });
+    }
+
+    /// Disables the USB interrupt
+    pub fn disable_interrupts(&mut self) {
+        self.interrupts_enabled = false;
+        self.usb().intenclr.write(|w| {
+            w.eorst().set_bit();
+            w.eorsm().set_bit();
+            w.suspend().set_bit();
+            w.wakeup().set_bit();
+            w.lpmsusp().set_bit();
+            w.sof().set_bit()
+        });
+    }
+
+    /// Enables or disables the interrupt at each start of frame.
+    /// This fires every millisecond while the bus is active.
+    pub fn enable_sof_interrupt(&mut self, enable: bool) {
+        if enable {
+            self.usb().intenset.write(|w| w.sof().set_bit());
+        } else {
+            self.usb().intenclr.write(|w| w.sof().set_bit());
+        }
+    }
+
+    /// Returns the number of the most recent frame
+    pub fn frame_number(&self) -> u16 {
+        self.usb().fnum.read().fnum().bits()
+    }
+
+    pub fn state(&self) -> UsbDeviceState {
+        if self.lpm_sleep {
+            UsbDeviceState::Sleep
+        } else if self.suspended {
+            UsbDeviceState::Suspended
+        } else {
+            self.state
+        }
+    }
+
+    /// Returns true if the host has enabled remote wakeup
+    pub fn remote_wakeup_enabled(&self) -> bool {
+        self.remote_wakeup_enabled
+    }
+
+    /// Signals resume to the host, to wake it from suspend or the
+    /// link from L1 sleep.  Returns false without signalling if the
+    /// link is active or the host has not permitted remote wakeup;
+    /// for L1 sleep the permission is part of the LPM request.
+    /// `poll` reports `resume` once the host has resumed the bus.
+    pub fn remote_wakeup(&mut self) -> bool {
+        let allowed = if self.lpm_sleep {
+            self.desc.desc[0].bank[0].remote_wake()
+        } else {
+            self.suspended && self.remote_wakeup_enabled
+        };
+        if allowed {
+            self.usb().ctrlb.modify(|_, w| w.uprsm().set_bit());
+        }
+        allowed
+    }
+
+    /// Returns the best effort service latency that the host sent in
+    /// the most recent LPM request.  The host waits for at least the
+    /// corresponding time after starting a resume before it expects
+    /// the device to respond.
+    pub fn lpm_besl(&self) -> u8 {
+        self.desc.desc[0].bank[0].besl()
+    }
+
+    /// Returns the deepest sleep mode that is compatible with the
+    /// current link state, or `None` if the link is active.  STANDBY
+    /// is only chosen when the host allows at least 1ms to resume,
+    /// since the 48Mhz clock has to restart before the device can
+    /// respond.
+    pub fn sleep_mode(&self) -> Option<SleepMode> {
+        if self.lpm_sleep {
+            // BESL values of 6 and above correspond to 1ms or more
+            if self.lpm_besl() >= 6 {
+                Some(SleepMode::Standby)
+            } else {
+                Some(SleepMode::Idle2)
+            }
+        } else if self.suspended {
+            Some(SleepMode::Standby)
+        } else {
+            None
+        }
+    }
+
+    /// Sleeps in the mode given by `sleep_mode` until an interrupt
+    /// occurs, or returns immediately if the link is active.  The
+    /// USB interrupt must be enabled with `enable_interrupts` so that
+    /// the resume from the host wakes the device.
+    pub fn sleep(&mut self, pm: &mut PM, scb: &mut SCB) {
+        if let Some(mode) = self.sleep_mode() {
+            power::sleep(mode, pm, scb);
+        }
+    }
+
+    /// Allocates an interface number.  Classes call this when they
+    /// are constructed and use the result in their descriptors.
+    pub fn alloc_interface(&mut self) -> u8 {
+        let number = self.num_interfaces;
+        self.num_interfaces += 1;
+        number
+    }
+
+    /// Allocates an IN endpoint.  The endpoint is enabled when the
+    /// host selects the configuration.
+    pub fn alloc_endpoint_in(
+        &mut self,
+        ep_type: EndpointType,
+        max_packet_size: u16,
+        interval: u8,
+    ) -> Result<EndpointIn<'a>, UsbError> {
+        let info = self.alloc_endpoint(1, ep_type, max_packet_size, interval)?;
+        let (bank, buf) = self.endpoint_memory(info.address);
+        Ok(EndpointIn::new(info, bank, buf))
+    }
+
+    /// Allocates an OUT endpoint.  The endpoint is enabled when the
+    /// host selects the configuration.
+    pub fn alloc_endpoint_out(
+        &mut self,
+        ep_type: EndpointType,
+        max_packet_size: u16,
+        interval: u8,
+    ) -> Result<EndpointOut<'a>, UsbError> {
+        let info = self.alloc_endpoint(0, ep_type, max_packet_size, interval)?;
+        let (bank, buf) = self.endpoint_memory(info.address);
+        Ok(EndpointOut::new(info, bank, buf))
+    }
+
+    fn alloc_endpoint(
+        &mut self,
+        bank: usize,
+        ep_type: EndpointType,
+        max_packet_size: u16,
+        interval: u8,
+    ) -> Result<EndpointInfo, UsbError> {
+        let valid = match (ep_type, max_packet_size) {
+            (EndpointType::Control, _) => false,
+            (_, 8) | (_, 16) | (_, 32) | (_, 64) => true,
+            (EndpointType::Isochronous, 128)
+            | (EndpointType::Isochronous, 256)
+            | (EndpointType::Isochronous, 512)
+            | (EndpointType::Isochronous, 1023) => true,
+            _ => false,
+        };
+        if !valid {
+            return Err(UsbError::InvalidPacketSize);
+        }
+
+        let index = match (1..NUM_ENDPOINTS).find(|&i| self.endpoints[i][bank].is_none()) {
+            Some(index) => index,
+            None => return Err(UsbError::EndpointOverflow),
+        };
+        let buf = self.alloc_memory(max_packet_size)?;
+        self.endpoints[index][bank] = Some(EndpointSlot {
+            ep_type,
+            max_packet_size,
+            buf,
+        });
+
+        let address = if bank == 1 {
+            EndpointAddress::new_in(index as u8)
+        } else {
+            EndpointAddress::new_out(index as u8)
+        };
+        Ok(EndpointInfo {
+            address,
+            ep_type,
+            max_packet_size,
+            interval,
+        })
+    }
+
+    /// Carves a 32-bit aligned buffer out of the endpoint memory
+    fn alloc_memory(&mut self, size: u16) -> Result<*mut u8, UsbError> {
+        let words = (size as usize + 3) / 4;
+        if self.memory_used + words > self.memory.len() {
+            return Err(UsbError::EndpointMemoryOverflow);
+        }
+        let buf = unsafe { self.memory.as_mut_ptr().offset(self.memory_used as isize) };
+        self.memory_used += words;
+        Ok(buf as *mut u8)
+    }
+
+    fn endpoint_memory(&mut self, address: EndpointAddress) -> (*mut DeviceDescBank, *mut u8) {
+        let index = address.index() as usize;
+        let bank = address.bank();
+        let buf = self.endpoints[index][bank].map(|s| s.buf).unwrap_or(null_mut());
+        (&mut self.desc.desc[index].bank[bank] as *mut _, buf)
+    }
+
+    /// Points a descriptor bank at its buffer and enables it in EPCFG
+    fn configure_bank(&mut self, index: usize, bank: usize) {
+        let slot = match self.endpoints[index][bank] {
+            Some(slot) => slot,
+            None => return,
+        };
+        {
+            let desc = &mut self.desc.desc[index].bank[bank];
+            desc.set_address(slot.buf);
+            desc.set_endpoint_size(slot.max_packet_size);
+            desc.set_auto_zlp(false);
+            desc.set_byte_count(0);
+            desc.set_multi_packet_size(if bank == 0 { slot.max_packet_size } else { 0 });
+        }
+
+        let regs = endpoint_registers(index as u8);
+        let eptype = slot.ep_type.eptype();
+        if bank == 0 {
+            regs.epcfg.modify(|_, w| unsafe { w.eptype0().bits(eptype) });
+            regs.epstatusclr.write(|w| {
+                w.bk0rdy().set_bit();
+                w.stallrq0().set_bit();
+                w.dtglout().set_bit()
+            });
+            regs.epintenset.write(|w| {
+                if index == 0 {
+                    w.rxstp().set_bit();
+                }
+                w.trcpt0().set_bit()
+            });
+        } else {
+            regs.epcfg.modify(|_, w| unsafe { w.eptype1().bits(eptype) });
+            regs.epstatusclr.write(|w| {
+                w.bk1rdy().set_bit();
+                w.stallrq1().set_bit();
+                w.dtglin().set_bit()
+            });
+            regs.epintenset.write(|w| w.trcpt1().set_bit());
+        }
+    }
+
+    /// Enables or disables all of the class endpoints
+    fn configure_endpoints(&mut self, enable: bool) {
+        for index in 1..NUM_ENDPOINTS {
+            let regs = endpoint_registers(index as u8);
+            regs.epcfg.write(|w| unsafe {
+                w.eptype0().bits(0);
+                w.eptype1().bits(0)
+            });
+            regs.epintenclr.write(|w| {
+                w.trcpt0().set_bit();
+                w.trcpt1().set_bit()
+            });
+            if enable {
+                self.configure_bank(index, 0);
+                self.configure_bank(index, 1);
+            }
+        }
+    }
+
+    /// Returns to the default state after the host resets the bus
+    fn bus_reset(&mut self) {
+        self.state = UsbDeviceState::Default;
+        self.suspended = false;
+        self.lpm_sleep = false;
+        self.configuration = 0;
+        self.remote_wakeup_enabled = false;
+        self.pending_address = None;
+        self.control = ControlState::Idle;
+
+        self.usb().dadd.write(|w| unsafe { w.bits(0) });
+        self.configure_endpoints(false);
+        self.configure_bank(0, 0);
+        self.configure_bank(0, 1);
+    }
+
+    /// Services the peripheral.  This processes bus events, runs the
+    /// control endpoint and notifies the classes of endpoint activity.
+    /// It should be called from the USB interrupt handler, or at least
+    /// once per millisecond if interrupts are not used.
+    pub fn poll(&mut self, classes: &mut [&mut UsbClass]) -> BusEvents {
+        let mut events = BusEvents::default();
+        let flags = self.usb().intflag.read();
+
+        if flags.eorst().bit_is_set() {
+            self.usb().intflag.write(|w| w.eorst().set_bit());
+            self.bus_reset();
+            self.set_suspend_interrupts(false);
+            for class in classes.iter_mut() {
+                class.reset();
+            }
+            events.reset = true;
+        }
+
+        if flags.suspend().bit_is_set() {
+            self.usb().intflag.write(|w| w.suspend().set_bit());
+            if !self.suspended {
+                self.suspended = true;
+                self.set_suspend_interrupts(true);
+                events.suspend = true;
+            }
+        }
+
+        if flags.lpmsusp().bit_is_set() {
+            self.usb().intflag.write(|w| w.lpmsusp().set_bit());
+            if !self.suspended {
+                self.suspended = true;
+                self.lpm_sleep = true;
+                self.set_suspend_interrupts(true);
+                events.lpm_sleep = true;
+            }
+        }
+
+        if flags.wakeup().bit_is_set() || flags.eorsm().bit_is_set() {
+            self.usb().intflag.write(|w| {
+                w.wakeup().set_bit();
+                w.eorsm().set_bit();
+                w.uprsm().set_bit()
+            });
+            if self.suspended {
+                self.suspended = false;
+                self.lpm_sleep = false;
+                self.set_suspend_interrupts(false);
+                events.resume = true;
+            }
+        }
+
+        if flags.sof().bit_is_set() {
+            self.usb().intflag.write(|w| w.sof().set_bit());
+            events.sof = Some(self.frame_number());
+        }
+
+        self.poll_control(classes);
+        self.poll_endpoints(classes);
+        events
+    }
+
+    /// While suspended only the wakeup interrupt is of interest;
+    /// otherwise the suspend interrupt would be raised repeatedly
+    fn set_suspend_interrupts(&mut self, suspended: bool) {
+        if !self.interrupts_enabled {
+            return;
+        }
+        let usb = self.usb();
+        if suspended {
+            usb.intenclr.write(|w| w.suspend().set_bit());
+            usb.intflag.write(|w| w.wakeup().set_bit());
+            usb.intenset.write(|w| w.wakeup().set_bit());
+        } else {
+            usb.intenclr.write(|w| w.wakeup().set_bit());
+            usb.intflag.write(|w| w.suspend().set_bit());
+            usb.intenset.write(|w| w.suspend().set_bit());
+        }
+    }
+
+    fn poll_endpoints(&mut self, classes: &mut [&mut UsbClass]) {
+        for index in 1..NUM_ENDPOINTS as u8 {
+            let regs = endpoint_registers(index);
+            let flags = regs.epintflag.read();
+            if flags.bits() == 0 {
+                continue;
+            }
+            regs.epintflag.write(|w| {
+                w.trcpt0().set_bit();
+                w.trcpt1().set_bit();
+                w.trfail0().set_bit();
+                w.trfail1().set_bit();
+                w.stall0().set_bit();
+                w.stall1().set_bit()
+            });
+
+            if flags.trcpt0().bit_is_set() {
+                for class in classes.iter_mut() {
+                    class.endpoint_out(EndpointAddress::new_out(index));
+                }
+            }
+            if flags.trcpt1().bit_is_set() {
+                for class in classes.iter_mut() {
+                    class.endpoint_in_complete(EndpointAddress::new_in(index));
+                }
+            }
+        }
+    }
+
+    fn poll_control(&mut self, classes: &mut [&mut UsbClass]) {
+        let regs = endpoint_registers(0);
+        let flags = regs.epintflag.read();
+
+        if flags.rxstp().bit_is_set() {
+            // A SETUP packet aborts whatever transfer was in progress
+            regs.epintflag.write(|w| {
+                w.rxstp().set_bit();
+                w.trcpt0().set_bit();
+                w.trcpt1().set_bit()
+            });
+            regs.epstatusclr.write(|w| w.bk1rdy().set_bit());
+            self.handle_setup(classes);
+            return;
+        }
+
+        if flags.trcpt1().bit_is_set() {
+            regs.epintflag.write(|w| w.trcpt1().set_bit());
+            self.control_in_complete();
+        }
+
+        if flags.trcpt0().bit_is_set() {
+            regs.epintflag.write(|w| w.trcpt0().set_bit());
+            self.control_out_received(classes);
+        }
+
+        regs.epintflag.write(|w| {
+            w.trfail0().set_bit();
+            w.trfail1().set_bit();
+            w.stall0().set_bit();
+            w.stall1().set_bit()
+        });
+    }
+
+    fn ep0_buf(&self, bank: usize) -> *mut u8 {
+        self.endpoints[0][bank].map(|s| s.buf).unwrap_or(null_mut())
+    }
+
+    /// Makes the control OUT bank available to receive the next packet
+    fn ep0_receive(&mut self) {
+        {
+            let desc = &mut self.desc.desc[0].bank[0];
+            desc.set_byte_count(0);
+            desc.set_multi_packet_size(EP0_SIZE);
+        }
+        endpoint_registers(0)
+            .epstatusclr
+            .write(|w| w.bk0rdy().set_bit());
+    }
+
+    /// Sends a packet from the control buffer on the control IN bank
+    fn ep0_send(&mut self, start: usize, len: usize) {
+        let buf = self.ep0_buf(1);
+        unsafe {
+            ptr::copy_nonoverlapping(self.control_buf[start..].as_ptr(), buf, len);
+        }
+        {
+            let desc = &mut self.desc.desc[0].bank[1];
+            desc.set_multi_packet_size(0);
+            desc.set_byte_count(len as u16);
+        }
+        atomic::compiler_fence(Ordering::SeqCst);
+        endpoint_registers(0)
+            .epstatusset
+            .write(|w| w.bk1rdy().set_bit());
+    }
+
+    /// Sends the zero length status packet that completes a request
+    fn ep0_status(&mut self) {
+        self.ep0_send(0, 0);
+        self.control = ControlState::StatusIn;
+    }
+
+    /// Signals that a request is not supported.  The hardware clears
+    /// the stall when the next SETUP packet arrives.
+    fn ep0_stall(&mut self) {
+        endpoint_registers(0).epstatusset.write(|w| {
+            w.stallrq0().set_bit();
+            w.stallrq1().set_bit()
+        });
+        self.control = ControlState::Idle;
+    }
+
+    fn handle_setup(&mut self, classes: &mut [&mut UsbClass]) {
+        let mut packet = [0u8; 8];
+        unsafe {
+            ptr::copy_nonoverlapping(self.ep0_buf(0), packet.as_mut_ptr(), 8);
+        }
+        self.setup = SetupPacket::parse(&packet);
+        self.control = ControlState::Idle;
+        self.ep0_receive();
+
+        let setup = self.setup;
+        if setup.direction() == Direction::In {
+            match self.control_in_request(&setup, classes) {
+                Some(len) => {
+                    let len = cmp::min(len, setup.length as usize);
+                    let zlp = len < setup.length as usize && len % EP0_SIZE as usize == 0;
+                    self.control = ControlState::DataIn { pos: 0, len, zlp };
+                    self.control_in_next();
+                }
+                None => self.ep0_stall(),
+            }
+        } else if setup.length == 0 {
+            if self.control_out_request(&setup, 0, classes) {
+                self.ep0_status();
+            } else {
+                self.ep0_stall();
+            }
+        } else if setup.length as usize > CONTROL_BUFFER_SIZE {
+            self.ep0_stall();
+        } else {
+            self.control = ControlState::DataOut { pos: 0 };
+        }
+    }
+
+    /// Sends the next packet of the IN data stage
+    fn control_in_next(&mut self) {
+        if let ControlState::DataIn { pos, len, zlp } = self.control {
+            let count = cmp::min(len - pos, EP0_SIZE as usize);
+            self.ep0_send(pos, count);
+            self.control = ControlState::DataIn {
+                pos: pos + count,
+                len,
+                zlp: zlp && count != 0,
+            };
+        }
+    }
+
+    fn control_in_complete(&mut self) {
+        match self.control {
+            ControlState::DataIn { pos, len, zlp } => {
+                if pos < len || zlp {
+                    self.control_in_next();
+                } else {
+                    self.control = ControlState::StatusOut;
+                }
+            }
+            ControlState::StatusIn => {
+                // The new address only takes effect once the
+                // status stage of SET_ADDRESS has completed
+                if let Some(address) = self.pending_address.take() {
+                    self.usb().dadd.write(|w| unsafe {
+                        w.dadd().bits(address);
+                        w.adden().bit(address != 0)
+                    });
+                    self.state = if address != 0 {
+                        UsbDeviceState::Addressed
+                    } else {
+                        UsbDeviceState::Default
+                    };
+                }
+                self.control = ControlState::Idle;
+            }
+            _ => {}
+        }
+    }
+
+    fn control_out_received(&mut self, classes: &mut [&mut UsbClass]) {
+        let count = self.desc.desc[0].bank[0].byte_count() as usize;
+        match self.control {
+            ControlState::DataOut { pos } => {
+                let expected = self.setup.length as usize;
+                let count = cmp::min(count, expected - pos);
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        self.ep0_buf(0),
+                        self.control_buf[pos..].as_mut_ptr(),
+                        count,
+                    );
+                }
+                self.ep0_receive();
+
+                let pos = pos + count;
+                if pos < expected && count == EP0_SIZE as usize {
+                    self.control = ControlState::DataOut { pos };
+                    return;
+                }
+                let setup = self.setup;
+                if self.control_out_request(&setup, pos, classes) {
+                    self.ep0_status();
+                } else {
+                    self.ep0_stall();
+                }
+            }
+            _ => {
+                // The status stage of an IN transfer; the host may
+                // also end the data stage early
+                self.ep0_receive();
+                self.control = ControlState::Idle;
+            }
+        }
+    }
+
+    /// Handles a request with an IN data stage, returning the number
+    /// of bytes placed in the control buffer, or None to stall
+    fn control_in_request(
+        &mut self,
+        setup: &SetupPacket,
+        classes: &mut [&mut UsbClass],
+    ) -> Option<usize> {
+        if setup.kind() == RequestKind::Standard && setup.recipient() == Recipient::Device {
+            return self.standard_device_in(setup, classes);
+        }
+        for class in classes.iter_mut() {
+            match class.control_in(setup, &mut self.control_buf) {
+                ControlIn::Data(len) => return Some(len),
+                ControlIn::Rejected => return None,
+                ControlIn::Ignored => {}
+            }
+        }
+        if setup.kind() == RequestKind::Standard {
+            self.standard_in(setup)
+        } else {
+            None
+        }
+    }
+
+    /// Handles a request with an OUT or no data stage, whose data has
+    /// been received into the control buffer.  Returns false to stall.
+    fn control_out_request(
+        &mut self,
+        setup: &SetupPacket,
+        len: usize,
+        classes: &mut [&mut UsbClass],
+    ) -> bool {
+        if setup.kind() == RequestKind::Standard && setup.recipient() == Recipient::Device {
+            return self.standard_device_out(setup, classes);
+        }
+        for class in classes.iter_mut() {
+            match class.control_out(setup, &self.control_buf[..len]) {
+                ControlOut::Accepted => return true,
+                ControlOut::Rejected => return false,
+                ControlOut::Ignored => {}
+            }
+        }
+        setup.kind() == RequestKind::Standard && self.standard_out(setup)
+    }
+
+    fn standard_device_in(
+        &mut self,
+        setup: &SetupPacket,
+        classes: &mut [&mut UsbClass],
+    ) -> Option<usize> {
+        match setup.request {
+            request::GET_STATUS => {
+                let mut status = 0;
+                if self.info.self_powered {
+                    status |= 1;
+                }
+                if self.remote_wakeup_enabled {
+                    status |= 2;
+                }
+                self.control_buf[0] = status;
+                self.control_buf[1] = 0;
+                Some(2)
+            }
+            request::GET_CONFIGURATION => {
+                self.control_buf[0] = self.configuration;
+                Some(1)
+            }
+            request::GET_DESCRIPTOR => match setup.value_high() {
+                descriptor_type::DEVICE => Some(self.device_descriptor()),
+                descriptor_type::CONFIGURATION => self.configuration_descriptor(classes),
+                descriptor_type::STRING => self.string_descriptor(setup.value_low(), classes),
+                descriptor_type::BOS if self.info.link_power_management => {
+                    Some(self.bos_descriptor())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Handles the standard requests addressed to an interface
+    /// or endpoint that no class has claimed
+    fn standard_in(&mut self, setup: &SetupPacket) -> Option<usize> {
+        match (setup.recipient(), setup.request) {
+            (Recipient::Interface, request::GET_STATUS) => {
+                self.control_buf[0] = 0;
+                self.control_buf[1] = 0;
+                Some(2)
+            }
+            (Recipient::Interface, request::GET_INTERFACE) => {
+                self.control_buf[0] = 0;
+                Some(1)
+            }
+            (Recipient::Endpoint, request::GET_STATUS) => {
+                let address = self.valid_endpoint(setup.index)?;
+                self.control_buf[0] = endpoint::is_stalled(address) as u8;
+                self.control_buf[1] = 0;
+                Some(2)
+            }
+            _ => None,
+        }
+    }
+
+    fn standard_device_out(&mut self, setup: &SetupPacket, classes: &mut [&mut UsbClass]) -> bool {
+        match setup.request {
+            request::SET_ADDRESS => {
+                self.pending_address = Some(setup.value as u8 & 0x7f);
+                true
+            }
+            request::SET_CONFIGURATION => {
+                if self.state == UsbDeviceState::Default {
+                    return false;
+                }
+                let configured = match setup.value {
+                    0 => false,
+                    1 => true,
+                    _ => return false,
+                };
+                self.configure_endpoints(configured);
+                self.configuration = setup.value as u8;
+                self.state = if configured {
+                    UsbDeviceState::Configured
+                } else {
+                    UsbDeviceState::Addressed
+                };
+                for class in classes.iter_mut() {
+                    class.configured(configured);
+                }
+                true
+            }
+            request::SET_FEATURE | request::CLEAR_FEATURE => {
+                if setup.value == feature::DEVICE_REMOTE_WAKEUP && self.info.remote_wakeup {
+                    self.remote_wakeup_enabled = setup.request == request::SET_FEATURE;
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn standard_out(&mut self, setup: &SetupPacket) -> bool {
+        match (setup.recipient(), setup.request) {
+            (Recipient::Interface, request::SET_INTERFACE) => setup.value == 0,
+            (Recipient::Endpoint, request::SET_FEATURE)
+            | (Recipient::Endpoint, request::CLEAR_FEATURE) => {
+                if setup.value != feature::ENDPOINT_HALT {
+                    return false;
+                }
+                match self.valid_endpoint(setup.index) {
+                    Some(address) => {
+                        endpoint::set_stalled(address, setup.request == request::SET_FEATURE);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the address named by wIndex if it is an allocated
+    /// class endpoint
+    fn valid_endpoint(&self, index: u16) -> Option<EndpointAddress> {
+        let address = EndpointAddress(index as u8);
+        let i = address.index() as usize;
+        if i != 0 && i < NUM_ENDPOINTS && self.endpoints[i][address.bank()].is_some() {
+            Some(address)
+        } else {
+            None
+        }
+    }
+
+    fn device_descriptor(&mut self) -> usize {
+        let info = self.info;
+        let mut writer = DescriptorWriter::new(&mut self.control_buf);
+        writer
+            .write(
+                descriptor_type::DEVICE,
+                &[
+                    // bcdUSB 2.00, or 2.01 to tell the host to look for
+                    // the BOS descriptor that advertises LPM
+                    if info.link_power_management { 0x01 } else { 0x00 },
+                    0x02,
+                    info.device_class,
+                    info.device_sub_class,
+                    info.device_protocol,
+                    EP0_SIZE as u8,
+                    info.vendor_id as u8,
+                    (info.vendor_id >> 8) as u8,
+                    info.product_id as u8,
+                    (info.product_id >> 8) as u8,
+                    info.device_release as u8,
+                    (info.device_release >> 8) as u8,
+                    descriptor::MANUFACTURER_STRING,
+                    descriptor::PRODUCT_STRING,
+                    if info.serial_number.is_some() {
+                        descriptor::SERIAL_NUMBER_STRING
+                    } else {
+                        0
+                    },
+                    1, // bNumConfigurations
+                ],
+            )
+            .unwrap();
+        writer.position()
+    }
+
+    /// The binary device object store, which holds the USB 2.0
+    /// extension capability advertising LPM support
+    fn bos_descriptor(&mut self) -> usize {
+        let mut writer = DescriptorWriter::new(&mut self.control_buf);
+        writer
+            .write(
+                descriptor_type::BOS,
+                &[
+                    12, 0, // wTotalLength
+                    1, // bNumDeviceCaps
+                ],
+            )
+            .unwrap();
+        writer
+            .write(
+                descriptor_type::DEVICE_CAPABILITY,
+                &[
+                    descriptor::capability_type::USB_2_0_EXTENSION,
+                    0x02, // bmAttributes: LPM
+                    0,
+                    0,
+                    0,
+                ],
+            )
+            .unwrap();
+        writer.position()
+    }
+
+    fn configuration_descriptor(&mut self, classes: &mut [&mut UsbClass]) -> Option<usize> {
+        let mut attributes = 0x80;
+        if self.info.self_powered {
+            attributes |= 0x40;
+        }
+        if self.info.remote_wakeup {
+            attributes |= 0x20;
+        }
+        let max_power = cmp::min(self.info.max_power_ma / 2, 250) as u8;
+
+        let len = {
+            let mut writer = DescriptorWriter::new(&mut self.control_buf);
+            writer
+                .write(
+                    descriptor_type::CONFIGURATION,
+                    &[
+                        0,
+                        0, // wTotalLength, filled in below
+                        self.num_interfaces,
+                        1, // bConfigurationValue
+                        0, // iConfiguration
+                        attributes,
+                        max_power,
+                    ],
+                )
+                .ok()?;
+            for class in classes.iter() {
+                class.configuration_descriptors(&mut writer).ok()?;
+            }
+            writer.position()
+        };
+        self.control_buf[2] = len as u8;
+        self.control_buf[3] = (len >> 8) as u8;
+        Some(len)
+    }
+
+    fn string_descriptor(&mut self, index: u8, classes: &mut [&mut UsbClass]) -> Option<usize> {
+        if index == 0 {
+            let lang = descriptor::LANGUAGE_ID_EN_US;
+            let mut writer = DescriptorWriter::new(&mut self.control_buf);
+            writer
+                .write(descriptor_type::STRING, &[lang as u8, (lang >> 8) as u8])
+                .ok()?;
+            return Some(writer.position());
+        }
+
+        let string = match index {
+            descriptor::MANUFACTURER_STRING => Some(self.info.manufacturer),
+            descriptor::PRODUCT_STRING => Some(self.info.product),
+            descriptor::SERIAL_NUMBER_STRING => self.info.serial_number,
+            _ => None,
+        };
+        let string = match string {
+            Some(string) => string,
+            None => classes.iter().filter_map(|c| c.string(index)).next()?,
+        };
+
+        // String descriptors are UTF-16LE, limited to 255 bytes
+        let mut pos = 2;
+        for unit in string.encode_utf16() {
+            if pos + 2 > 255 {
+                break;
+            }
+            self.control_buf[pos] = unit as u8;
+            self.control_buf[pos + 1] = (unit >> 8) as u8;
+            pos += 2;
+        }
+        self.control_buf[0] = pos as u8;
+        self.control_buf[1] = descriptor_type::STRING;
+        Some(pos)
     }
 
     pub fn free(self) -> (DmPad, DpPad, USB) {
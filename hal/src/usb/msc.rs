@@ -0,0 +1,496 @@
+//! USB Mass Storage class.
+//!
+//! `MassStorage` implements the bulk-only transport with the SCSI
+//! transparent command set, which every mainstream operating system
+//! mounts as a removable drive.  The storage itself is provided by an
+//! implementation of `BlockDevice`; the host is responsible for the
+//! filesystem, so the device simply appears empty until it is formatted.
+//!
+//! Blocks are read and written from within `UsbDevice::poll`, so a slow
+//! block device (such as SPI flash that must erase before writing) will
+//! delay the servicing of the other classes while it works.
+use core::cmp;
+
+use super::class::{ControlIn, ControlOut, UsbClass};
+use super::control::{feature, request, Recipient, RequestKind, SetupPacket};
+use super::descriptor::DescriptorWriter;
+use super::endpoint::{EndpointAddress, EndpointIn, EndpointOut, EndpointType};
+use super::{UsbDevice, UsbError};
+
+/// The size of the blocks exchanged with the host and the block device
+pub const BLOCK_SIZE: usize = 512;
+
+/// A store of fixed size blocks
+pub trait BlockDevice {
+    type Error;
+
+    /// The number of `BLOCK_SIZE` blocks on the device
+    fn block_count(&self) -> u32;
+
+    fn read_block(&mut self, lba: u32, block: &mut [u8; BLOCK_SIZE]) -> Result<(), Self::Error>;
+
+    fn write_block(&mut self, lba: u32, block: &[u8; BLOCK_SIZE]) -> Result<(), Self::Error>;
+
+    /// Commits any writes that the device has buffered.  This is called
+    /// at the end of each write command and when the host asks for the
+    /// cache to be synchronized.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+const USB_CLASS_MSC: u8 = 0x08;
+const MSC_SUBCLASS_SCSI: u8 = 0x06;
+const MSC_PROTOCOL_BULK_ONLY: u8 = 0x50;
+
+const MSC_REQ_GET_MAX_LUN: u8 = 0xfe;
+const MSC_REQ_RESET: u8 = 0xff;
+
+const PACKET_SIZE: usize = 64;
+
+const CBW_SIGNATURE: u32 = 0x4342_5355;
+const CBW_LEN: usize = 31;
+const CSW_SIGNATURE: u32 = 0x5342_5355;
+
+const CSW_PASSED: u8 = 0;
+const CSW_FAILED: u8 = 1;
+
+const SCSI_TEST_UNIT_READY: u8 = 0x00;
+const SCSI_REQUEST_SENSE: u8 = 0x03;
+const SCSI_INQUIRY: u8 = 0x12;
+const SCSI_MODE_SENSE_6: u8 = 0x1a;
+const SCSI_START_STOP_UNIT: u8 = 0x1b;
+const SCSI_PREVENT_ALLOW_MEDIUM_REMOVAL: u8 = 0x1e;
+const SCSI_READ_FORMAT_CAPACITIES: u8 = 0x23;
+const SCSI_READ_CAPACITY_10: u8 = 0x25;
+const SCSI_READ_10: u8 = 0x28;
+const SCSI_WRITE_10: u8 = 0x2a;
+const SCSI_VERIFY_10: u8 = 0x2f;
+const SCSI_SYNCHRONIZE_CACHE_10: u8 = 0x35;
+
+const SENSE_NO_SENSE: u8 = 0x00;
+const SENSE_MEDIUM_ERROR: u8 = 0x03;
+const SENSE_ILLEGAL_REQUEST: u8 = 0x05;
+
+const ASC_NONE: u8 = 0x00;
+const ASC_WRITE_ERROR: u8 = 0x0c;
+const ASC_UNRECOVERED_READ_ERROR: u8 = 0x11;
+const ASC_INVALID_COMMAND: u8 = 0x20;
+const ASC_LBA_OUT_OF_RANGE: u8 = 0x21;
+
+/// The INQUIRY response: a removable direct access device
+/// followed by the vendor, product and revision strings
+const INQUIRY_RESPONSE: [u8; 36] = [
+    0x00, 0x80, 0x04, 0x02, 31, 0, 0, 0, // header
+    b'a', b't', b's', b'a', b'm', b'd', b'2', b'1', // vendor
+    b'M', b'a', b's', b's', b' ', b'S', b't', b'o', // product
+    b'r', b'a', b'g', b'e', b' ', b' ', b' ', b' ',
+    b'0', b'.', b'1', b' ', // revision
+];
+
+fn be32(buf: &[u8]) -> u32 {
+    (u32::from(buf[0]) << 24) | (u32::from(buf[1]) << 16) | (u32::from(buf[2]) << 8)
+        | u32::from(buf[3])
+}
+
+fn put_be32(buf: &mut [u8], value: u32) {
+    buf[0] = (value >> 24) as u8;
+    buf[1] = (value >> 16) as u8;
+    buf[2] = (value >> 8) as u8;
+    buf[3] = value as u8;
+}
+
+fn le32(buf: &[u8]) -> u32 {
+    u32::from(buf[0]) | (u32::from(buf[1]) << 8) | (u32::from(buf[2]) << 16)
+        | (u32::from(buf[3]) << 24)
+}
+
+fn put_le32(buf: &mut [u8], value: u32) {
+    buf[0] = value as u8;
+    buf[1] = (value >> 8) as u8;
+    buf[2] = (value >> 16) as u8;
+    buf[3] = (value >> 24) as u8;
+}
+
+/// The phase of the bulk-only transport
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Waiting for a command block wrapper
+    Command,
+    /// Sending `buf[pos..len]`; further blocks of a READ(10)
+    /// follow while `blocks` is nonzero
+    DataIn { pos: usize, len: usize },
+    /// Receiving blocks of a WRITE(10) into `buf`
+    DataOut { pos: usize },
+    /// The command status wrapper has been queued
+    Status,
+    /// The IN endpoint has been stalled to end the data phase early;
+    /// the status is sent once the host clears the halt
+    StallIn,
+}
+
+/// A mass storage interface with a single logical unit
+pub struct MassStorage<'a, B: BlockDevice> {
+    interface: u8,
+    in_ep: EndpointIn<'a>,
+    out_ep: EndpointOut<'a>,
+    device: B,
+    phase: Phase,
+    tag: u32,
+    /// Bytes of the data phase the host expects but has not transferred
+    residue: u32,
+    status: u8,
+    /// The next block of a READ(10) or WRITE(10)
+    lba: u32,
+    /// The blocks remaining after the one in `buf`
+    blocks: u32,
+    sense_key: u8,
+    sense_asc: u8,
+    buf: [u8; BLOCK_SIZE],
+}
+
+impl<'a, B: BlockDevice> MassStorage<'a, B> {
+    /// Allocates the interface and its bulk endpoints
+    pub fn new(usb: &mut UsbDevice<'a>, device: B) -> Result<Self, UsbError> {
+        Ok(Self {
+            interface: usb.alloc_interface(),
+            in_ep: usb.alloc_endpoint_in(EndpointType::Bulk, PACKET_SIZE as u16, 0)?,
+            out_ep: usb.alloc_endpoint_out(EndpointType::Bulk, PACKET_SIZE as u16, 0)?,
+            device,
+            phase: Phase::Command,
+            tag: 0,
+            residue: 0,
+            status: CSW_PASSED,
+            lba: 0,
+            blocks: 0,
+            sense_key: SENSE_NO_SENSE,
+            sense_asc: ASC_NONE,
+            buf: [0; BLOCK_SIZE],
+        })
+    }
+
+    pub fn block_device(&mut self) -> &mut B {
+        &mut self.device
+    }
+
+    /// Releases the block device
+    pub fn free(self) -> B {
+        self.device
+    }
+
+    /// Processes a packet waiting on the OUT endpoint, if any
+    fn process_out(&mut self) {
+        match self.phase {
+            Phase::Command => {
+                let mut cbw = [0u8; PACKET_SIZE];
+                if let Ok(len) = self.out_ep.read(&mut cbw) {
+                    if len == CBW_LEN && le32(&cbw) == CBW_SIGNATURE {
+                        self.command(&cbw);
+                    } else {
+                        // An invalid CBW requires the host to perform
+                        // a reset recovery
+                        self.in_ep.set_stalled(true);
+                        self.out_ep.set_stalled(true);
+                    }
+                }
+            }
+            Phase::DataOut { pos } => {
+                let len = match self.out_ep.read(&mut self.buf[pos..]) {
+                    Ok(len) => len,
+                    Err(_) => return,
+                };
+                self.residue = self.residue.saturating_sub(len as u32);
+                let pos = pos + len;
+                if pos < BLOCK_SIZE {
+                    self.phase = Phase::DataOut { pos };
+                    return;
+                }
+
+                let result = {
+                    let buf = &self.buf;
+                    self.device.write_block(self.lba, buf)
+                };
+                if result.is_err() {
+                    self.out_ep.set_stalled(true);
+                    self.fail(SENSE_MEDIUM_ERROR, ASC_WRITE_ERROR);
+                    self.send_status();
+                    return;
+                }
+                self.lba += 1;
+                if self.blocks > 0 {
+                    self.blocks -= 1;
+                    self.phase = Phase::DataOut { pos: 0 };
+                } else {
+                    if self.device.flush().is_err() {
+                        self.fail(SENSE_MEDIUM_ERROR, ASC_WRITE_ERROR);
+                    }
+                    self.send_status();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Executes the SCSI command in a command block wrapper
+    fn command(&mut self, cbw: &[u8]) {
+        self.tag = le32(&cbw[4..]);
+        self.residue = le32(&cbw[8..]);
+        self.status = CSW_PASSED;
+        let data_in = cbw[12] & 0x80 != 0;
+        let cb = &cbw[15..31];
+
+        match cb[0] {
+            SCSI_TEST_UNIT_READY
+            | SCSI_START_STOP_UNIT
+            | SCSI_PREVENT_ALLOW_MEDIUM_REMOVAL
+            | SCSI_VERIFY_10 => self.send_status(),
+            SCSI_SYNCHRONIZE_CACHE_10 => {
+                if self.device.flush().is_err() {
+                    self.fail(SENSE_MEDIUM_ERROR, ASC_WRITE_ERROR);
+                }
+                self.send_status();
+            }
+            SCSI_REQUEST_SENSE => {
+                let mut sense = [0u8; 18];
+                sense[0] = 0x70;
+                sense[2] = self.sense_key;
+                sense[7] = 10;
+                sense[12] = self.sense_asc;
+                self.buf[..18].copy_from_slice(&sense);
+                self.sense_key = SENSE_NO_SENSE;
+                self.sense_asc = ASC_NONE;
+                self.reply(18);
+            }
+            SCSI_INQUIRY => {
+                self.buf[..36].copy_from_slice(&INQUIRY_RESPONSE);
+                self.reply(36);
+            }
+            SCSI_MODE_SENSE_6 => {
+                // No mode pages and not write protected
+                self.buf[..4].copy_from_slice(&[3, 0, 0, 0]);
+                self.reply(4);
+            }
+            SCSI_READ_FORMAT_CAPACITIES => {
+                let count = self.device.block_count();
+                self.buf[..4].copy_from_slice(&[0, 0, 0, 8]);
+                put_be32(&mut self.buf[4..], count);
+                // Formatted media
+                put_be32(&mut self.buf[8..], BLOCK_SIZE as u32);
+                self.buf[8] = 0x02;
+                self.reply(12);
+            }
+            SCSI_READ_CAPACITY_10 => {
+                let last = self.device.block_count().saturating_sub(1);
+                put_be32(&mut self.buf[0..], last);
+                put_be32(&mut self.buf[4..], BLOCK_SIZE as u32);
+                self.reply(8);
+            }
+            SCSI_READ_10 | SCSI_WRITE_10 => {
+                let lba = be32(&cb[2..]);
+                let count = (u32::from(cb[7]) << 8) | u32::from(cb[8]);
+                if lba as u64 + count as u64 > self.device.block_count() as u64 {
+                    self.fail(SENSE_ILLEGAL_REQUEST, ASC_LBA_OUT_OF_RANGE);
+                    self.end_data_phase(data_in);
+                } else if count == 0 {
+                    self.send_status();
+                } else {
+                    self.lba = lba;
+                    self.blocks = count - 1;
+                    if cb[0] == SCSI_READ_10 {
+                        self.read_next_block();
+                    } else {
+                        self.phase = Phase::DataOut { pos: 0 };
+                    }
+                }
+            }
+            _ => {
+                self.fail(SENSE_ILLEGAL_REQUEST, ASC_INVALID_COMMAND);
+                self.end_data_phase(data_in);
+            }
+        }
+    }
+
+    fn fail(&mut self, key: u8, asc: u8) {
+        self.status = CSW_FAILED;
+        self.sense_key = key;
+        self.sense_asc = asc;
+    }
+
+    /// Ends a data phase that the host expects but the device will
+    /// not perform, then sends the status
+    fn end_data_phase(&mut self, data_in: bool) {
+        if self.residue == 0 {
+            self.send_status();
+        } else if data_in {
+            self.in_ep.set_stalled(true);
+            self.phase = Phase::StallIn;
+        } else {
+            self.out_ep.set_stalled(true);
+            self.send_status();
+        }
+    }
+
+    /// Starts sending the first `len` bytes of `buf` as the data phase
+    fn reply(&mut self, len: usize) {
+        let len = cmp::min(len, self.residue as usize);
+        if len == 0 {
+            self.send_status();
+            return;
+        }
+        self.blocks = 0;
+        self.phase = Phase::DataIn { pos: 0, len };
+        self.send_data();
+    }
+
+    fn read_next_block(&mut self) {
+        let result = {
+            let buf = &mut self.buf;
+            self.device.read_block(self.lba, buf)
+        };
+        if result.is_err() {
+            self.fail(SENSE_MEDIUM_ERROR, ASC_UNRECOVERED_READ_ERROR);
+            self.end_data_phase(true);
+            return;
+        }
+        self.lba += 1;
+        self.phase = Phase::DataIn {
+            pos: 0,
+            len: BLOCK_SIZE,
+        };
+        self.send_data();
+    }
+
+    fn send_data(&mut self) {
+        if let Phase::DataIn { pos, len } = self.phase {
+            let count = cmp::min(len - pos, PACKET_SIZE);
+            if self.in_ep.write(&self.buf[pos..pos + count]).is_ok() {
+                self.residue = self.residue.saturating_sub(count as u32);
+                self.phase = Phase::DataIn {
+                    pos: pos + count,
+                    len,
+                };
+            }
+        }
+    }
+
+    /// Called when a data phase packet has been collected
+    fn data_sent(&mut self) {
+        if let Phase::DataIn { pos, len } = self.phase {
+            if pos < len {
+                self.send_data();
+            } else if self.blocks > 0 {
+                self.blocks -= 1;
+                self.read_next_block();
+            } else if self.residue > 0 && len % PACKET_SIZE == 0 {
+                // The host cannot tell that a data phase ending in a
+                // full packet is short, so it must be told with a stall
+                self.in_ep.set_stalled(true);
+                self.phase = Phase::StallIn;
+            } else {
+                self.send_status();
+            }
+        }
+    }
+
+    fn send_status(&mut self) {
+        let mut csw = [0u8; 13];
+        put_le32(&mut csw[0..], CSW_SIGNATURE);
+        put_le32(&mut csw[4..], self.tag);
+        put_le32(&mut csw[8..], self.residue);
+        csw[12] = self.status;
+        self.phase = match self.in_ep.write(&csw) {
+            Ok(_) => Phase::Status,
+            Err(_) => Phase::Command,
+        };
+    }
+}
+
+impl<'a, B: BlockDevice> UsbClass for MassStorage<'a, B> {
+    fn reset(&mut self) {
+        self.phase = Phase::Command;
+        self.sense_key = SENSE_NO_SENSE;
+        self.sense_asc = ASC_NONE;
+    }
+
+    fn configuration_descriptors(&self, w: &mut DescriptorWriter) -> Result<(), UsbError> {
+        w.interface(
+            self.interface,
+            0,
+            USB_CLASS_MSC,
+            MSC_SUBCLASS_SCSI,
+            MSC_PROTOCOL_BULK_ONLY,
+            0,
+        )?;
+        w.endpoint(&self.in_ep.info())?;
+        w.endpoint(&self.out_ep.info())
+    }
+
+    fn control_in(&mut self, req: &SetupPacket, buf: &mut [u8]) -> ControlIn {
+        if req.kind() != RequestKind::Class
+            || req.recipient() != Recipient::Interface
+            || req.index != u16::from(self.interface)
+        {
+            return ControlIn::Ignored;
+        }
+        match req.request {
+            MSC_REQ_GET_MAX_LUN => {
+                buf[0] = 0;
+                ControlIn::Data(1)
+            }
+            _ => ControlIn::Rejected,
+        }
+    }
+
+    fn control_out(&mut self, req: &SetupPacket, _data: &[u8]) -> ControlOut {
+        // Watch for the host clearing the halt on the IN endpoint, which
+        // is the cue to send the status of a command that stalled.  The
+        // device performs the actual clear, so the request is not claimed.
+        if req.kind() == RequestKind::Standard
+            && req.recipient() == Recipient::Endpoint
+            && req.request == request::CLEAR_FEATURE
+            && req.value == feature::ENDPOINT_HALT
+            && req.index == u16::from(self.in_ep.address().0)
+        {
+            if self.phase == Phase::StallIn {
+                self.send_status();
+            }
+            return ControlOut::Ignored;
+        }
+
+        if req.kind() != RequestKind::Class
+            || req.recipient() != Recipient::Interface
+            || req.index != u16::from(self.interface)
+        {
+            return ControlOut::Ignored;
+        }
+        match req.request {
+            MSC_REQ_RESET => {
+                self.phase = Phase::Command;
+                ControlOut::Accepted
+            }
+            _ => ControlOut::Rejected,
+        }
+    }
+
+    fn endpoint_out(&mut self, addr: EndpointAddress) {
+        if addr == self.out_ep.address() {
+            self.process_out();
+        }
+    }
+
+    fn endpoint_in_complete(&mut self, addr: EndpointAddress) {
+        if addr != self.in_ep.address() {
+            return;
+        }
+        match self.phase {
+            Phase::DataIn { .. } => self.data_sent(),
+            Phase::Status => {
+                self.phase = Phase::Command;
+                // The next command may have arrived while the
+                // status was being sent
+                self.process_out();
+            }
+            _ => {}
+        }
+    }
+}
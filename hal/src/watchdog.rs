@@ -0,0 +1,190 @@
+//! Working with the watchdog timer.
+//!
+//! The watchdog resets the device if it is not fed within the configured
+//! period.  It is clocked by its own generic clock; the usual setup is
+//! a generator that divides the OSCULP32K down to roughly 1Khz, which
+//! gives periods from 8ms up to 16s.
+//! After a reset, `power::reset_cause` can be used to determine whether
+//! the watchdog was responsible.
+use clock;
+use target_device::{PM, WDT};
+use time::{Hertz, MilliSeconds};
+
+#[cfg(feature = "unproven")]
+use hal::watchdog::{self, WatchdogDisable, WatchdogEnable};
+
+/// The largest period supported by the hardware is 16K clock cycles
+const MAX_PERIOD: u8 = 11;
+
+/// Represents the WDT peripheral.
+/// The watchdog is not started until one of the `start` methods is
+/// called.  Once started in always-on mode, it cannot be disabled
+/// until the next reset.
+pub struct Watchdog {
+    freq: Hertz,
+    wdt: WDT,
+}
+
+impl Watchdog {
+    /// Configures the watchdog instance.  The clock frequency
+    /// determines the resolution and range of the periods that
+    /// can be used with the `start` methods.
+    pub fn new(clock: &clock::WdtClock, wdt: WDT, pm: &mut PM) -> Self {
+        pm.apbamask.modify(|_, w| w.wdt_().set_bit());
+
+        let mut watchdog = Self {
+            freq: clock.freq(),
+            wdt,
+        };
+        watchdog.disable_impl();
+        watchdog
+    }
+
+    /// Releases the WDT peripheral.  The watchdog is left running
+    /// if it has been started.
+    pub fn free(self) -> WDT {
+        self.wdt
+    }
+
+    fn wait_for_sync(&mut self) {
+        while self.wdt.status.read().syncbusy().bit_is_set() {}
+    }
+
+    /// Computes the PER/WINDOW/EWOFFSET encoding for the shortest
+    /// hardware period that is at least as long as `period`.
+    /// The hardware supports periods of 8 << n clock cycles.
+    fn cycles_to_bits(&self, period: MilliSeconds) -> u8 {
+        let cycles = (period.0 as u64 * self.freq.0 as u64) / 1_000;
+        let mut bits = 0;
+        while bits < MAX_PERIOD && (8u64 << bits) < cycles {
+            bits += 1;
+        }
+        bits
+    }
+
+    fn configure(&mut self, period: MilliSeconds, window: Option<MilliSeconds>) {
+        self.disable_impl();
+
+        let per = self.cycles_to_bits(period);
+        let win = window.map(|w| self.cycles_to_bits(w)).unwrap_or(0);
+        self.wdt.config.write(|w| unsafe {
+            w.per().bits(per);
+            w.window().bits(win)
+        });
+    }
+
+    /// Starts the watchdog in normal mode.  It must be fed at least
+    /// once per `period` to avoid a reset.
+    pub fn start(&mut self, period: MilliSeconds) {
+        self.configure(period, None);
+        self.wdt.ctrl.write(|w| {
+            w.wen().clear_bit();
+            w.enable().set_bit()
+        });
+        self.wait_for_sync();
+    }
+
+    /// Starts the watchdog in window mode.  Feeding the watchdog during
+    /// the `closed` period that follows the previous feed resets the
+    /// device, as does failing to feed it before the subsequent `open`
+    /// period elapses.
+    pub fn start_windowed(&mut self, closed: MilliSeconds, open: MilliSeconds) {
+        self.configure(open, Some(closed));
+        self.wdt.ctrl.write(|w| {
+            w.wen().set_bit();
+            w.enable().set_bit()
+        });
+        self.wait_for_sync();
+    }
+
+    /// Starts the watchdog in always-on mode.  Once started, the
+    /// watchdog and its configuration cannot be changed until the
+    /// device is reset.
+    pub fn start_always_on(&mut self, period: MilliSeconds) {
+        self.configure(period, None);
+        self.wdt.ctrl.write(|w| w.alwayson().set_bit());
+        self.wait_for_sync();
+    }
+
+    /// Returns true if the watchdog was started in always-on mode
+    pub fn is_always_on(&self) -> bool {
+        self.wdt.ctrl.read().alwayson().bit_is_set()
+    }
+
+    /// Enables the early warning interrupt, which fires `offset` after
+    /// the watchdog was last fed.  This gives the application a chance
+    /// to save state before the watchdog resets the device.
+    /// This method only configures the peripheral to trigger the
+    /// interrupt; it does not configure the interrupt controller or
+    /// define an interrupt handler.
+    /// The offset should be configured before the watchdog is started.
+    pub fn enable_early_warning(&mut self, offset: MilliSeconds) {
+        let bits = self.cycles_to_bits(offset);
+        self.wdt.ewctrl.write(|w| unsafe { w.ewoffset().bits(bits) });
+        self.wdt.intenset.write(|w| w.ew().set_bit());
+    }
+
+    /// Disables the early warning interrupt
+    pub fn disable_early_warning(&mut self) {
+        self.wdt.intenclr.write(|w| w.ew().set_bit());
+    }
+
+    /// Returns true if the early warning interrupt flag is set
+    pub fn is_early_warning(&self) -> bool {
+        self.wdt.intflag.read().ew().bit_is_set()
+    }
+
+    /// Clears the early warning interrupt flag
+    pub fn clear_early_warning(&mut self) {
+        // Writing a 1 clears the flag
+        self.wdt.intflag.write(|w| w.ew().set_bit());
+    }
+
+    /// Feed the watchdog, restarting its period
+    pub fn feed(&mut self) {
+        self.feed_impl();
+    }
+
+    fn feed_impl(&mut self) {
+        // Writing anything other than the key resets the device
+        self.wdt.clear.write(|w| w.clear().key());
+        self.wait_for_sync();
+    }
+
+    /// Stops the watchdog.  This has no effect if the watchdog
+    /// was started in always-on mode.
+    pub fn disable(&mut self) {
+        self.disable_impl();
+    }
+
+    fn disable_impl(&mut self) {
+        self.wdt.ctrl.modify(|_, w| w.enable().clear_bit());
+        self.wait_for_sync();
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl watchdog::Watchdog for Watchdog {
+    fn feed(&mut self) {
+        self.feed_impl();
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl WatchdogEnable for Watchdog {
+    type Time = MilliSeconds;
+
+    fn start<T>(&mut self, period: T)
+    where
+        T: Into<MilliSeconds>,
+    {
+        Watchdog::start(self, period.into());
+    }
+}
+
+#[cfg(feature = "unproven")]
+impl WatchdogDisable for Watchdog {
+    fn disable(&mut self) {
+        self.disable_impl();
+    }
+}
@@ -0,0 +1,272 @@
+#![no_std]
+
+extern crate atsamd21_hal as hal;
+
+pub use hal::atsamd21g18a::*;
+use hal::prelude::*;
+pub use hal::*;
+
+use gpio::{Floating, Input, Output, Port, PushPull};
+use hal::clock::GenericClockController;
+use hal::sercom::{I2CMaster3, PadPin, SPIMaster1, SPIMaster4, SPIMaster5, UART0};
+use hal::time::Hertz;
+
+/// Maps the pins to their arduino names and
+/// the numbers printed on the board.
+pub struct Pins {
+    /// Opaque port reference
+    pub port: Port,
+
+    /// Analog pin 0.  Can act as a true analog output
+    /// as it has a DAC (which is not currently supported
+    /// by this hal) as well as input.
+    pub a0: gpio::Pa2<Input<Floating>>,
+    /// Analog Pin 1
+    pub a1: gpio::Pb8<Input<Floating>>,
+    /// Analog Pin 2
+    pub a2: gpio::Pb9<Input<Floating>>,
+    /// Analog Pin 3
+    pub a3: gpio::Pa4<Input<Floating>>,
+    /// Analog Pin 4
+    pub a4: gpio::Pa5<Input<Floating>>,
+    /// Analog Pin 5
+    pub a5: gpio::Pb2<Input<Floating>>,
+
+    /// Pin 0, rx
+    pub d0: gpio::Pa11<Input<Floating>>,
+    /// Pin 1, tx
+    pub d1: gpio::Pa10<Input<Floating>>,
+    /// Pin 2
+    pub d2: gpio::Pa14<Input<Floating>>,
+    /// Pin 3, PWM capable
+    pub d3: gpio::Pa9<Input<Floating>>,
+    /// Pin 4, PWM capable
+    pub d4: gpio::Pa8<Input<Floating>>,
+    /// Pin 5, PWM capable.  Its level is shifted up to the
+    /// 5V of the USB or battery supply.
+    pub d5: gpio::Pa15<Input<Floating>>,
+    /// Pin 7
+    pub d7: gpio::Pa21<Input<Floating>>,
+    /// Pin 9, PWM capable
+    pub d9: gpio::Pa7<Input<Floating>>,
+    /// Pin 10, PWM capable
+    pub d10: gpio::Pa18<Input<Floating>>,
+    /// Pin 11, PWM capable
+    pub d11: gpio::Pa16<Input<Floating>>,
+    /// Pin 12, PWM capable
+    pub d12: gpio::Pa19<Input<Floating>>,
+    /// Digital pin number 13, which is also attached to
+    /// the red LED.  PWM capable.
+    pub d13: gpio::Pa17<Input<Floating>>,
+    pub sda: gpio::Pa22<Input<Floating>>,
+    pub scl: gpio::Pa23<Input<Floating>>,
+
+    /// The SPI SCK
+    pub sck: gpio::Pb11<Input<Floating>>,
+    /// The SPI MOSI
+    pub mosi: gpio::Pb10<Input<Floating>>,
+    /// The SPI MISO
+    pub miso: gpio::Pa12<Input<Floating>>,
+
+    /// The data line of the on-board DotStar LED
+    pub dotstar_di: gpio::Pa0<Input<Floating>>,
+    /// The clock line of the on-board DotStar LED
+    pub dotstar_ci: gpio::Pa1<Input<Floating>>,
+
+    /// The SCK pin attached to the on-board SPI flash
+    pub flash_sck: gpio::Pb23<Input<Floating>>,
+    /// The MOSI pin attached to the on-board SPI flash
+    pub flash_mosi: gpio::Pb22<Input<Floating>>,
+    /// The MISO pin attached to the on-board SPI flash
+    pub flash_miso: gpio::Pb3<Input<Floating>>,
+    /// The CS pin attached to the on-board SPI flash
+    pub flash_cs: gpio::Pa27<Input<Floating>>,
+
+    /// The USB D- pad
+    pub usb_dm: gpio::Pa24<Input<Floating>>,
+    /// The USB D+ pad
+    pub usb_dp: gpio::Pa25<Input<Floating>>,
+}
+
+/// Returns the pins for the device
+pub fn pins(port: atsamd21g18a::PORT) -> Pins {
+    let pins = port.split();
+    Pins {
+        port: pins.port,
+        a0: pins.pa2,
+        a1: pins.pb8,
+        a2: pins.pb9,
+        a3: pins.pa4,
+        a4: pins.pa5,
+        a5: pins.pb2,
+        d0: pins.pa11,
+        d1: pins.pa10,
+        d2: pins.pa14,
+        d3: pins.pa9,
+        d4: pins.pa8,
+        d5: pins.pa15,
+        d7: pins.pa21,
+        d9: pins.pa7,
+        d10: pins.pa18,
+        d11: pins.pa16,
+        d12: pins.pa19,
+        d13: pins.pa17,
+        sda: pins.pa22,
+        scl: pins.pa23,
+        sck: pins.pb11,
+        mosi: pins.pb10,
+        miso: pins.pa12,
+        dotstar_di: pins.pa0,
+        dotstar_ci: pins.pa1,
+
+        flash_sck: pins.pb23,
+        flash_mosi: pins.pb22,
+        flash_miso: pins.pb3,
+        flash_cs: pins.pa27,
+
+        usb_dm: pins.pa24,
+        usb_dp: pins.pa25,
+    }
+}
+
+/// Convenience for setting up the labelled SPI pins.
+/// This powers up SERCOM4 and configures it for use as an
+/// SPI Master in SPI Mode 0.
+/// Configuring a pin for CS is the responsibility of the caller.
+pub fn spi_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom4: SERCOM4,
+    pm: &mut PM,
+    sck: gpio::Pb11<Input<Floating>>,
+    mosi: gpio::Pb10<Input<Floating>>,
+    miso: gpio::Pa12<Input<Floating>>,
+    port: &mut Port,
+) -> SPIMaster4 {
+    let gclk0 = clocks.gclk0();
+    SPIMaster4::new(
+        &clocks.sercom4_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom4,
+        pm,
+        hal::sercom::SPI4Pinout::Dipo0Dopo1 {
+            miso: miso.into_pad(port),
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for accessing the on-board SPI Flash device.
+/// This powers up SERCOM5 and configures it for use as an
+/// SPI Master.
+pub fn flash_spi_master(
+    clocks: &mut GenericClockController,
+    sercom5: SERCOM5,
+    pm: &mut PM,
+    sck: gpio::Pb23<Input<Floating>>,
+    mosi: gpio::Pb22<Input<Floating>>,
+    miso: gpio::Pb3<Input<Floating>>,
+    cs: gpio::Pa27<Input<Floating>>,
+    port: &mut Port,
+) -> (SPIMaster5, gpio::Pa27<Output<PushPull>>) {
+    let gclk0 = clocks.gclk0();
+    let flash = SPIMaster5::new(
+        &clocks.sercom5_core(&gclk0).unwrap(),
+        48.mhz(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom5,
+        pm,
+        hal::sercom::SPI5Pinout::Dipo1Dopo1 {
+            miso: miso.into_pad(port),
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    );
+
+    let mut cs = cs.into_push_pull_output(port);
+    cs.set_high();
+
+    (flash, cs)
+}
+
+/// Convenience for setting up the labelled SDA, SCL pins to
+/// operate as an I2C master running at the specified frequency.
+pub fn i2c_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom3: SERCOM3,
+    pm: &mut PM,
+    sda: gpio::Pa22<Input<Floating>>,
+    scl: gpio::Pa23<Input<Floating>>,
+    port: &mut Port,
+) -> I2CMaster3 {
+    let gclk0 = clocks.gclk0();
+    I2CMaster3::new(
+        &clocks.sercom3_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        sercom3,
+        pm,
+        sda.into_pad(port),
+        scl.into_pad(port),
+    )
+}
+
+/// Convenience for setting up the labelled RX, TX pins to
+/// operate as a UART running at the specified baud rate.
+pub fn uart<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    baud: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    rx: gpio::Pa11<Input<Floating>>,
+    tx: gpio::Pa10<Input<Floating>>,
+    port: &mut Port,
+) -> UART0 {
+    let gclk0 = clocks.gclk0();
+    UART0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        baud.into(),
+        sercom0,
+        pm,
+        hal::sercom::UART0Pinout::Rx3Tx2 {
+            rx: rx.into_pad(port),
+            tx: tx.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for driving the on-board DotStar LED.
+/// This powers up SERCOM1 and configures it as a write-only
+/// SPI Master in SPI Mode 0, which is what the APA102 expects.
+pub fn dotstar_spi(
+    clocks: &mut GenericClockController,
+    sercom1: SERCOM1,
+    pm: &mut PM,
+    di: gpio::Pa0<Input<Floating>>,
+    ci: gpio::Pa1<Input<Floating>>,
+    port: &mut Port,
+) -> SPIMaster1 {
+    let gclk0 = clocks.gclk0();
+    SPIMaster1::new(
+        &clocks.sercom1_core(&gclk0).unwrap(),
+        4.mhz(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom1,
+        pm,
+        hal::sercom::SPI1Pinout::Dopo0 {
+            mosi: di.into_pad(port),
+            sck: ci.into_pad(port),
+        },
+    )
+}
@@ -0,0 +1,44 @@
+#![feature(used)]
+#![no_std]
+
+extern crate metro_m0 as hal;
+extern crate panic_abort;
+
+use hal::clock::GenericClockController;
+use hal::delay::Delay;
+use hal::leds::RGB8;
+use hal::prelude::*;
+use hal::{CorePeripherals, Peripherals};
+
+/// Cycles the on-board neopixel through red, green and blue
+fn main() {
+    let mut peripherals = Peripherals::take().unwrap();
+    let core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::new(
+        peripherals.GCLK,
+        &mut peripherals.PM,
+        &mut peripherals.SYSCTRL,
+        &mut peripherals.NVMCTRL,
+    );
+    let mut pins = hal::pins(peripherals.PORT);
+    let mut neopixel = hal::neopixel(
+        &mut clocks,
+        peripherals.SERCOM1,
+        &mut peripherals.PM,
+        pins.neopixel,
+        &mut pins.port,
+    );
+    let mut delay = Delay::new(core.SYST, &mut clocks);
+
+    let colors = [
+        RGB8::new(32, 0, 0),
+        RGB8::new(0, 32, 0),
+        RGB8::new(0, 0, 32),
+    ];
+    loop {
+        for &color in colors.iter() {
+            neopixel.write(Some(color).into_iter()).unwrap();
+            delay.delay_ms(500u16);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+#![feature(used)]
+#![no_std]
+
+extern crate cortex_m;
+extern crate metro_m0 as hal;
+#[cfg(not(feature = "use_semihosting"))]
+extern crate panic_abort;
+#[cfg(feature = "use_semihosting")]
+extern crate panic_semihosting;
+
+use hal::clock::GenericClockController;
+use hal::prelude::*;
+use hal::usb::cdc_acm::CdcAcm;
+use hal::usb::class::UsbClass;
+use hal::usb::descriptor::UsbDeviceInfo;
+use hal::usb::{Descriptors, UsbDevice};
+use hal::{CorePeripherals, Peripherals};
+
+/// Echoes everything typed into the USB serial port.
+/// Opening the port at 1200 baud resets into the bootloader.
+fn main() {
+    let mut peripherals = Peripherals::take().unwrap();
+    let mut core = CorePeripherals::take().unwrap();
+    let mut clocks = GenericClockController::with_usb_clock_recovery(
+        peripherals.GCLK,
+        &mut peripherals.PM,
+        &mut peripherals.SYSCTRL,
+        &mut peripherals.NVMCTRL,
+    );
+    let mut pins = hal::pins(peripherals.PORT);
+
+    let gclk0 = clocks.gclk0();
+    let usb_clock = clocks.usb(&gclk0).unwrap();
+    let mut descriptors = Descriptors::new();
+    let mut memory = [0u32; 128];
+    let mut usb = UsbDevice::new(
+        &usb_clock,
+        &mut peripherals.PM,
+        pins.usb_dm.into_function_g(&mut pins.port),
+        pins.usb_dp.into_function_g(&mut pins.port),
+        peripherals.USB,
+        &mut descriptors,
+        &mut memory,
+        // The pid.codes test VID/PID
+        UsbDeviceInfo::new(0x1209, 0x0001, "atsamd21-rs", "Metro M0 serial"),
+    );
+    let mut serial = CdcAcm::new(&mut usb).unwrap();
+    usb.attach();
+
+    loop {
+        usb.poll(&mut [&mut serial as &mut UsbClass]);
+        if serial.bootloader_requested() {
+            hal::power::reset_into_bootloader(&mut core.SCB);
+        }
+        if let Ok(byte) = serial.read() {
+            serial.write(byte).ok();
+        }
+    }
+}
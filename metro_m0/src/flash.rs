@@ -0,0 +1,3 @@
+//! The on-board SPI flash of the Metro M0 Express, a 2MB GD25Q16.
+//! The driver is shared with the other boards; see `hal::spi_flash`.
+pub use hal::spi_flash::SpiFlash;
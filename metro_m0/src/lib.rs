@@ -8,8 +8,13 @@ pub use hal::*;
 
 use gpio::{Floating, Input, Output, Port, PushPull};
 use hal::clock::GenericClockController;
-use hal::sercom::{I2CMaster3, PadPin, SPIMaster4, SPIMaster5};
+use hal::leds::ws2812::{self, Ws2812};
+use hal::sercom::{I2CMaster3, PadPin, SPIMaster1, SPIMaster4, SPIMaster5, UART0};
 use hal::time::Hertz;
+use hal::usb::descriptor::UsbDeviceInfo;
+use hal::usb::{Descriptors, UsbDevice};
+
+pub mod flash;
 
 /// Maps the pins to their arduino names and
 /// the numbers printed on the board.
@@ -83,6 +88,11 @@ pub struct Pins {
     pub flash_miso: gpio::Pb3<Input<Floating>>,
     /// The CS pin attached to the on-board SPI flash
     pub flash_cs: gpio::Pa13<Input<Floating>>,
+
+    /// The USB D- pad
+    pub usb_dm: gpio::Pa24<Input<Floating>>,
+    /// The USB D+ pad
+    pub usb_dp: gpio::Pa25<Input<Floating>>,
 }
 
 /// Returns the pins for the device
@@ -121,6 +131,9 @@ pub fn pins(port: atsamd21g18a::PORT) -> Pins {
         flash_mosi: pins.pb22,
         flash_miso: pins.pb3,
         flash_cs: pins.pa13,
+
+        usb_dm: pins.pa24,
+        usb_dp: pins.pa25,
     }
 }
 
@@ -216,3 +229,85 @@ pub fn i2c_master<F: Into<Hertz>>(
         scl.into_pad(port),
     )
 }
+
+/// Convenience for setting up the labelled RX, TX pins to
+/// operate as a UART running at the specified baud rate.
+pub fn uart<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    baud: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    rx: gpio::Pa11<Input<Floating>>,
+    tx: gpio::Pa10<Input<Floating>>,
+    port: &mut Port,
+) -> UART0 {
+    let gclk0 = clocks.gclk0();
+    UART0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        baud.into(),
+        sercom0,
+        pm,
+        hal::sercom::UART0Pinout::Rx3Tx2 {
+            rx: rx.into_pad(port),
+            tx: tx.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for setting up the USB connector as a device.
+/// The clocks must have been set up with
+/// `GenericClockController::with_usb_clock_recovery`, so that the
+/// 48Mhz clock is locked to the host.  The device is returned
+/// detached, so that the classes can be added before calling `attach`.
+pub fn usb_device<'a>(
+    clocks: &mut GenericClockController,
+    usb: USB,
+    pm: &mut PM,
+    dm: gpio::Pa24<Input<Floating>>,
+    dp: gpio::Pa25<Input<Floating>>,
+    port: &mut Port,
+    descriptors: &'a mut Descriptors,
+    memory: &'a mut [u32],
+    info: UsbDeviceInfo<'a>,
+) -> UsbDevice<'a> {
+    let gclk0 = clocks.gclk0();
+    UsbDevice::new(
+        &clocks.usb(&gclk0).unwrap(),
+        pm,
+        dm.into_function_g(port),
+        dp.into_function_g(port),
+        usb,
+        descriptors,
+        memory,
+        info,
+    )
+}
+
+/// Convenience for driving the on-board neopixel.
+/// This powers up SERCOM1 and configures it as a write-only
+/// SPI Master that generates the WS2812 waveform on the neopixel
+/// pin.  The pin is shared with SWCLK, so this disconnects the
+/// debugger.
+pub fn neopixel(
+    clocks: &mut GenericClockController,
+    sercom1: SERCOM1,
+    pm: &mut PM,
+    neopixel: gpio::Pa30<Input<Floating>>,
+    port: &mut Port,
+) -> Ws2812<SPIMaster1> {
+    let gclk0 = clocks.gclk0();
+    let spi = SPIMaster1::new(
+        &clocks.sercom1_core(&gclk0).unwrap(),
+        ws2812::SPI_FREQUENCY,
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom1,
+        pm,
+        hal::sercom::SPI1Pinout::Dopo1Mosi {
+            mosi: neopixel.into_pad(port),
+        },
+    );
+    Ws2812::new(spi)
+}
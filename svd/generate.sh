@@ -0,0 +1,55 @@
+#!/bin/sh
+# Generates the peripheral access crate for a part from its SVD file.
+#
+#   svd/generate.sh ATSAMD21J18A
+#
+# creates ./atsamd21j18a using the same layout as the existing crates.
+# Requires svd2rust 0.12.1, form and rustfmt on the PATH.
+set -e
+
+part=$1
+if [ -z "$part" ] ; then
+  echo "usage: $0 <PART>, eg: $0 ATSAMD21J18A" >&2
+  exit 1
+fi
+
+top=$(cd $(dirname $0)/.. && pwd)
+svd=$top/svd/$part.svd
+lower=$(echo $part | tr 'A-Z' 'a-z')
+crate=$top/$lower
+
+if [ ! -f "$svd" ] ; then
+  echo "no such file: $svd" >&2
+  exit 1
+fi
+
+mkdir -p $crate
+cd $crate
+rm -rf src
+svd2rust -i $svd
+form -i lib.rs -o src
+rm lib.rs
+cargo fmt
+
+# The manifest and README are the same for every part apart from its name
+sed -e "s/atsamd21e18a/$lower/g" -e "s/ATSAMD21E18A/$part/g" \
+  $top/atsamd21e18a/Cargo.toml > Cargo.toml
+sed -e "s/atsamd21e18a/$lower/g" -e "s/ATSAMD21E18A/$part/g" \
+  $top/atsamd21e18a/README.md > README.md
+
+feature=$(echo $lower | sed -e 's/^at//')
+case $lower in
+  atsamd21g*) pins=', "pins-48"' ;;
+  atsamd21j*) pins=', "pins-48", "pins-64"' ;;
+  *) pins='' ;;
+esac
+echo "Generated $crate; to use it from the HAL add to hal/Cargo.toml:"
+echo
+echo "[dependencies.$lower]"
+echo "path = \"../$lower\""
+echo "version = \"~0.2\""
+echo "optional = true"
+echo
+echo "$feature = [\"$lower\"$pins]"
+echo
+echo "and add \"$lower/rt\" to the rt feature."
@@ -0,0 +1,182 @@
+#![no_std]
+
+extern crate atsamd21_hal as hal;
+
+pub use hal::atsamd21e18a::*;
+use hal::prelude::*;
+pub use hal::*;
+
+use gpio::{Floating, Input, Port};
+use hal::clock::GenericClockController;
+use hal::sercom::{I2CMaster2, PadPin, SPIMaster0, SPIMaster1, UART0};
+use hal::time::Hertz;
+
+/// Maps the pins to their arduino names and
+/// the numbers printed on the board.
+/// Each of the five pads has several functions, which are
+/// listed on the pad.
+pub struct Pins {
+    /// Opaque port reference
+    pub port: Port,
+
+    /// Pin 0.  Also analog input A2 and the I2C SDA
+    pub d0: gpio::Pa8<Input<Floating>>,
+    /// Pin 1.  Also analog input A0, which can act as a true analog
+    /// output as it has a DAC, and a capacitive touch pad
+    pub d1: gpio::Pa2<Input<Floating>>,
+    /// Pin 2.  Also analog input A1, the I2C SCL and the SPI MISO
+    pub d2: gpio::Pa9<Input<Floating>>,
+    /// Pin 3.  Also analog input A3, a capacitive touch pad,
+    /// the UART RX and the SPI SCK
+    pub d3: gpio::Pa7<Input<Floating>>,
+    /// Pin 4.  Also analog input A4, a capacitive touch pad,
+    /// the UART TX and the SPI MOSI
+    pub d4: gpio::Pa6<Input<Floating>>,
+
+    /// Digital pin number 13, which is also attached to
+    /// the red LED.  PWM capable.
+    pub d13: gpio::Pa10<Input<Floating>>,
+
+    /// The data line of the on-board DotStar LED
+    pub dotstar_di: gpio::Pa0<Input<Floating>>,
+    /// The clock line of the on-board DotStar LED
+    pub dotstar_ci: gpio::Pa1<Input<Floating>>,
+
+    /// The USB D- pad
+    pub usb_dm: gpio::Pa24<Input<Floating>>,
+    /// The USB D+ pad
+    pub usb_dp: gpio::Pa25<Input<Floating>>,
+
+    /// The SWD clock, on the pads on the back of the board
+    pub swclk: gpio::Pa30<Input<Floating>>,
+    /// The SWD data line, on the pads on the back of the board
+    pub swdio: gpio::Pa31<Input<Floating>>,
+}
+
+/// Returns the pins for the device
+pub fn pins(port: atsamd21e18a::PORT) -> Pins {
+    let pins = port.split();
+    Pins {
+        port: pins.port,
+        d0: pins.pa8,
+        d1: pins.pa2,
+        d2: pins.pa9,
+        d3: pins.pa7,
+        d4: pins.pa6,
+        d13: pins.pa10,
+        dotstar_di: pins.pa0,
+        dotstar_ci: pins.pa1,
+        usb_dm: pins.pa24,
+        usb_dp: pins.pa25,
+        swclk: pins.pa30,
+        swdio: pins.pa31,
+    }
+}
+
+/// Convenience for setting up pins 0 and 2 to operate as an I2C
+/// master running at the specified frequency, with SDA on pin 0
+/// and SCL on pin 2.
+pub fn i2c_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom2: SERCOM2,
+    pm: &mut PM,
+    sda: gpio::Pa8<Input<Floating>>,
+    scl: gpio::Pa9<Input<Floating>>,
+    port: &mut Port,
+) -> I2CMaster2 {
+    let gclk0 = clocks.gclk0();
+    I2CMaster2::new(
+        &clocks.sercom2_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        sercom2,
+        pm,
+        sda.into_pad(port),
+        scl.into_pad(port),
+    )
+}
+
+/// Convenience for setting up pins 3 and 4 as a UART running at
+/// the specified baud rate, with RX on pin 3 and TX on pin 4.
+pub fn uart<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    baud: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    rx: gpio::Pa7<Input<Floating>>,
+    tx: gpio::Pa6<Input<Floating>>,
+    port: &mut Port,
+) -> UART0 {
+    let gclk0 = clocks.gclk0();
+    UART0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        baud.into(),
+        sercom0,
+        pm,
+        hal::sercom::UART0Pinout::Rx3Tx2 {
+            rx: rx.into_pad(port),
+            tx: tx.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for setting up pins 2, 3 and 4 as an SPI bus, with
+/// MISO on pin 2, SCK on pin 3 and MOSI on pin 4.
+/// This powers up SERCOM0 and configures it for use as an
+/// SPI Master in SPI Mode 0.
+/// Configuring a pin for CS is the responsibility of the caller.
+pub fn spi_master<F: Into<Hertz>>(
+    clocks: &mut GenericClockController,
+    bus_speed: F,
+    sercom0: SERCOM0,
+    pm: &mut PM,
+    sck: gpio::Pa7<Input<Floating>>,
+    mosi: gpio::Pa6<Input<Floating>>,
+    miso: gpio::Pa9<Input<Floating>>,
+    port: &mut Port,
+) -> SPIMaster0 {
+    let gclk0 = clocks.gclk0();
+    SPIMaster0::new(
+        &clocks.sercom0_core(&gclk0).unwrap(),
+        bus_speed.into(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom0,
+        pm,
+        hal::sercom::SPI0Pinout::Dipo1Dopo1 {
+            miso: miso.into_pad(port),
+            mosi: mosi.into_pad(port),
+            sck: sck.into_pad(port),
+        },
+    )
+}
+
+/// Convenience for driving the on-board DotStar LED.
+/// This powers up SERCOM1 and configures it as a write-only
+/// SPI Master in SPI Mode 0, which is what the APA102 expects.
+pub fn dotstar_spi(
+    clocks: &mut GenericClockController,
+    sercom1: SERCOM1,
+    pm: &mut PM,
+    di: gpio::Pa0<Input<Floating>>,
+    ci: gpio::Pa1<Input<Floating>>,
+    port: &mut Port,
+) -> SPIMaster1 {
+    let gclk0 = clocks.gclk0();
+    SPIMaster1::new(
+        &clocks.sercom1_core(&gclk0).unwrap(),
+        4.mhz(),
+        hal::hal::spi::Mode {
+            phase: hal::hal::spi::Phase::CaptureOnFirstTransition,
+            polarity: hal::hal::spi::Polarity::IdleLow,
+        },
+        sercom1,
+        pm,
+        hal::sercom::SPI1Pinout::Dopo0 {
+            mosi: di.into_pad(port),
+            sck: ci.into_pad(port),
+        },
+    )
+}